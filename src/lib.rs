@@ -1,6 +1,8 @@
 #![allow(non_upper_case_globals)]
 #![feature(portable_simd)]
 pub mod canvas;
+pub mod pga;
+pub mod pga_2;
 pub mod pga_3;
 pub mod raytracing;
 pub mod util;
@@ -4,8 +4,20 @@ use pgatracer::raytracing::lighting::*;
 use pgatracer::raytracing::materials::patterns::*;
 use pgatracer::raytracing::materials::*;
 use pgatracer::raytracing::*;
+use std::error::Error;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    match render_scene("img.ppm") {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Failed to render the scene: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
 
-fn main() {
+fn render_scene(path: &str) -> Result<(), Box<dyn Error>> {
     use std::f32::consts::PI;
     let cam_loc = Trivector::point(0.0, 2.25, -7.5);
     let cam_target = Trivector::point(0.0, 1.0, 0.0);
@@ -17,12 +29,12 @@ fn main() {
         3000,
         1000,
         PI / 3.0,
-    );
+    )?;
 
     let floor_stripe_1 = Pattern::stripe(Color::new(0.8, 0.2, 0.2), WHITE);
 
     let mut floor_stripe_2 = Pattern::stripe(Color::new(0.8, 0.2, 0.2), WHITE);
-    floor_stripe_2.transform_t(Transformation::rotation(e31, PI / 2.0));
+    floor_stripe_2.transform_t(Transformation::rotation(Blade::e31, PI / 2.0));
 
     let floor_pattern = Pattern::blend(&floor_stripe_1, &floor_stripe_2);
 
@@ -33,27 +45,27 @@ fn main() {
 
     let mut middle = Sphere::new();
     middle.transform_t(Transformation::trans_coords(-0.5, 1.0, 0.5));
-    middle.transform_t(Transformation::rotation(e31, -PI / 4.0));
+    middle.transform_t(Transformation::rotation(Blade::e31, -PI / 4.0));
     middle.material.color = Color::new(0.1, 1.0, 0.5);
     middle.material.diffuse = 0.7;
     middle.material.specular = 0.3;
 
     let mut middle_pattern = Pattern::stripe(Color::new(0.7, 0.1, 0.1), Color::new(0.1, 0.7, 0.1));
-    middle_pattern.scale(Trivector::scale(0.125, 0.125, 0.125));
+    middle_pattern.transform(Transform::scale(0.125, 0.125, 0.125)?);
     middle.material.pattern = Some(middle_pattern);
     let middle = Object::Sphere(middle);
 
     let mut right = Sphere::new();
+    right.transform = Transform::scale(0.5, 0.5, 0.5)?;
     right.transform_t(Transformation::trans_coords(1.5, 0.5, -0.5));
-    right.scale = Trivector::scale(0.5, 0.5, 0.5);
     right.material.color = Color::new(0.5, 1.0, 0.1);
     right.material.diffuse = 0.7;
     right.material.specular = 0.3;
     let right = Object::Sphere(right);
 
     let mut left = Sphere::new();
+    left.transform = Transform::scale(0.33, 0.33, 0.33)?;
     left.transform_t(Transformation::trans_coords(-1.5, 0.33, -0.75));
-    left.scale = Trivector::scale(0.33, 0.33, 0.33);
     left.material.color = Color::new(1.0, 0.8, 0.1);
     left.material.diffuse = 0.7;
     left.material.specular = 0.3;
@@ -67,11 +79,11 @@ fn main() {
     let light1_loc = Trivector::point(-10.0, 10.0, -10.0);
     let light = Light::Point(PointLight::new(light1_loc, Color::new(0.1, 0.5, 0.1)));
 
-    let light2_loc = Motor::from(Transformation::rotation(e31, PI / 2.0)) >> light1_loc;
+    let light2_loc = Motor::rotation(Blade::e31, PI / 2.0) >> light1_loc;
     let light2 = Light::Point(PointLight::new(light2_loc, Color::new(0.1, 0.1, 0.5)));
 
-    let light3_loc = Motor::from(Transformation::rotation(e31, PI / 4.0))
-        >> (Motor::from(Transformation::rotation(e12, PI / 4.0)) >> light1_loc);
+    let light3_loc = Motor::rotation(Blade::e31, PI / 4.0)
+        >> (Motor::rotation(Blade::e12, PI / 4.0) >> light1_loc);
     let light3 = Light::Point(PointLight::new(light3_loc, Color::new(0.5, 0.1, 0.1)));
 
     let mut world = World::new();
@@ -79,5 +91,6 @@ fn main() {
     world.objects = vec![left, middle, right, floor_plane];
     world.lights = vec![light, light2, light3];
 
-    world.render().write_file("img.ppm").unwrap();
+    world.render().write_file(path)?;
+    Ok(())
 }
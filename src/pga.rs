@@ -0,0 +1,136 @@
+use crate::util::Float;
+pub use error::*;
+use std::marker::Sized;
+use std::ops::{Div, Mul, Neg};
+
+// The traits `pga_2` and `pga_3` share. Each algebra is a marker type naming its float type,
+// `Versor` and `AnyKVector`, and the traits take it as a parameter, so that products only take
+// operands from the same algebra and precision, eg `T: Multivector<Pga3>`, while scalars belong
+// to both
+
+pub trait Algebra: Sized + 'static {
+    /// The float type of the coefficients
+    type Float: Float;
+    type Versor: Multivector<Self>;
+    type AnyKVector: SingleGrade<Self>;
+    /// The number of basis vectors, which is the grade of the pseudoscalar. `Multivector::e` takes
+    /// basis blades as bitmasks of this many bits.
+    const DIMENSION: u8;
+}
+
+pub trait Multivector<A: Algebra>:
+    Sized
+    + Neg
+    + Mul<A::Float, Output = Self>
+    + Div<A::Float, Output = Self>
+    + Into<A::Versor>
+    + Copy
+    + Clone
+{
+    fn e(&self, basis: u8) -> A::Float;
+    fn grade(&self, g: u8) -> A::AnyKVector;
+    fn highest_grade(&self) -> u8;
+    fn zero(&self) -> bool;
+    fn is_ideal(&self) -> bool;
+    fn eucl_norm(&self) -> A::Float {
+        self.reverse().geo(*self).e(0).sqrt()
+    }
+    fn ideal_norm(&self) -> A::Float {
+        self.dual().eucl_norm()
+    }
+    fn magnitude(&self) -> A::Float {
+        if self.is_ideal() {
+            self.ideal_norm()
+        } else {
+            self.eucl_norm()
+        }
+    }
+
+    fn reverse(&self) -> Self;
+    fn grade_involution(&self) -> Self;
+    fn dual(self) -> A::Versor;
+    fn undual(self) -> A::Versor;
+    fn geo<T: Multivector<A>>(self, rhs: T) -> A::Versor;
+
+    fn normalize(self) -> Self {
+        self / self.magnitude()
+    }
+
+    fn inverse(self) -> Option<Self> {
+        if self.is_ideal() {
+            return None;
+        }
+        Some(self.reverse() / self.magnitude().powi(2))
+    }
+
+    fn try_normalize(self) -> Result<Self, PgaError> {
+        let normalized = self.normalize();
+        if is_finite(&normalized) {
+            Ok(normalized)
+        } else if self.is_ideal() {
+            Err(PgaError::IdealNormalization)
+        } else {
+            Err(PgaError::NonInvertible)
+        }
+    }
+
+    fn try_inverse(self) -> Result<Self, PgaError> {
+        match self.inverse() {
+            Some(inv) if is_finite(&inv) => Ok(inv),
+            _ => Err(PgaError::NonInvertible),
+        }
+    }
+}
+
+fn is_finite<A: Algebra, T: Multivector<A>>(mv: &T) -> bool {
+    (0..1 << A::DIMENSION).all(|basis| mv.e(basis).is_finite())
+}
+
+pub trait SingleGrade<A: Algebra>: Multivector<A> + Into<A::AnyKVector> {
+    /// The grade of the type, or `None` for `AnyKVector`
+    const GRADE: Option<u8>;
+
+    fn outer<T: SingleGrade<A>>(self, rhs: T) -> A::AnyKVector;
+    fn inner<T: SingleGrade<A>>(self, rhs: T) -> A::AnyKVector;
+    fn try_assert<T: SingleGrade<A> + 'static>(self) -> Result<T, PgaError>;
+
+    fn assert<T: SingleGrade<A> + 'static>(self) -> T {
+        match self.try_assert() {
+            Ok(res) => res,
+            Err(e) => panic!("Single-grade assert failed: {e}"),
+        }
+    }
+
+    /// Whether `self` is the outer product of vectors, which only bivectors of `pga_3` can fail
+    fn is_blade(self) -> bool {
+        true
+    }
+
+    /// Orthogonal projection onto `target`, `(self | target) * target^-1`, eg the foot of a point
+    /// on a plane or line, or the plane through a point parallel to `target`. Fails with
+    /// `PgaError::NonInvertible` if `target` has no inverse.
+    fn project_onto<T: SingleGrade<A>>(self, target: T) -> Result<A::AnyKVector, PgaError> {
+        let inverse = target.try_inverse()?;
+        Ok(self.inner(target).geo(inverse).grade(self.highest_grade()))
+    }
+
+    /// The part of `self` orthogonal to `target`, `(self ^ target) * target^-1`. Fails with
+    /// `PgaError::NonInvertible` if `target` has no inverse.
+    fn reject_from<T: SingleGrade<A>>(self, target: T) -> Result<A::AnyKVector, PgaError> {
+        let inverse = target.try_inverse()?;
+        Ok(self.outer(target).geo(inverse).grade(self.highest_grade()))
+    }
+
+    fn regressive<T: SingleGrade<A>>(self, rhs: T) -> A::AnyKVector {
+        // The dual of a k-vector is the (n - k)-vector in n dimensions
+        let n = A::DIMENSION;
+        let d1 = self.dual().grade(n - self.highest_grade());
+        let d2 = rhs.dual().grade(n - rhs.highest_grade());
+        let meet = d1.outer(d2);
+        meet.undual().grade(n - meet.highest_grade())
+    }
+}
+
+pub trait NonScalar {}
+
+mod error;
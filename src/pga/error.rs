@@ -0,0 +1,170 @@
+use crate::pga::*;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PgaError {
+    /// A single-grade value was asked for as another grade. `None` stands for something without
+    /// a single grade: an `AnyKVector` on the expected side, or a motor, odd versor or mixed
+    /// multivector on the found side.
+    GradeMismatch {
+        expected: Option<u8>,
+        found: Option<u8>,
+    },
+    /// An ideal element with a zero ideal norm (including zero itself) has no normalized form
+    IdealNormalization,
+    /// The element has no inverse, eg because it's ideal or its norm vanishes
+    NonInvertible,
+    /// A transformation was asked for as the wrong kind of versor: a reflection as a `Motor`, or a
+    /// rigid motion as an `OddVersor`
+    ParityMismatch { expected_odd: bool },
+    /// Text that isn't a sum of scaled basis blades, failing at byte `at` where `expected` should
+    /// have been
+    Parse { at: usize, expected: &'static str },
+}
+
+impl Display for PgaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let describe = |grade: &Option<u8>| match grade {
+            Some(g) => format!("grade {g}"),
+            None => "mixed grades".to_string(),
+        };
+        match self {
+            PgaError::GradeMismatch { expected, found } => write!(
+                f,
+                "grade mismatch: expected {}, found {}",
+                describe(expected),
+                describe(found)
+            ),
+            PgaError::IdealNormalization => {
+                write!(f, "cannot normalize an ideal element of norm 0")
+            }
+            PgaError::NonInvertible => write!(f, "element is not invertible"),
+            PgaError::ParityMismatch { expected_odd } => {
+                let (expected, found) = if *expected_odd {
+                    ("an odd versor", "a rigid motion")
+                } else {
+                    ("a motor", "a reflection")
+                };
+                write!(f, "parity mismatch: expected {expected}, found {found}")
+            }
+            PgaError::Parse { at, expected } => {
+                write!(
+                    f,
+                    "cannot parse multivector at byte {at}: expected {expected}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PgaError {}
+
+/// Downcasts a single-grade value of grade `found` to `T`
+pub(crate) fn downcast_grade<A: Algebra, S: 'static, T: SingleGrade<A> + 'static>(
+    value: &S,
+    found: u8,
+) -> Result<T, PgaError> {
+    use std::any::Any;
+    match (value as &dyn Any).downcast_ref::<T>() {
+        Some(res) => Ok(*res),
+        None => Err(PgaError::GradeMismatch {
+            expected: T::GRADE,
+            found: Some(found),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pga_3::*;
+
+    #[test]
+    fn try_assert_reports_grades() {
+        let tv = Trivector::point(1.0, 2.0, 3.0);
+        assert_eq!(tv.try_assert::<Trivector>(), Ok(tv));
+        assert_eq!(
+            tv.try_assert::<Bivector>(),
+            Err(PgaError::GradeMismatch {
+                expected: Some(2),
+                found: Some(3)
+            })
+        );
+        assert_eq!(
+            AnyKVector::from(2.0).try_assert::<Pseudoscalar>(),
+            Err(PgaError::GradeMismatch {
+                expected: Some(4),
+                found: Some(0)
+            })
+        );
+    }
+
+    #[test]
+    fn versor_try_assert() {
+        let m = Motor::trans_coords(1.0, 0.0, 0.0);
+        assert_eq!(
+            Versor::from(m).try_assert::<Bivector>(),
+            Err(PgaError::GradeMismatch {
+                expected: Some(2),
+                found: None
+            })
+        );
+        assert_eq!((e1 * e2).try_assert::<Bivector>(), Ok(e12));
+    }
+
+    #[test]
+    fn try_normalize_zero() {
+        let zero: Trivector = Trivector::from([0.0; 4]);
+        assert_eq!(zero.try_normalize(), Err(PgaError::IdealNormalization));
+        assert_eq!(
+            <Trivector>::from([2.0, 2.0, 4.0, 6.0]).try_normalize(),
+            Ok(Trivector::point(-1.0, -2.0, -3.0))
+        );
+        assert_eq!(
+            <Trivector>::direction(3.0, 0.0, 4.0).try_normalize(),
+            Ok(Trivector::direction(0.6, 0.0, 0.8))
+        );
+    }
+
+    #[test]
+    fn try_inverse() {
+        let v: Vector = Vector::from([0.0, 2.0, 0.0, 1.0]);
+        assert_eq!(v.try_inverse(), Ok(Vector::from([0.0, 0.5, 0.0, 0.25])));
+        assert_eq!(e0.try_inverse(), Err(PgaError::NonInvertible));
+        assert_eq!(e01.try_inverse(), Err(PgaError::NonInvertible));
+    }
+
+    #[test]
+    fn pga_2_errors() {
+        use crate::pga_2::{AnyKVector, Bivector, Pseudoscalar, Vector, e0};
+        let p = Bivector::point(1.0, 2.0);
+        assert_eq!(p.try_assert::<Bivector>(), Ok(p));
+        assert_eq!(
+            p.try_assert::<Vector>(),
+            Err(PgaError::GradeMismatch {
+                expected: Some(1),
+                found: Some(2)
+            })
+        );
+        assert_eq!(
+            <AnyKVector>::from(2.0).try_assert::<Pseudoscalar>(),
+            Err(PgaError::GradeMismatch {
+                expected: Some(3),
+                found: Some(0)
+            })
+        );
+        assert_eq!(
+            <Bivector>::from([2.0, 2.0, 4.0]).try_normalize(),
+            Ok(Bivector::point(-1.0, -2.0))
+        );
+        assert_eq!(
+            <Bivector>::from([0.0; 3]).try_normalize(),
+            Err(PgaError::IdealNormalization)
+        );
+        assert_eq!(
+            <Vector>::from([0.0, 2.0, 1.0]).try_inverse(),
+            Ok(Vector::from([0.0, 0.5, 0.25]))
+        );
+        assert_eq!(e0.try_inverse(), Err(PgaError::NonInvertible));
+    }
+}
@@ -0,0 +1,87 @@
+pub(crate) use crate::pga::downcast_grade;
+pub use crate::pga::{Algebra, Multivector, NonScalar, PgaError, SingleGrade};
+use crate::util::{Float, Lanes};
+pub use kvector::*;
+pub use multivector8::*;
+use std::marker::PhantomData;
+use std::ops::Mul;
+use std::simd::{LaneCount, SupportedLaneCount};
+pub use versor::*;
+
+// PGA(2,0,1), laid out like `pga_3` one dimension down: lines are vectors, points are bivectors,
+// the types and methods share their names with `pga_3`'s, and both implement the traits of
+// `crate::pga`, so code written against one works with the other by swapping which module it
+// imports
+
+/// Names the types of PGA(2,0,1) with coefficients of type `F` for the shared traits, as in
+/// `T: Multivector<Pga2>` or `T: Multivector<Pga2<f64>>`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Pga2<F: Float = f32>(PhantomData<F>);
+
+impl<F: Float> Algebra for Pga2<F> {
+    type Float = F;
+    type Versor = Versor<F>;
+    type AnyKVector = AnyKVector<F>;
+    const DIMENSION: u8 = 3;
+}
+
+impl<F: Float, const K: u8, const N: usize> NonScalar for KVector<K, N, F> where
+    LaneCount<N>: SupportedLaneCount
+{
+}
+impl<F: Float> NonScalar for Pseudoscalar<F> {}
+impl<F: Float> NonScalar for AnyKVector<F> {}
+impl<F: Float> NonScalar for Versor<F> {}
+impl<F: Float> NonScalar for OddVersor<F> {}
+impl<F: Float> NonScalar for Motor<F> {}
+impl<F: Float> NonScalar for Multivector8<F> {}
+
+mod kvector;
+mod multivector8;
+mod versor;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dimension() {
+        assert_eq!(e012.highest_grade(), <Pga2>::DIMENSION);
+        assert_eq!(<Pseudoscalar>::GRADE, Some(<Pga2>::DIMENSION));
+        assert_eq!(<AnyKVector>::GRADE, None);
+    }
+
+    #[test]
+    fn try_normalize() {
+        let p: Bivector = Bivector::from([2.0, -4.0, 6.0]);
+        assert_eq!(p.try_normalize(), Ok(Bivector::point(2.0, -3.0)));
+        let d: Bivector = Bivector::direction(3.0, 4.0);
+        assert_eq!(d.try_normalize(), Ok(Bivector::direction(0.6, 0.8)));
+        assert_eq!(
+            <Bivector>::from([0.0; 3]).try_normalize(),
+            Err(PgaError::IdealNormalization)
+        );
+    }
+
+    #[test]
+    fn regressive() {
+        let line: Vector = Vector::line(1.0, 0.0, 2.0);
+        assert!(line.regressive(Bivector::point(2.0, 7.0)).zero());
+        // Minus the signed distance, for a point 3 along the line's normal
+        let distance = line.regressive(Bivector::point(5.0, 1.0));
+        assert_eq!(distance, AnyKVector::Zero(-3.0));
+        let p: Bivector = Bivector::point(1.0, 2.0);
+        assert!(p.regressive(p).zero());
+    }
+
+    #[test]
+    fn project_and_reject() {
+        let line = Vector::line(1.0, 0.0, 2.0);
+        let p = Bivector::point(5.0, 1.0);
+        let parallel = line.project_onto(p).unwrap().assert::<Vector>();
+        assert_eq!(parallel.normalize(), Vector::line(1.0, 0.0, 5.0));
+        let foot = p.project_onto(line).unwrap().assert::<Bivector>();
+        assert_eq!(foot.normalize(), Bivector::point(2.0, 1.0));
+        assert!(p.reject_from(line).unwrap().is_ideal());
+    }
+}
@@ -0,0 +1,451 @@
+use crate::pga_2::*;
+use crate::util::*;
+pub use anykvector::*;
+pub use bivector::*;
+pub use pseudoscalar::*;
+pub use scalar::*;
+use std::cmp::PartialEq;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Neg, Sub};
+use std::simd::{LaneCount, SupportedLaneCount};
+use std::slice::SliceIndex;
+pub use vector::*;
+
+mod anykvector;
+mod bivector;
+mod pseudoscalar;
+mod scalar;
+mod vector;
+
+#[derive(Debug, Copy, Clone)]
+pub struct KVector<const K: u8, const N: usize, F: Float = f32>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    components: Lanes<F, N>,
+}
+
+impl<F: Float, Idx, const K: u8, const N: usize> Index<Idx> for KVector<K, N, F>
+where
+    Idx: SliceIndex<[F]>,
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Idx::Output;
+
+    fn index(&self, index: Idx) -> &Self::Output {
+        &self.components[index]
+    }
+}
+
+impl<F: Float, Idx, const K: u8, const N: usize> IndexMut<Idx> for KVector<K, N, F>
+where
+    Idx: SliceIndex<[F], Output = F>,
+    LaneCount<N>: SupportedLaneCount,
+{
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        &mut self.components[index]
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> PartialEq for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.components
+            .as_array()
+            .iter()
+            .enumerate()
+            .fold(true, |acc, (i, e)| acc && float_eq(*e, other[i]))
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> Add for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            components: self.components + rhs.components,
+        }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> Sub for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            components: self.components - rhs.components,
+        }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> Neg for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            components: -self.components,
+        }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> Mul<F> for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: F) -> Self {
+        Self {
+            components: self.components * Lanes::splat(other),
+        }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> Div<F> for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: F) -> Self {
+        Self {
+            components: self.components / Lanes::splat(other),
+        }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize, T> BitXor<T> for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+    T: SingleGrade<Pga2<F>>,
+{
+    type Output = AnyKVector<F>;
+
+    #[inline]
+    fn bitxor(self, rhs: T) -> Self::Output {
+        self.outer(rhs)
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize, T> BitAnd<T> for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+    T: SingleGrade<Pga2<F>>,
+{
+    type Output = AnyKVector<F>;
+
+    #[inline]
+    fn bitand(self, rhs: T) -> Self::Output {
+        self.regressive(rhs)
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize, T> BitOr<T> for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+    T: SingleGrade<Pga2<F>>,
+{
+    type Output = AnyKVector<F>;
+
+    #[inline]
+    fn bitor(self, rhs: T) -> Self::Output {
+        self.inner(rhs)
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> From<[F; N]> for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(value: [F; N]) -> Self {
+        Self {
+            components: Lanes::from(value),
+        }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> From<Lanes<F, N>> for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(value: Lanes<F, N>) -> Self {
+        Self { components: value }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> Multivector<Pga2<F>> for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn reverse(&self) -> Self {
+        *self * Self::reverse_multiplier()
+    }
+
+    #[inline]
+    fn grade_involution(&self) -> Self {
+        *self * F::from((-1_i32).pow(K as u32) as f32)
+    }
+
+    // basis is a binary value where the bits, from right-to-
+    // left, represent e2, e1, and e0 respectively being
+    // present in the basis being requested
+    #[inline]
+    fn e(&self, basis: u8) -> F {
+        match K {
+            1 => match basis {
+                0b010 => self[0],
+                0b001 => self[1],
+                0b100 => self[2],
+                _ => F::ZERO,
+            },
+            2 => match basis {
+                0b011 => self[0],
+                0b101 => self[1],
+                0b110 => self[2],
+                _ => F::ZERO,
+            },
+            _ => F::ZERO,
+        }
+    }
+
+    #[inline]
+    fn grade(&self, g: u8) -> AnyKVector<F> {
+        if g == K {
+            (*self).into()
+        } else {
+            AnyKVector::Zero(F::ZERO)
+        }
+    }
+
+    #[inline]
+    fn highest_grade(&self) -> u8 {
+        K
+    }
+
+    #[inline]
+    fn zero(&self) -> bool {
+        self.components == Lanes::splat(F::ZERO)
+    }
+
+    #[inline]
+    fn is_ideal(&self) -> bool {
+        self[0..Self::ideal_index()]
+            .iter()
+            .all(|f| float_eq(*f, F::ZERO))
+    }
+
+    #[inline]
+    fn dual(self) -> Versor<F> {
+        match K {
+            1 => Versor::KVec(AnyKVector::Two(Bivector::from([self[2], self[0], self[1]]))),
+            2 => Versor::KVec(AnyKVector::One(Vector::from([self[1], self[2], self[0]]))),
+            _ => Versor::from(F::ZERO),
+        }
+    }
+
+    #[inline]
+    fn undual(self) -> Versor<F> {
+        self.dual()
+    }
+
+    #[inline]
+    fn geo<T: Multivector<Pga2<F>>>(self, rhs: T) -> Versor<F> {
+        Multivector8::from(self).geo(rhs)
+    }
+
+    fn normalize(self) -> Self {
+        if K == 2 && self[0] != F::ZERO {
+            self / self[0]
+        } else {
+            self / self.magnitude()
+        }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> SingleGrade<Pga2<F>> for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    const GRADE: Option<u8> = Some(K);
+
+    #[inline]
+    fn outer<T: SingleGrade<Pga2<F>>>(self, rhs: T) -> AnyKVector<F> {
+        single_grade(Multivector8::from(self) ^ rhs)
+    }
+
+    #[inline]
+    fn inner<T: SingleGrade<Pga2<F>>>(self, rhs: T) -> AnyKVector<F> {
+        single_grade(Multivector8::from(self) | rhs)
+    }
+
+    #[inline]
+    fn try_assert<T: SingleGrade<Pga2<F>> + 'static>(self) -> Result<T, PgaError> {
+        downcast_grade(&self, K)
+    }
+}
+
+// The outer and inner products of two k-vectors only have a single grade
+fn single_grade<F: Float>(mv: Multivector8<F>) -> AnyKVector<F> {
+    match AnyKVector::try_from(mv) {
+        Ok(kv) => kv,
+        Err(_) => panic!("Product of k-vectors should be a k-vector"),
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    const fn ideal_index() -> usize {
+        match K {
+            1 => 2,
+            2 => 1,
+            _ => 0,
+        }
+    }
+
+    #[inline]
+    fn reverse_multiplier() -> F {
+        match K {
+            2 => -F::ONE,
+            _ => F::ONE,
+        }
+    }
+
+    #[inline]
+    pub fn real_part(&self) -> &[F] {
+        &self[0..Self::ideal_index()]
+    }
+
+    #[inline]
+    pub fn ideal_part(&self) -> &[F] {
+        &self[Self::ideal_index()..N]
+    }
+
+    #[inline]
+    pub fn snap_to_zero(mut self) -> Self {
+        for f in &mut self.components[0..N] {
+            if float_eq(*f, F::ZERO) {
+                *f = F::ZERO;
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn geo_vector_vector() {
+        let v1: Vector = Vector::from([1.0, 2.0, 3.0]);
+        let v2 = Vector::from([-2.0, 1.0, 0.5]);
+        // The lines are perpendicular, so the product is only the bivector part
+        let expected = Bivector::from([5.0, -2.0, -6.5]);
+        assert_eq!(v1 * v2, Versor::from(expected));
+        assert_eq!(v2 * v1, Versor::from(-expected));
+        assert_eq!(v1 * v1, Versor::from(5.0));
+    }
+
+    #[test]
+    fn join_and_meet() {
+        let p = Bivector::point(1.0, 2.0);
+        let q = Bivector::point(3.0, -1.0);
+        let line = (p & q).assert::<Vector>();
+        assert!((line ^ p).zero());
+        assert!((line ^ q).zero());
+        let other = Vector::line(1.0, 0.0, 2.0);
+        let meet = (line ^ other).assert::<Bivector>().normalize();
+        assert_eq!(meet.x(), 2.0);
+        assert!((meet ^ line).zero());
+    }
+
+    #[test]
+    fn inner_products() {
+        // Lines meeting at right angles have a zero inner product
+        assert_eq!(e1 | e2, AnyKVector::Zero(0.0));
+        assert_eq!(
+            <Vector>::line(3.0, 4.0, 1.0) | Vector::line(3.0, 4.0, -2.0),
+            AnyKVector::Zero(25.0)
+        );
+        // A point and a line give the line through the point perpendicular to it
+        let perpendicular = (Bivector::point(1.0, 1.0) | e1).assert::<Vector>();
+        assert!((perpendicular ^ Bivector::point(1.0, 1.0)).zero());
+        assert_eq!(perpendicular | e1, AnyKVector::Zero(0.0));
+    }
+
+    #[test]
+    fn dual() {
+        assert_eq!(e1.dual(), Versor::from(e20));
+        assert_eq!(e12.dual(), Versor::from(e0));
+        let p = Bivector::point(2.0, -1.0);
+        assert_eq!(p.dual().assert::<Vector>().dual(), Versor::from(p));
+        assert!((e1 ^ e1.dual().assert::<Bivector>()) == AnyKVector::from(e012));
+    }
+
+    #[test]
+    fn normalize_point() {
+        let p: Bivector = Bivector::from([2.0, -4.0, 6.0]);
+        assert_eq!(p.normalize(), Bivector::point(2.0, -3.0));
+        let line: Vector = Vector::line(3.0, 4.0, 10.0);
+        assert_eq!(line.normalize(), Vector::line(0.6, 0.8, 2.0));
+        assert!(float_eq(line.normalize().magnitude(), 1.0));
+    }
+
+    #[test]
+    fn basis_bitmasks() {
+        assert_eq!(e1.e(0b010), 1.0);
+        assert_eq!(e2.e(0b001), 1.0);
+        assert_eq!(e0.e(0b100), 1.0);
+        assert_eq!(e12.e(0b011), 1.0);
+        assert_eq!(e20.e(0b101), 1.0);
+        assert_eq!(e01.e(0b110), 1.0);
+        assert_eq!(e1.e(0b011), 0.0);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let v = Vector::from([1.0, 2.0, 3.0]);
+        assert_eq!(v + e1 - e0 * 3.0, Vector::from([2.0, 2.0, 0.0]));
+        assert_eq!(-v, v * -1.0);
+        assert_eq!(v / 2.0, Vector::from([0.5, 1.0, 1.5]));
+        assert_eq!(
+            <Bivector>::point(1.0, 2.0).reverse(),
+            -Bivector::point(1.0, 2.0)
+        );
+        assert_eq!(v.reverse(), v);
+        assert_eq!(v.grade_involution(), -v);
+    }
+
+    #[test]
+    fn real_and_ideal_parts() {
+        let v = Vector::line(3.0, 4.0, 5.0);
+        assert_eq!(v.real_part(), &[3.0, 4.0]);
+        assert_eq!(v.ideal_part(), &[5.0]);
+        let p = Bivector::point(1.0, 2.0);
+        assert_eq!(p.real_part(), &[1.0]);
+        assert_eq!(p.ideal_part(), &[-1.0, -2.0]);
+        let tiny = Vector::from([1.0, crate::util::EPSILON / 10.0, 2.0]);
+        assert_eq!(tiny.snap_to_zero()[1], 0.0);
+    }
+}
@@ -0,0 +1,326 @@
+use crate::pga_2::*;
+use crate::util::float_eq;
+use AnyKVector::*;
+use std::any::Any;
+use std::ops::{BitAnd, BitOr, BitXor, Div, Mul, Neg};
+use std::simd::{LaneCount, SupportedLaneCount};
+
+#[derive(Copy, Clone, Debug)]
+pub enum AnyKVector<F: Float = f32> {
+    Zero(F),
+    One(Vector<F>),
+    Two(Bivector<F>),
+    Three(Pseudoscalar<F>),
+}
+
+impl<F: Float> PartialEq for AnyKVector<F> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Zero(f1), Zero(f2)) => float_eq(*f1, *f2),
+            (One(v1), One(v2)) => v1 == v2,
+            (Two(bv1), Two(bv2)) => bv1 == bv2,
+            (Three(ps1), Three(ps2)) => ps1 == ps2,
+            _ => false,
+        }
+    }
+}
+
+impl<F: Float> Neg for AnyKVector<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Zero(s) => Zero(-s),
+            One(v) => One(-v),
+            Two(bv) => Two(-bv),
+            Three(ps) => Three(-ps),
+        }
+    }
+}
+
+impl<F: Float> Mul<F> for AnyKVector<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        match self {
+            Zero(s) => Zero(s * rhs),
+            One(v) => One(v * rhs),
+            Two(bv) => Two(bv * rhs),
+            Three(ps) => Three(ps * rhs),
+        }
+    }
+}
+
+impl<F: Float> Div<F> for AnyKVector<F> {
+    type Output = Self;
+
+    fn div(self, rhs: F) -> Self::Output {
+        match self {
+            Zero(s) => Zero(s / rhs),
+            One(v) => One(v / rhs),
+            Two(bv) => Two(bv / rhs),
+            Three(ps) => Three(ps / rhs),
+        }
+    }
+}
+
+impl<F: Float, T: SingleGrade<Pga2<F>>> BitXor<T> for AnyKVector<F> {
+    type Output = AnyKVector<F>;
+
+    #[inline]
+    fn bitxor(self, rhs: T) -> Self::Output {
+        self.outer(rhs)
+    }
+}
+
+impl<F: Float, T: SingleGrade<Pga2<F>>> BitAnd<T> for AnyKVector<F> {
+    type Output = AnyKVector<F>;
+
+    #[inline]
+    fn bitand(self, rhs: T) -> Self::Output {
+        self.regressive(rhs)
+    }
+}
+
+impl<F: Float, T: SingleGrade<Pga2<F>>> BitOr<T> for AnyKVector<F> {
+    type Output = AnyKVector<F>;
+    #[inline]
+    fn bitor(self, rhs: T) -> Self::Output {
+        self.inner(rhs)
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> From<KVector<K, N, F>> for AnyKVector<F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    fn from(value: KVector<K, N, F>) -> AnyKVector<F> {
+        if let Some(vector) = (&value as &dyn Any).downcast_ref::<Vector<F>>() {
+            return One(*vector);
+        }
+        if let Some(bivector) = (&value as &dyn Any).downcast_ref::<Bivector<F>>() {
+            return Two(*bivector);
+        }
+        Zero(F::ZERO)
+    }
+}
+
+impl<F: Float> From<F> for AnyKVector<F> {
+    fn from(value: F) -> Self {
+        Zero(value)
+    }
+}
+
+impl<F: Float> From<Pseudoscalar<F>> for AnyKVector<F> {
+    fn from(value: Pseudoscalar<F>) -> Self {
+        Three(value)
+    }
+}
+
+impl<F: Float> Multivector<Pga2<F>> for AnyKVector<F> {
+    #[inline]
+    fn reverse(&self) -> Self {
+        match self {
+            Zero(s) => Multivector::<Pga2<F>>::reverse(s).into(),
+            One(v) => v.reverse().into(),
+            Two(bv) => bv.reverse().into(),
+            Three(ps) => ps.reverse().into(),
+        }
+    }
+
+    #[inline]
+    fn grade_involution(&self) -> Self {
+        match self {
+            Zero(s) => Multivector::<Pga2<F>>::grade_involution(s).into(),
+            One(v) => v.grade_involution().into(),
+            Two(bv) => bv.grade_involution().into(),
+            Three(ps) => ps.grade_involution().into(),
+        }
+    }
+
+    #[inline]
+    fn e(&self, basis: u8) -> F {
+        match self {
+            Zero(s) => Multivector::<Pga2<F>>::e(s, basis),
+            One(v) => v.e(basis),
+            Two(bv) => bv.e(basis),
+            Three(ps) => ps.e(basis),
+        }
+    }
+
+    #[inline]
+    fn grade(&self, g: u8) -> Self {
+        match self {
+            Zero(s) => Multivector::<Pga2<F>>::grade(s, g),
+            One(v) => v.grade(g),
+            Two(bv) => bv.grade(g),
+            Three(ps) => ps.grade(g),
+        }
+    }
+
+    #[inline]
+    fn highest_grade(&self) -> u8 {
+        match self {
+            Zero(_) => 0,
+            One(_) => 1,
+            Two(_) => 2,
+            Three(_) => 3,
+        }
+    }
+
+    #[inline]
+    fn zero(&self) -> bool {
+        match self {
+            Zero(s) => Multivector::<Pga2<F>>::zero(s),
+            One(v) => v.zero(),
+            Two(bv) => bv.zero(),
+            Three(ps) => ps.zero(),
+        }
+    }
+
+    #[inline]
+    fn is_ideal(&self) -> bool {
+        match self {
+            Zero(s) => Multivector::<Pga2<F>>::is_ideal(s),
+            One(v) => v.is_ideal(),
+            Two(bv) => bv.is_ideal(),
+            Three(ps) => ps.is_ideal(),
+        }
+    }
+
+    #[inline]
+    fn dual(self) -> Versor<F> {
+        match self {
+            Zero(s) if s == F::ZERO => Versor::KVec(Zero(F::ZERO)),
+            Zero(s) => Versor::KVec(Three(Pseudoscalar(s))),
+            One(v) => v.dual(),
+            Two(bv) => bv.dual(),
+            Three(ps) => ps.dual(),
+        }
+    }
+
+    #[inline]
+    fn undual(self) -> Versor<F> {
+        self.dual()
+    }
+
+    #[inline]
+    fn geo<T: Multivector<Pga2<F>>>(self, rhs: T) -> Versor<F> {
+        match self {
+            Zero(s) => (rhs * s).into(),
+            One(v) => v.geo(rhs),
+            Two(bv) => bv.geo(rhs),
+            Three(ps) => ps.geo(rhs),
+        }
+    }
+
+    #[inline]
+    fn normalize(self) -> Self {
+        match self {
+            Zero(s) => Zero(s),
+            One(v) => One(v.normalize()),
+            Two(bv) => Two(bv.normalize()),
+            Three(ps) => Three(ps.normalize()),
+        }
+    }
+}
+
+impl<F: Float> SingleGrade<Pga2<F>> for AnyKVector<F> {
+    const GRADE: Option<u8> = None;
+
+    #[inline]
+    fn outer<T: SingleGrade<Pga2<F>>>(self, rhs: T) -> AnyKVector<F> {
+        match self {
+            Zero(s) => (rhs * s).into(),
+            One(v) => v.outer(rhs),
+            Two(bv) => bv.outer(rhs),
+            Three(ps) => ps.outer(rhs),
+        }
+    }
+
+    #[inline]
+    fn inner<T: SingleGrade<Pga2<F>>>(self, rhs: T) -> AnyKVector<F> {
+        match self {
+            Zero(s) => (rhs * s).into(),
+            One(v) => v.inner(rhs),
+            Two(bv) => bv.inner(rhs),
+            Three(ps) => ps.inner(rhs),
+        }
+    }
+
+    #[inline]
+    fn try_assert<T: SingleGrade<Pga2<F>> + 'static>(self) -> Result<T, PgaError> {
+        match self {
+            Zero(s) => s.try_assert::<T>(),
+            One(v) => v.try_assert::<T>(),
+            Two(bv) => bv.try_assert::<T>(),
+            Three(ps) => ps.try_assert::<T>(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_single_grades() {
+        assert_eq!(<AnyKVector>::from(2.0), Zero(2.0));
+        assert_eq!(AnyKVector::from(e1), One(e1));
+        assert_eq!(AnyKVector::from(e12), Two(e12));
+        assert_eq!(AnyKVector::from(e012), Three(e012));
+        let grades = [Zero(2.0), One(e1), Two(e12), Three(e012)].map(|kv| kv.highest_grade());
+        assert_eq!(grades, [0, 1, 2, 3]);
+        assert_ne!(Zero(0.0), One(e1 * 0.0));
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(-One(e1) * 2.0 / 4.0, One(e1 * -0.5));
+        assert_eq!(Two(e12).reverse(), Two(-e12));
+        assert_eq!(One(e2).grade_involution(), One(-e2));
+        assert_eq!(Three(e012).e(0b111), 1.0);
+        assert_eq!(Two(e20).grade(2), Two(e20));
+        assert_eq!(Two(e20).grade(1), Zero(0.0));
+    }
+
+    #[test]
+    fn dual() {
+        assert_eq!(Zero(2.0).dual(), Versor::from(e012 * 2.0));
+        assert_eq!(Zero(0.0).dual(), <Versor>::from(0.0));
+        assert_eq!(One(e1).dual(), Versor::from(e20));
+        assert_eq!(Two(e12).dual(), Versor::from(e0));
+        assert_eq!(Three(e012).dual(), Versor::from(1.0));
+        for kv in [Zero(2.0), One(e2), Two(e01), Three(e012)] {
+            assert_eq!(kv.dual().dual(), Versor::from(kv));
+        }
+    }
+
+    #[test]
+    fn products() {
+        let p = Two(Bivector::point(1.0, 0.0));
+        let q = Bivector::point(0.0, 1.0);
+        let line = (p & q).assert::<Vector>();
+        assert!((line ^ q).zero());
+        assert_eq!(One(e1) ^ e2, Two(e12));
+        assert_eq!(Zero(2.0) ^ e1, One(e1 * 2.0));
+        assert_eq!(One(e1) | e1, Zero(1.0));
+        assert_eq!(Zero(2.0) * e1, Versor::from(e1 * 2.0));
+    }
+
+    #[test]
+    fn normalize_and_assert() {
+        let p: AnyKVector = Two(Bivector::from([2.0, -4.0, 6.0]));
+        assert_eq!(p.normalize(), Two(Bivector::point(2.0, -3.0)));
+        // Scalars are left alone rather than being normalized to 1
+        assert_eq!(<AnyKVector>::Zero(3.0).normalize(), Zero(3.0));
+        assert_eq!(Two(e12).try_assert::<Bivector>(), Ok(e12));
+        assert_eq!(
+            Two(e12).try_assert::<Vector>(),
+            Err(PgaError::GradeMismatch {
+                expected: Some(1),
+                found: Some(2)
+            })
+        );
+    }
+}
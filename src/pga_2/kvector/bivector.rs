@@ -0,0 +1,86 @@
+use crate::pga_2::*;
+
+// Basis: e12, e20, e01
+pub type Bivector<F = f32> = KVector<2, 3, F>;
+
+pub const e12: Bivector = Bivector {
+    components: Lanes::from_array([1.0, 0.0, 0.0]),
+};
+pub const e20: Bivector = Bivector {
+    components: Lanes::from_array([0.0, 1.0, 0.0]),
+};
+pub const e01: Bivector = Bivector {
+    components: Lanes::from_array([0.0, 0.0, 1.0]),
+};
+
+impl<F: Float> Bivector<F> {
+    #[inline]
+    pub fn point(x: F, y: F) -> Self {
+        Self {
+            components: Lanes::from_array([F::ONE, -x, -y]),
+        }
+    }
+
+    #[inline]
+    pub fn direction(x: F, y: F) -> Self {
+        Self {
+            components: Lanes::from_array([F::ZERO, -x, -y]),
+        }
+    }
+
+    #[inline]
+    pub fn x(&self) -> F {
+        -self[1] / self[0]
+    }
+
+    #[inline]
+    pub fn y(&self) -> F {
+        -self[2] / self[0]
+    }
+
+    /// Closed-form exponential. A bivector squares to minus the square of its `e12` part, so
+    /// `exp(B) = cos(u) + sin(u) / u * B` for `u = B[0]`, and `1 + B` for an ideal point.
+    pub fn exp(&self) -> Motor<F> {
+        let u = self[0];
+        if u == F::ZERO {
+            return Motor::from([F::ONE, F::ZERO, self[1], self[2]]);
+        }
+        let (sin, cos) = u.sin_cos();
+        let sinc = sin / u;
+        Motor::from([cos, sin, self[1] * sinc, self[2] * sinc])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::float_eq;
+
+    #[test]
+    fn point_coordinates() {
+        let p: Bivector = Bivector::point(1.5, -2.0) * 4.0;
+        assert_eq!(p.x(), 1.5);
+        assert_eq!(p.y(), -2.0);
+        assert!(<Bivector>::direction(1.0, 0.0).is_ideal());
+    }
+
+    #[test]
+    fn exp() {
+        let bv: Bivector = Bivector::from([0.5, 1.0, -2.0]);
+        let m = bv.exp();
+        assert_eq!(m.log(), bv);
+        assert!(float_eq(m.magnitude(), 1.0));
+        let ideal: Bivector = Bivector::from([0.0, 1.0, -2.0]);
+        assert_eq!(ideal.exp(), Motor::from([1.0, 0.0, 1.0, -2.0]));
+    }
+
+    #[test]
+    fn project_onto_line() {
+        let p = Bivector::point(1.0, 2.0);
+        let foot = p.project_onto(e2 * 3.0).unwrap().assert::<Bivector>();
+        assert_eq!(foot.normalize(), Bivector::point(1.0, 0.0));
+        let offset = p.reject_from(e2).unwrap().assert::<Bivector>();
+        assert!(offset.is_ideal());
+        assert_eq!(p.project_onto(e0), Err(PgaError::NonInvertible));
+    }
+}
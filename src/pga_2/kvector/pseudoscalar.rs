@@ -0,0 +1,194 @@
+use crate::pga_2::*;
+use crate::util::float_eq;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Sub};
+
+#[derive(Copy, Clone, Debug)]
+pub struct Pseudoscalar<F: Float = f32>(pub F);
+
+pub const e012: Pseudoscalar = Pseudoscalar(1.0);
+
+impl<F: Float> PartialEq for Pseudoscalar<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        float_eq(self.0, other.0)
+    }
+}
+
+impl<F: Float> Neg for Pseudoscalar<F> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Pseudoscalar(-self.0)
+    }
+}
+
+impl<F: Float> Add for Pseudoscalar<F> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Pseudoscalar(self.0 + rhs.0)
+    }
+}
+
+impl<F: Float> Sub for Pseudoscalar<F> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Pseudoscalar(self.0 - rhs.0)
+    }
+}
+
+impl<F: Float> Mul<F> for Pseudoscalar<F> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: F) -> Self::Output {
+        Pseudoscalar(self.0 * rhs)
+    }
+}
+
+impl<F: Float> Div<F> for Pseudoscalar<F> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: F) -> Self::Output {
+        Pseudoscalar(self.0 / rhs)
+    }
+}
+
+impl<F: Float, T: SingleGrade<Pga2<F>>> BitXor<T> for Pseudoscalar<F> {
+    type Output = AnyKVector<F>;
+    #[inline]
+    fn bitxor(self, rhs: T) -> Self::Output {
+        self.outer(rhs)
+    }
+}
+
+impl<F: Float, T: SingleGrade<Pga2<F>>> BitAnd<T> for Pseudoscalar<F> {
+    type Output = AnyKVector<F>;
+    #[inline]
+    fn bitand(self, rhs: T) -> Self::Output {
+        self.regressive(rhs)
+    }
+}
+
+impl<F: Float, T: SingleGrade<Pga2<F>>> BitOr<T> for Pseudoscalar<F> {
+    type Output = AnyKVector<F>;
+    #[inline]
+    fn bitor(self, rhs: T) -> Self::Output {
+        self.inner(rhs)
+    }
+}
+
+impl<F: Float> Multivector<Pga2<F>> for Pseudoscalar<F> {
+    #[inline]
+    fn reverse(&self) -> Self {
+        -*self
+    }
+
+    #[inline]
+    fn grade_involution(&self) -> Self {
+        -*self
+    }
+
+    #[inline]
+    fn e(&self, basis: u8) -> F {
+        match basis {
+            0b111 => self.0,
+            _ => F::ZERO,
+        }
+    }
+
+    #[inline]
+    fn grade(&self, g: u8) -> AnyKVector<F> {
+        match g {
+            3 => AnyKVector::Three(*self),
+            _ => AnyKVector::Zero(F::ZERO),
+        }
+    }
+
+    #[inline]
+    fn highest_grade(&self) -> u8 {
+        3
+    }
+
+    #[inline]
+    fn zero(&self) -> bool {
+        float_eq(self.0, F::ZERO)
+    }
+
+    #[inline]
+    fn is_ideal(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn dual(self) -> Versor<F> {
+        Versor::KVec(AnyKVector::Zero(self.0))
+    }
+
+    #[inline]
+    fn undual(self) -> Versor<F> {
+        self.dual()
+    }
+
+    #[inline]
+    fn geo<T: Multivector<Pga2<F>>>(self, rhs: T) -> Versor<F> {
+        Multivector8::from(self).geo(rhs)
+    }
+}
+
+impl<F: Float> SingleGrade<Pga2<F>> for Pseudoscalar<F> {
+    const GRADE: Option<u8> = Some(3);
+
+    #[inline]
+    fn outer<T: SingleGrade<Pga2<F>>>(self, rhs: T) -> AnyKVector<F> {
+        (self * rhs.e(0b000)).into()
+    }
+
+    #[inline]
+    fn inner<T: SingleGrade<Pga2<F>>>(self, rhs: T) -> AnyKVector<F> {
+        match AnyKVector::try_from(Multivector8::from(self) | rhs) {
+            Ok(kv) => kv,
+            Err(_) => panic!("Inner product of k-vectors should be a k-vector"),
+        }
+    }
+
+    #[inline]
+    fn try_assert<T: SingleGrade<Pga2<F>> + 'static>(self) -> Result<T, PgaError> {
+        downcast_grade(&self, 3)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn involutions_and_grades() {
+        let ps = e012 * 2.0;
+        assert_eq!(ps.reverse(), -ps);
+        assert_eq!(ps.grade_involution(), -ps);
+        assert_eq!(ps.e(0b111), 2.0);
+        assert_eq!(ps.e(0b011), 0.0);
+        assert_eq!(ps.grade(3), AnyKVector::Three(ps));
+        assert_eq!(ps.grade(0), AnyKVector::Zero(0.0));
+        assert!(ps.is_ideal());
+        assert!((ps - ps).zero());
+    }
+
+    #[test]
+    fn dual() {
+        assert_eq!((e012 * 2.0).dual(), Versor::from(2.0));
+        assert_eq!(e012.undual(), Versor::from(1.0));
+        assert_eq!(e012.magnitude(), 1.0);
+    }
+
+    #[test]
+    fn products() {
+        assert_eq!(e1 * e012, Versor::from(e20));
+        assert_eq!(e012 | e12, AnyKVector::from(-e0));
+        assert_eq!(e012 ^ 3.0, AnyKVector::from(e012 * 3.0));
+        assert!((e012 ^ e1).zero());
+        assert!((e012 * e012).zero());
+    }
+}
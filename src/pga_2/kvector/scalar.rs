@@ -0,0 +1,158 @@
+use crate::pga_2::*;
+use crate::util::{Float, float_eq};
+use std::simd::{LaneCount, SupportedLaneCount};
+
+pub type Scalar<F = f32> = F;
+
+// A generic `impl<F: Float> Mul<Motor<F>> for F` would break the orphan rule, so scalars on the
+// left are implemented for each float type
+macro_rules! scalar_mul {
+    ($($f:ty),*) => {$(
+        impl<const K: u8, const N: usize> Mul<KVector<K, N, $f>> for $f
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = KVector<K, N, $f>;
+            #[inline]
+            fn mul(self, other: KVector<K, N, $f>) -> Self::Output {
+                other * self
+            }
+        }
+
+        scalar_mul!($f; Pseudoscalar, AnyKVector, Motor, OddVersor, Versor);
+    )*};
+    ($f:ty; $($t:ident),*) => {$(
+        impl Mul<$t<$f>> for $f {
+            type Output = $t<$f>;
+            #[inline]
+            fn mul(self, other: $t<$f>) -> Self::Output {
+                other * self
+            }
+        }
+    )*};
+}
+
+scalar_mul!(f32, f64);
+
+impl<F: Float> Multivector<Pga2<F>> for F {
+    #[inline]
+    fn e(&self, basis: u8) -> F {
+        match basis {
+            0b000 => *self,
+            _ => F::ZERO,
+        }
+    }
+
+    #[inline]
+    fn grade(&self, g: u8) -> AnyKVector<F> {
+        if g == 0 {
+            AnyKVector::Zero(*self)
+        } else {
+            AnyKVector::Zero(F::ZERO)
+        }
+    }
+
+    #[inline]
+    fn highest_grade(&self) -> u8 {
+        0
+    }
+
+    #[inline]
+    fn zero(&self) -> bool {
+        float_eq(*self, F::ZERO)
+    }
+
+    #[inline]
+    fn is_ideal(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn reverse(&self) -> Self {
+        *self
+    }
+
+    #[inline]
+    fn grade_involution(&self) -> Self {
+        *self
+    }
+
+    #[inline]
+    fn dual(self) -> Versor<F> {
+        Versor::KVec(AnyKVector::Three(Pseudoscalar(self)))
+    }
+
+    #[inline]
+    fn undual(self) -> Versor<F> {
+        Multivector::<Pga2<F>>::dual(self)
+    }
+
+    #[inline]
+    fn geo<T: Multivector<Pga2<F>>>(self, rhs: T) -> Versor<F> {
+        (rhs * self).into()
+    }
+}
+
+impl<F: Float> SingleGrade<Pga2<F>> for F {
+    const GRADE: Option<u8> = Some(0);
+
+    #[inline]
+    fn outer<T: SingleGrade<Pga2<F>>>(self, rhs: T) -> AnyKVector<F> {
+        (rhs * self).into()
+    }
+
+    #[inline]
+    fn inner<T: SingleGrade<Pga2<F>>>(self, rhs: T) -> AnyKVector<F> {
+        (rhs * self).into()
+    }
+
+    #[inline]
+    fn try_assert<T: SingleGrade<Pga2<F>> + 'static>(self) -> Result<T, PgaError> {
+        downcast_grade(&self, 0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scales() {
+        assert_eq!(2.0 * e1, e1 * 2.0);
+        assert_eq!(2.0 * e012, Pseudoscalar(2.0));
+        assert_eq!(2.0 * AnyKVector::from(e12), AnyKVector::from(e12 * 2.0));
+        assert_eq!(
+            2.0 * <Motor>::from([1.0, 0.0, 1.0, 0.0]),
+            Motor::from([2.0, 0.0, 2.0, 0.0])
+        );
+        assert_eq!(
+            SingleGrade::<Pga2>::outer(2.0, e1),
+            AnyKVector::from(e1 * 2.0)
+        );
+        assert_eq!(
+            SingleGrade::<Pga2>::inner(2.0, e1),
+            AnyKVector::from(e1 * 2.0)
+        );
+    }
+
+    #[test]
+    fn dual() {
+        assert_eq!(Multivector::<Pga2>::dual(2.0), Versor::from(e012 * 2.0));
+        assert_eq!(Multivector::<Pga2>::undual(2.0), Versor::from(e012 * 2.0));
+        assert!(!Multivector::<Pga2>::is_ideal(&2.0));
+        assert_eq!(Multivector::<Pga2>::grade(&2.0, 0), AnyKVector::Zero(2.0));
+        assert_eq!(Multivector::<Pga2>::grade(&2.0, 1), AnyKVector::Zero(0.0));
+    }
+
+    #[test]
+    fn try_assert() {
+        assert_eq!(SingleGrade::<Pga2>::try_assert::<Scalar>(2.0), Ok(2.0));
+        assert_eq!(
+            SingleGrade::<Pga2>::try_assert::<Vector>(2.0),
+            Err(PgaError::GradeMismatch {
+                expected: Some(1),
+                found: Some(0)
+            })
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use super::*;
+
+// Basis: e1, e2, e0
+pub type Vector<F = f32> = KVector<1, 3, F>;
+pub const e1: Vector = Vector {
+    components: Lanes::from_array([1.0, 0.0, 0.0]),
+};
+pub const e2: Vector = Vector {
+    components: Lanes::from_array([0.0, 1.0, 0.0]),
+};
+pub const e0: Vector = Vector {
+    components: Lanes::from_array([0.0, 0.0, 1.0]),
+};
+
+impl<F: Float> Vector<F> {
+    /// The line `ax + by = c`
+    #[inline]
+    pub const fn line(a: F, b: F, c: F) -> Self {
+        Self {
+            components: Lanes::from_array([a, b, c]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn line_contains_points() {
+        let line = Vector::line(1.0, 2.0, 5.0);
+        assert!((line ^ Bivector::point(1.0, 2.0)).zero());
+        assert!((line ^ Bivector::point(-1.0, 3.0)).zero());
+        assert!(!(line ^ Bivector::point(0.0, 0.0)).zero());
+        // Parallel lines meet at the ideal point along them
+        let meet = (line ^ Vector::line(1.0, 2.0, -1.0)).assert::<Bivector>();
+        assert!(meet.is_ideal());
+        assert!((meet ^ line).zero());
+    }
+
+    #[test]
+    fn basis_lines() {
+        assert_eq!(e1, Vector::line(1.0, 0.0, 0.0));
+        assert_eq!(e2, Vector::line(0.0, 1.0, 0.0));
+        assert!(e0.is_ideal());
+        assert!(!e1.is_ideal() && !e2.is_ideal());
+        // The axes meet at the origin
+        assert_eq!((e1 ^ e2).assert::<Bivector>(), Bivector::point(0.0, 0.0));
+    }
+
+    #[test]
+    fn inverse() {
+        let line: Vector = Vector::line(3.0, 4.0, 1.0);
+        assert_eq!(line.inverse(), Some(line / 25.0));
+        assert_eq!(line * line.try_inverse().unwrap(), Versor::from(1.0));
+        assert_eq!(e0.try_inverse(), Err(PgaError::NonInvertible));
+        assert_eq!((e0 * 2.0).try_normalize(), Ok(e0));
+    }
+}
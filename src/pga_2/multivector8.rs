@@ -0,0 +1,616 @@
+use crate::pga_2::*;
+use crate::util::float_eq;
+use crate::util::swizzle;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Neg, Sub};
+use std::slice::SliceIndex;
+
+// Basis: 1, e1, e2, e0, e12, e20, e01, e012
+#[derive(Copy, Clone, Debug)]
+pub struct Multivector8<F: Float = f32> {
+    components: Lanes<F, 8>,
+}
+
+const GRADES: [u8; 8] = [0, 1, 1, 1, 2, 2, 2, 3];
+
+// Multiplying the basis blade in lane i by the basis blade in lane GEO_INDICES[i][k]
+// gives the basis blade in lane k, scaled by GEO_SIGNS[i][k]
+pub(crate) const GEO_INDICES: [[usize; 8]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [1, 0, 4, 6, 2, 7, 3, 5],
+    [2, 4, 0, 5, 1, 3, 7, 6],
+    [3, 5, 6, 0, 7, 2, 1, 4],
+    [4, 2, 1, 7, 0, 6, 5, 3],
+    [3, 5, 6, 2, 7, 0, 4, 1],
+    [3, 5, 6, 1, 7, 4, 0, 2],
+    [3, 5, 6, 4, 7, 1, 2, 0],
+];
+
+#[rustfmt::skip]
+pub(crate) const GEO_SIGNS: [[f32; 8]; 8] = [
+    [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+    [1.0, 1.0, 1.0, -1.0, 1.0, 1.0, -1.0, 1.0],
+    [1.0, -1.0, 1.0, 1.0, -1.0, 1.0, 1.0, 1.0],
+    [0.0, 0.0, 0.0, 1.0, 0.0, -1.0, 1.0, 1.0],
+    [-1.0, 1.0, -1.0, -1.0, 1.0, 1.0, -1.0, 1.0],
+    [0.0, 0.0, 0.0, -1.0, 0.0, 1.0, 1.0, 1.0],
+    [0.0, 0.0, 0.0, 1.0, 0.0, -1.0, 1.0, 1.0],
+    [0.0, 0.0, 0.0, -1.0, 0.0, 1.0, 1.0, 1.0],
+];
+
+// The outer and inner products keep only the terms of the geometric product whose grade is
+// the sum or the absolute difference of the grades of their factors, respectively
+const OUTER_SIGNS: [[f32; 8]; 8] = grade_filtered_signs(true);
+const INNER_SIGNS: [[f32; 8]; 8] = grade_filtered_signs(false);
+
+// With e20 and e01 as the ideal bivectors, every blade's right and left complements are the
+// same, so the dual and undual are one unsigned swizzle
+const DUAL_INDICES: [usize; 8] = [7, 5, 6, 4, 3, 1, 2, 0];
+const REVERSE_SIGNS: [f32; 8] = [1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0];
+const INVOLUTION_SIGNS: [f32; 8] = [1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, -1.0];
+
+const fn grade_filtered_signs(outer: bool) -> [[f32; 8]; 8] {
+    let mut signs = GEO_SIGNS;
+    let mut i = 0;
+    while i < 8 {
+        let mut k = 0;
+        while k < 8 {
+            let (gi, gj, gk) = (GRADES[i], GRADES[GEO_INDICES[i][k]], GRADES[k]);
+            let keep = if outer {
+                gk == gi + gj
+            } else {
+                gk == gi.abs_diff(gj)
+            };
+            if !keep {
+                signs[i][k] = 0.0;
+            }
+            k += 1;
+        }
+        i += 1;
+    }
+    signs
+}
+
+// Sums, over every lane of the left-hand side, that coefficient times the correspondingly
+// permuted and sign-corrected right-hand side
+macro_rules! cayley_product {
+    ($lhs:expr, $rhs:expr, $signs:expr) => {
+        cayley_product!($lhs, $rhs, $signs; 0, 1, 2, 3, 4, 5, 6, 7)
+    };
+    ($lhs:expr, $rhs:expr, $signs:expr; $($i:literal),*) => {{
+        let mut result = Lanes::splat(F::ZERO);
+        $(
+            result += Lanes::splat($lhs[$i])
+                * Lanes::from_array($signs[$i].map(F::from))
+                * swizzle!($rhs, GEO_INDICES[$i]);
+        )*
+        result
+    }};
+}
+
+impl<F: Float> From<[F; 8]> for Multivector8<F> {
+    #[inline]
+    fn from(cs: [F; 8]) -> Self {
+        Multivector8 {
+            components: Lanes::from(cs),
+        }
+    }
+}
+
+impl<F: Float> From<Lanes<F, 8>> for Multivector8<F> {
+    #[inline]
+    fn from(cs: Lanes<F, 8>) -> Self {
+        Multivector8 { components: cs }
+    }
+}
+
+impl<F: Float> From<F> for Multivector8<F> {
+    #[inline]
+    fn from(s: F) -> Self {
+        let mut res = Self::from([F::ZERO; 8]);
+        res[0] = s;
+        res
+    }
+}
+
+impl<F: Float> From<Pseudoscalar<F>> for Multivector8<F> {
+    #[inline]
+    fn from(ps: Pseudoscalar<F>) -> Self {
+        let mut res = Self::from([F::ZERO; 8]);
+        res[7] = ps.0;
+        res
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> From<KVector<K, N, F>> for Multivector8<F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(kv: KVector<K, N, F>) -> Self {
+        AnyKVector::from(kv).into()
+    }
+}
+
+impl<F: Float> From<AnyKVector<F>> for Multivector8<F> {
+    #[inline]
+    fn from(kv: AnyKVector<F>) -> Self {
+        use AnyKVector::*;
+        let mut res = Self::from([F::ZERO; 8]);
+        match kv {
+            Zero(s) => res[0] = s,
+            One(v) => res[1..4].copy_from_slice(&v[0..3]),
+            Two(bv) => res[4..7].copy_from_slice(&bv[0..3]),
+            Three(ps) => res[7] = ps.0,
+        }
+        res
+    }
+}
+
+impl<F: Float> From<Motor<F>> for Multivector8<F> {
+    #[inline]
+    fn from(m: Motor<F>) -> Self {
+        Self::from([m[0], F::ZERO, F::ZERO, F::ZERO, m[1], m[2], m[3], F::ZERO])
+    }
+}
+
+impl<F: Float> From<OddVersor<F>> for Multivector8<F> {
+    #[inline]
+    fn from(ov: OddVersor<F>) -> Self {
+        Self::from([
+            F::ZERO,
+            ov[0],
+            ov[1],
+            ov[2],
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            ov[3],
+        ])
+    }
+}
+
+impl<F: Float> From<Versor<F>> for Multivector8<F> {
+    #[inline]
+    fn from(v: Versor<F>) -> Self {
+        use Versor::*;
+        match v {
+            Even(m) => m.into(),
+            Odd(ov) => ov.into(),
+            KVec(kv) => kv.into(),
+            Mixed(mv) => mv,
+        }
+    }
+}
+
+impl<F: Float> TryFrom<Multivector8<F>> for Motor<F> {
+    type Error = Multivector8<F>;
+
+    fn try_from(mv: Multivector8<F>) -> Result<Self, Self::Error> {
+        if !(mv.grade_is_zero(1) && mv.grade_is_zero(3)) {
+            return Err(mv);
+        }
+        Ok(Motor::from([mv[0], mv[4], mv[5], mv[6]]))
+    }
+}
+
+impl<F: Float> TryFrom<Multivector8<F>> for OddVersor<F> {
+    type Error = Multivector8<F>;
+
+    fn try_from(mv: Multivector8<F>) -> Result<Self, Self::Error> {
+        if !(mv.grade_is_zero(0) && mv.grade_is_zero(2)) {
+            return Err(mv);
+        }
+        Ok(OddVersor::from([mv[1], mv[2], mv[3], mv[7]]))
+    }
+}
+
+impl<F: Float> TryFrom<Multivector8<F>> for AnyKVector<F> {
+    type Error = Multivector8<F>;
+
+    fn try_from(mv: Multivector8<F>) -> Result<Self, Self::Error> {
+        let mut grades = (0..=3).filter(|g| !mv.grade_is_zero(*g));
+        match (grades.next(), grades.next()) {
+            (None, _) => Ok(AnyKVector::Zero(F::ZERO)),
+            (Some(g), None) => Ok(mv.grade(g)),
+            _ => Err(mv),
+        }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> TryFrom<Multivector8<F>> for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Error = Multivector8<F>;
+
+    fn try_from(mv: Multivector8<F>) -> Result<Self, Self::Error> {
+        if (0..=3).any(|g| g != K && !mv.grade_is_zero(g)) {
+            return Err(mv);
+        }
+        Ok(mv.grade(K).assert::<Self>())
+    }
+}
+
+// Generic over the float type, this would break the orphan rule
+macro_rules! scalar_try_from {
+    ($($f:ty),*) => {$(
+        impl TryFrom<Multivector8<$f>> for $f {
+            type Error = Multivector8<$f>;
+
+            fn try_from(mv: Multivector8<$f>) -> Result<Self, Self::Error> {
+                if (1..=3).any(|g| !mv.grade_is_zero(g)) {
+                    return Err(mv);
+                }
+                Ok(mv[0])
+            }
+        }
+    )*};
+}
+
+scalar_try_from!(f32, f64);
+
+impl<F: Float> TryFrom<Multivector8<F>> for Pseudoscalar<F> {
+    type Error = Multivector8<F>;
+
+    fn try_from(mv: Multivector8<F>) -> Result<Self, Self::Error> {
+        if (0..3).any(|g| !mv.grade_is_zero(g)) {
+            return Err(mv);
+        }
+        Ok(Pseudoscalar(mv[7]))
+    }
+}
+
+impl<F: Float, Idx: SliceIndex<[F]>> Index<Idx> for Multivector8<F> {
+    type Output = Idx::Output;
+    fn index(&self, index: Idx) -> &Self::Output {
+        &self.components[index]
+    }
+}
+
+impl<F: Float, Idx: SliceIndex<[F]>> IndexMut<Idx> for Multivector8<F> {
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        &mut self.components[index]
+    }
+}
+
+impl<F: Float> PartialEq for Multivector8<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.components
+            .as_array()
+            .iter()
+            .enumerate()
+            .fold(true, |acc, (i, e)| acc && float_eq(*e, other[i]))
+    }
+}
+
+impl<F: Float> Neg for Multivector8<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Multivector8 {
+            components: -self.components,
+        }
+    }
+}
+
+impl<F: Float, T: Multivector<Pga2<F>>> Add<T> for Multivector8<F> {
+    type Output = Self;
+    fn add(self, rhs: T) -> Self::Output {
+        Multivector8 {
+            components: self.components + Multivector8::from(rhs.into()).components,
+        }
+    }
+}
+
+impl<F: Float, T: Multivector<Pga2<F>>> Sub<T> for Multivector8<F> {
+    type Output = Self;
+    fn sub(self, rhs: T) -> Self::Output {
+        Multivector8 {
+            components: self.components - Multivector8::from(rhs.into()).components,
+        }
+    }
+}
+
+impl<F: Float> Mul<F> for Multivector8<F> {
+    type Output = Self;
+    fn mul(self, rhs: F) -> Self::Output {
+        Multivector8 {
+            components: self.components * Lanes::splat(rhs),
+        }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> Mul<KVector<K, N, F>> for Multivector8<F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Multivector8<F>;
+    fn mul(self, rhs: KVector<K, N, F>) -> Self::Output {
+        self.geo_full(Multivector8::from(rhs))
+    }
+}
+
+// Spelled out per type, as `Mul<T: NonScalar>` would overlap `Mul<F>`
+macro_rules! mul_via_geo_full {
+    ($($rhs:ty),*) => {$(
+        impl<F: Float> Mul<$rhs> for Multivector8<F> {
+            type Output = Multivector8<F>;
+            fn mul(self, rhs: $rhs) -> Self::Output {
+                self.geo_full(Multivector8::from(rhs))
+            }
+        }
+    )*};
+}
+
+mul_via_geo_full!(
+    Pseudoscalar<F>,
+    AnyKVector<F>,
+    Motor<F>,
+    OddVersor<F>,
+    Versor<F>,
+    Multivector8<F>
+);
+
+impl<F: Float> Div<F> for Multivector8<F> {
+    type Output = Self;
+    fn div(self, rhs: F) -> Self::Output {
+        Multivector8 {
+            components: self.components / Lanes::splat(rhs),
+        }
+    }
+}
+
+impl<F: Float, T: Multivector<Pga2<F>>> BitXor<T> for Multivector8<F> {
+    type Output = Multivector8<F>;
+    #[inline]
+    fn bitxor(self, rhs: T) -> Self::Output {
+        self.outer_full(Multivector8::from(rhs.into()))
+    }
+}
+
+impl<F: Float, T: Multivector<Pga2<F>>> BitAnd<T> for Multivector8<F> {
+    type Output = Multivector8<F>;
+    #[inline]
+    fn bitand(self, rhs: T) -> Self::Output {
+        self.regressive_full(Multivector8::from(rhs.into()))
+    }
+}
+
+impl<F: Float, T: Multivector<Pga2<F>>> BitOr<T> for Multivector8<F> {
+    type Output = Multivector8<F>;
+    #[inline]
+    fn bitor(self, rhs: T) -> Self::Output {
+        self.inner_full(Multivector8::from(rhs.into()))
+    }
+}
+
+impl<F: Float> Multivector<Pga2<F>> for Multivector8<F> {
+    // basis is a binary value where the bits, from right-to-left, represent e2, e1, and e0
+    // respectively being present in the basis being requested
+    #[inline]
+    fn e(&self, basis: u8) -> F {
+        match basis {
+            0b000 => self[0],
+            0b010 => self[1],
+            0b001 => self[2],
+            0b100 => self[3],
+            0b011 => self[4],
+            0b101 => self[5],
+            0b110 => self[6],
+            0b111 => self[7],
+            _ => F::ZERO,
+        }
+    }
+
+    #[inline]
+    fn grade(&self, g: u8) -> AnyKVector<F> {
+        match g {
+            0 => self[0].into(),
+            1 => Vector::from(self.components.extract::<1, 3>()).into(),
+            2 => Bivector::from(self.components.extract::<4, 3>()).into(),
+            3 => Pseudoscalar(self[7]).into(),
+            _ => F::ZERO.into(),
+        }
+    }
+
+    #[inline]
+    fn highest_grade(&self) -> u8 {
+        (0..=3).rev().find(|g| !self.grade_is_zero(*g)).unwrap_or(0)
+    }
+
+    #[inline]
+    fn reverse(&self) -> Self {
+        Self::from(self.components * Lanes::from_array(REVERSE_SIGNS.map(F::from)))
+    }
+
+    #[inline]
+    fn grade_involution(&self) -> Self {
+        Self::from(self.components * Lanes::from_array(INVOLUTION_SIGNS.map(F::from)))
+    }
+
+    #[inline]
+    fn zero(&self) -> bool {
+        self.components == Lanes::splat(F::ZERO)
+    }
+
+    #[inline]
+    fn is_ideal(&self) -> bool {
+        [0, 1, 2, 4].iter().all(|i| float_eq(self[*i], F::ZERO))
+    }
+
+    #[inline]
+    fn dual(self) -> Versor<F> {
+        Versor::from(self.dual_full())
+    }
+
+    #[inline]
+    fn undual(self) -> Versor<F> {
+        Versor::from(self.undual_full())
+    }
+
+    #[inline]
+    fn geo<T: Multivector<Pga2<F>>>(self, rhs: T) -> Versor<F> {
+        Versor::from(self.geo_full(Multivector8::from(rhs.into())))
+    }
+}
+
+impl<F: Float> Multivector8<F> {
+    #[inline]
+    fn grade_is_zero(&self, g: u8) -> bool {
+        GRADES
+            .iter()
+            .enumerate()
+            .all(|(i, grade)| *grade != g || self[i] == F::ZERO)
+    }
+
+    #[inline]
+    pub fn dual_full(self) -> Self {
+        Self::from(swizzle!(self.components, DUAL_INDICES))
+    }
+
+    #[inline]
+    pub fn undual_full(self) -> Self {
+        self.dual_full()
+    }
+
+    #[inline]
+    pub fn geo_full(self, rhs: Self) -> Self {
+        Self::from(cayley_product!(self, rhs.components, GEO_SIGNS))
+    }
+
+    #[inline]
+    pub fn outer_full(self, rhs: Self) -> Self {
+        Self::from(cayley_product!(self, rhs.components, OUTER_SIGNS))
+    }
+
+    #[inline]
+    pub fn inner_full(self, rhs: Self) -> Self {
+        Self::from(cayley_product!(self, rhs.components, INNER_SIGNS))
+    }
+
+    #[inline]
+    pub fn regressive_full(self, rhs: Self) -> Self {
+        self.dual_full().outer_full(rhs.dual_full()).undual_full()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample_1() -> Multivector8 {
+        Multivector8::from([1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 0.25])
+    }
+
+    fn sample_2() -> Multivector8 {
+        Multivector8::from([-0.5, 1.0, 3.0, -2.0, 0.5, 1.0, -1.0, 2.0])
+    }
+
+    fn basis(lane: usize) -> Multivector8 {
+        let mut mv = Multivector8::from([0.0; 8]);
+        mv[lane] = 1.0;
+        mv
+    }
+
+    #[test]
+    fn basis_products() {
+        let (x, y, w) = (basis(1), basis(2), basis(3));
+        assert_eq!(x * x, basis(0));
+        assert_eq!(w * w, Multivector8::from([0.0; 8]));
+        assert_eq!(x * y, basis(4));
+        assert_eq!(y * w, basis(5));
+        assert_eq!(w * x, basis(6));
+        assert_eq!(w * x * y, basis(7));
+        assert_eq!(basis(4) * basis(4), -basis(0));
+        assert_eq!(x * basis(5), basis(7));
+    }
+
+    #[test]
+    fn geo_associative() {
+        let (a, b) = (sample_1(), sample_2());
+        let c = a + b * 0.5;
+        assert_eq!((a * b) * c, a * (b * c));
+    }
+
+    #[test]
+    fn outer_and_inner_are_parts_of_geo() {
+        // For a vector, the geometric product is the sum of the inner and outer products, as
+        // long as there's no scalar part for both of them to keep
+        let v = Multivector8::from(Vector::from([1.0, -2.0, 3.0]));
+        let mut x = sample_1();
+        x[0] = 0.0;
+        assert_eq!(v * x, (v | x) + (v ^ x));
+        assert_eq!(v ^ v, Multivector8::from([0.0; 8]));
+    }
+
+    #[test]
+    fn dual_is_complement() {
+        let pseudoscalar = basis(7);
+        for lane in 0..8 {
+            let b = basis(lane);
+            assert_eq!(b ^ b.dual_full(), pseudoscalar, "lane {lane}");
+            assert_eq!(b.undual_full() ^ b, pseudoscalar, "lane {lane}");
+        }
+        assert_eq!(sample_1().dual_full().undual_full(), sample_1());
+    }
+
+    #[test]
+    fn lossless_conversions() {
+        let m: Motor = Motor::from([1.0, 2.0, 3.0, 4.0]);
+        let ov: OddVersor = OddVersor::from([1.0, 2.0, 3.0, 4.0]);
+        let bv: Bivector = Bivector::from([3.0, 2.0, 1.0]);
+        assert_eq!(Motor::try_from(Multivector8::from(m)), Ok(m));
+        assert_eq!(OddVersor::try_from(Multivector8::from(ov)), Ok(ov));
+        assert_eq!(Bivector::try_from(Multivector8::from(bv)), Ok(bv));
+        assert_eq!(
+            AnyKVector::try_from(Multivector8::from(bv)),
+            Ok(AnyKVector::Two(bv))
+        );
+        assert_eq!(Scalar::try_from(Multivector8::from(2.0)), Ok(2.0));
+        assert!(Motor::try_from(sample_1()).is_err());
+        assert!(Vector::try_from(Multivector8::from(ov)).is_err());
+    }
+
+    #[test]
+    fn reverse_and_involution() {
+        let mv = sample_1();
+        assert_eq!(
+            mv.reverse(),
+            Multivector8::from([1.0, 2.0, -1.0, 0.5, -3.0, 2.0, -1.5, -0.25])
+        );
+        assert_eq!(
+            mv.grade_involution(),
+            Multivector8::from([1.0, -2.0, 1.0, -0.5, 3.0, -2.0, 1.5, -0.25])
+        );
+        assert_eq!(mv.reverse().reverse(), mv);
+    }
+
+    #[test]
+    fn grades_and_bitmasks() {
+        let mv = sample_1();
+        assert_eq!(mv.grade(0), AnyKVector::Zero(1.0));
+        assert_eq!(mv.grade(1), AnyKVector::One(Vector::from([2.0, -1.0, 0.5])));
+        assert_eq!(
+            mv.grade(2),
+            AnyKVector::Two(Bivector::from([3.0, -2.0, 1.5]))
+        );
+        assert_eq!(mv.grade(3), AnyKVector::Three(Pseudoscalar(0.25)));
+        assert_eq!(mv.highest_grade(), 3);
+        let masks = [0b000, 0b010, 0b001, 0b100, 0b011, 0b101, 0b110, 0b111];
+        for (lane, mask) in masks.into_iter().enumerate() {
+            assert_eq!(basis(lane).e(mask), 1.0, "lane {lane}");
+        }
+    }
+
+    #[test]
+    fn regressive_full() {
+        let p: Multivector8 = Multivector8::from(Bivector::point(1.0, 2.0));
+        let q = Bivector::point(3.0, -1.0);
+        let line = Bivector::point(1.0, 2.0) & q;
+        assert_eq!(
+            p.regressive_full(Multivector8::from(q)),
+            Multivector8::from(line)
+        );
+        assert_eq!(p & q, Multivector8::from(line));
+    }
+}
@@ -0,0 +1,454 @@
+use crate::pga_2::*;
+pub use motor::*;
+pub use odd_versor::*;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::simd::{LaneCount, SupportedLaneCount};
+
+mod motor;
+mod odd_versor;
+mod products;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Versor<F: Float = f32> {
+    Even(Motor<F>),
+    Odd(OddVersor<F>),
+    KVec(AnyKVector<F>),
+    Mixed(Multivector8<F>),
+}
+
+// As in 3D, the other grades of a sandwich product only cancel up to rounding, relative to the
+// size of the product
+fn sandwiched<F: Float, T: SingleGrade<Pga2<F>> + TryFrom<Multivector8<F>>>(
+    mut product: Multivector8<F>,
+) -> Result<T, PgaError> {
+    let scale = (0..8).fold(F::ZERO, |acc, i| acc.max(product[i].abs()));
+    for i in 0..8 {
+        if product[i].abs() <= scale * F::EPSILON {
+            product[i] = F::ZERO;
+        }
+    }
+    T::try_from(product).map_err(|_| PgaError::GradeMismatch {
+        expected: T::GRADE,
+        found: AnyKVector::try_from(product)
+            .ok()
+            .map(|kv| kv.highest_grade()),
+    })
+}
+
+// The part of a sandwich product with the grade of the sandwiched element, for the operators
+fn projected<F: Float, T: TryFrom<Multivector8<F>>>(product: Multivector8<F>, grade: u8) -> T {
+    match T::try_from(Multivector8::from(product.grade(grade))) {
+        Ok(res) => res,
+        Err(_) => unreachable!("A single grade converts to the type of that grade"),
+    }
+}
+
+fn is_zero<F: Float>(slice: &[F]) -> bool {
+    slice.iter().all(|f| *f == F::ZERO)
+}
+
+impl<F: Float> From<AnyKVector<F>> for Versor<F> {
+    #[inline]
+    fn from(kv: AnyKVector<F>) -> Self {
+        Self::KVec(kv)
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> From<KVector<K, N, F>> for Versor<F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(kv: KVector<K, N, F>) -> Self {
+        Self::KVec(kv.into())
+    }
+}
+
+impl<F: Float> From<F> for Versor<F> {
+    #[inline]
+    fn from(s: F) -> Self {
+        Versor::KVec(s.into())
+    }
+}
+
+impl<F: Float> From<Pseudoscalar<F>> for Versor<F> {
+    #[inline]
+    fn from(ps: Pseudoscalar<F>) -> Self {
+        Versor::KVec(ps.into())
+    }
+}
+
+impl<F: Float> From<OddVersor<F>> for Versor<F> {
+    #[inline]
+    fn from(ov: OddVersor<F>) -> Self {
+        if ov.zero() {
+            Versor::KVec(F::ZERO.into())
+        } else if is_zero(&ov[0..3]) {
+            Versor::KVec(Pseudoscalar(ov[3]).into())
+        } else if ov[3] == F::ZERO {
+            Versor::KVec(Vector::from([ov[0], ov[1], ov[2]]).into())
+        } else {
+            Versor::Odd(ov)
+        }
+    }
+}
+
+impl<F: Float> From<Transformation<F>> for Versor<F> {
+    #[inline]
+    fn from(t: Transformation<F>) -> Self {
+        use Transformation::*;
+        match t {
+            Rotation { center, angle } => Versor::from(Motor::rotation(center, angle)),
+            Translation { direction } => Versor::from(Motor::translation(direction)),
+            Reflection { line } => Versor::from(OddVersor::reflection(line)),
+            Glide { line, direction } => Versor::from(OddVersor::glide(line, direction)),
+        }
+    }
+}
+
+impl<F: Float> From<Motor<F>> for Versor<F> {
+    #[inline]
+    fn from(m: Motor<F>) -> Self {
+        use crate::util::float_eq;
+        if is_zero(&m[1..4]) {
+            Versor::KVec(m[0].into())
+        } else if float_eq(m[0], F::ZERO) {
+            Versor::KVec(Bivector::from([m[1], m[2], m[3]]).into())
+        } else {
+            Versor::Even(m)
+        }
+    }
+}
+
+impl<F: Float> From<Multivector8<F>> for Versor<F> {
+    #[inline]
+    fn from(mv: Multivector8<F>) -> Self {
+        match (Motor::try_from(mv), OddVersor::try_from(mv)) {
+            (Ok(m), _) => Versor::from(m),
+            (_, Ok(ov)) => Versor::from(ov),
+            _ => Versor::Mixed(mv),
+        }
+    }
+}
+
+impl<F: Float> PartialEq for Versor<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        use Versor::*;
+        match (self, other) {
+            (Odd(ov1), Odd(ov2)) => ov1 == ov2,
+            (Even(m1), Even(m2)) => m1 == m2,
+            (KVec(kv1), KVec(kv2)) => kv1 == kv2,
+            (Mixed(mv1), Mixed(mv2)) => mv1 == mv2,
+            _ => false,
+        }
+    }
+}
+
+impl<F: Float> Neg for Versor<F> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        use Versor::*;
+        match self {
+            Odd(ov) => Odd(-ov),
+            Even(m) => Even(-m),
+            KVec(kv) => KVec(-kv),
+            Mixed(mv) => Mixed(-mv),
+        }
+    }
+}
+
+impl<F: Float> Mul<F> for Versor<F> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: F) -> Self::Output {
+        use Versor::*;
+        match self {
+            Odd(ov) => Odd(ov * rhs),
+            Even(m) => Even(m * rhs),
+            KVec(kv) => KVec(kv * rhs),
+            Mixed(mv) => Mixed(mv * rhs),
+        }
+    }
+}
+
+// `Mul<T: NonScalar>` would overlap `Mul<F>` for a generic float, so the geometric product is
+// implemented for each pair of types. Motors and odd versors multiply each other into a known
+// type, but anything else goes through the general geometric product
+macro_rules! mul_via_geo {
+    (@each $lhs:ty, $generics:tt, $bounds:tt; $($rhs:ty),*) => {
+        $(mul_via_geo!(@one $lhs, $generics, $bounds, $rhs);)*
+    };
+    (@one $lhs:ty, [$($generics:tt)*], [$($bounds:tt)*], $rhs:ty) => {
+        impl<F: Float, $($generics)*> Mul<$rhs> for $lhs
+        where
+            $($bounds)*
+        {
+            type Output = Versor<F>;
+            #[inline]
+            fn mul(self, rhs: $rhs) -> Versor<F> {
+                self.geo(rhs)
+            }
+        }
+    };
+    ($lhs:ty $(, $k:ident, $n:ident)?; $($rhs:ty),*) => {
+        impl<F: Float, $(const $k: u8, const $n: usize,)? const K: u8, const N: usize>
+            Mul<KVector<K, N, F>> for $lhs
+        where
+            LaneCount<N>: SupportedLaneCount,
+            $(LaneCount<$n>: SupportedLaneCount,)?
+        {
+            type Output = Versor<F>;
+            #[inline]
+            fn mul(self, rhs: KVector<K, N, F>) -> Versor<F> {
+                self.geo(rhs)
+            }
+        }
+
+        mul_via_geo!(
+            @each $lhs,
+            [$(const $k: u8, const $n: usize)?],
+            [$(LaneCount<$n>: SupportedLaneCount)?];
+            $($rhs),*
+        );
+    };
+}
+
+mul_via_geo!(KVector<J, M, F>, J, M; Pseudoscalar<F>, AnyKVector<F>, Motor<F>, OddVersor<F>, Versor<F>, Multivector8<F>);
+mul_via_geo!(Pseudoscalar<F>; Pseudoscalar<F>, AnyKVector<F>, Motor<F>, OddVersor<F>, Versor<F>, Multivector8<F>);
+mul_via_geo!(AnyKVector<F>; Pseudoscalar<F>, AnyKVector<F>, Motor<F>, OddVersor<F>, Versor<F>, Multivector8<F>);
+mul_via_geo!(Versor<F>; Pseudoscalar<F>, AnyKVector<F>, Motor<F>, OddVersor<F>, Versor<F>, Multivector8<F>);
+mul_via_geo!(Motor<F>; Pseudoscalar<F>, AnyKVector<F>, Versor<F>, Multivector8<F>);
+mul_via_geo!(OddVersor<F>; Pseudoscalar<F>, AnyKVector<F>, Versor<F>, Multivector8<F>);
+
+impl<F: Float> Div<F> for Versor<F> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: F) -> Self::Output {
+        use Versor::*;
+        match self {
+            Odd(ov) => Odd(ov / rhs),
+            Even(m) => Even(m / rhs),
+            KVec(kv) => KVec(kv / rhs),
+            Mixed(mv) => Mixed(mv / rhs),
+        }
+    }
+}
+
+impl<F: Float, T: Multivector<Pga2<F>>> Add<T> for Versor<F> {
+    type Output = Versor<F>;
+    #[inline]
+    fn add(self, rhs: T) -> Versor<F> {
+        Versor::from(Multivector8::from(self) + rhs)
+    }
+}
+
+impl<F: Float, T: Multivector<Pga2<F>>> Sub<T> for Versor<F> {
+    type Output = Versor<F>;
+    #[inline]
+    fn sub(self, rhs: T) -> Versor<F> {
+        Versor::from(Multivector8::from(self) - rhs)
+    }
+}
+
+impl<F: Float> Multivector<Pga2<F>> for Versor<F> {
+    #[inline]
+    fn e(&self, basis: u8) -> F {
+        use Versor::*;
+        match self {
+            Odd(ov) => ov.e(basis),
+            Even(m) => m.e(basis),
+            KVec(kv) => kv.e(basis),
+            Mixed(mv) => mv.e(basis),
+        }
+    }
+
+    #[inline]
+    fn grade(&self, g: u8) -> AnyKVector<F> {
+        use Versor::*;
+        match self {
+            Odd(ov) => ov.grade(g),
+            Even(m) => m.grade(g),
+            KVec(kv) => kv.grade(g),
+            Mixed(mv) => mv.grade(g),
+        }
+    }
+
+    #[inline]
+    fn highest_grade(&self) -> u8 {
+        use Versor::*;
+        match self {
+            Odd(ov) => ov.highest_grade(),
+            Even(m) => m.highest_grade(),
+            KVec(kv) => kv.highest_grade(),
+            Mixed(mv) => mv.highest_grade(),
+        }
+    }
+
+    #[inline]
+    fn zero(&self) -> bool {
+        use Versor::*;
+        match self {
+            Odd(ov) => ov.zero(),
+            Even(m) => m.zero(),
+            KVec(kv) => kv.zero(),
+            Mixed(mv) => mv.zero(),
+        }
+    }
+
+    #[inline]
+    fn is_ideal(&self) -> bool {
+        use Versor::*;
+        match self {
+            Odd(ov) => ov.is_ideal(),
+            Even(m) => m.is_ideal(),
+            KVec(kv) => kv.is_ideal(),
+            Mixed(mv) => mv.is_ideal(),
+        }
+    }
+
+    #[inline]
+    fn reverse(&self) -> Self {
+        use Versor::*;
+        match self {
+            Odd(ov) => Odd(ov.reverse()),
+            Even(m) => Even(m.reverse()),
+            KVec(kv) => KVec(kv.reverse()),
+            Mixed(mv) => Mixed(mv.reverse()),
+        }
+    }
+
+    #[inline]
+    fn normalize(self) -> Self {
+        use Versor::*;
+        match self {
+            Odd(ov) => Odd(ov.normalize()),
+            Even(m) => Even(m.normalize()),
+            KVec(kv) => KVec(kv.normalize()),
+            Mixed(mv) => Mixed(mv.normalize()),
+        }
+    }
+
+    #[inline]
+    fn grade_involution(&self) -> Self {
+        use Versor::*;
+        match self {
+            Odd(ov) => Odd(-*ov),
+            Even(m) => Even(*m),
+            KVec(kv) => KVec(kv.grade_involution()),
+            Mixed(mv) => Mixed(mv.grade_involution()),
+        }
+    }
+
+    #[inline]
+    fn dual(self) -> Versor<F> {
+        use Versor::*;
+        match self {
+            Odd(ov) => ov.dual(),
+            Even(m) => m.dual(),
+            KVec(kv) => kv.dual(),
+            Mixed(mv) => mv.dual(),
+        }
+    }
+
+    #[inline]
+    fn undual(self) -> Versor<F> {
+        self.dual()
+    }
+
+    #[inline]
+    fn geo<T: Multivector<Pga2<F>>>(self, rhs: T) -> Versor<F> {
+        use Versor::*;
+        match self {
+            Odd(ov) => ov.geo(rhs),
+            Even(m) => m.geo(rhs),
+            KVec(kv) => kv.geo(rhs),
+            Mixed(mv) => mv.geo(rhs),
+        }
+    }
+}
+
+impl<F: Float> Versor<F> {
+    pub fn assert<T: SingleGrade<Pga2<F>> + 'static>(&self) -> T {
+        use Versor::*;
+        match self {
+            Even(_) => panic!("Assert failed: motor not kvector"),
+            Odd(_) => panic!("Assert failed: oddvector not kvector"),
+            Mixed(_) => panic!("Assert failed: mixed multivector not kvector"),
+            KVec(kv) => kv.assert::<T>(),
+        }
+    }
+
+    pub fn try_assert<T: SingleGrade<Pga2<F>> + 'static>(&self) -> Result<T, PgaError> {
+        match self {
+            Versor::KVec(kv) => kv.try_assert::<T>(),
+            _ => Err(PgaError::GradeMismatch {
+                expected: T::GRADE,
+                found: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn narrows_to_kvectors() {
+        assert_eq!(Versor::from(<Motor>::from(2.0)), Versor::from(2.0));
+        assert_eq!(Versor::from(Motor::from(e12)), Versor::from(e12));
+        assert_eq!(Versor::from(OddVersor::from(e012)), Versor::from(e012));
+        let ov = OddVersor::from((e1, e012));
+        assert_eq!(Versor::from(ov), Versor::Odd(ov));
+        let Versor::Mixed(mv) = Versor::from(e1) + 1.0 else {
+            panic!("Vector + scalar should be mixed");
+        };
+        assert_eq!(mv - e1, Multivector8::from(1.0));
+    }
+
+    #[test]
+    fn try_assert() {
+        let m = Motor::trans_coords(1.0, 0.0);
+        assert_eq!(
+            Versor::from(m).try_assert::<Bivector>(),
+            Err(PgaError::GradeMismatch {
+                expected: Some(2),
+                found: None
+            })
+        );
+        assert_eq!((e1 * e2).try_assert::<Bivector>(), Ok(e12));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let v = Versor::from(Motor::from([1.0, 2.0, 0.0, 0.0]));
+        assert_eq!(-v, Versor::from(Motor::from([-1.0, -2.0, 0.0, 0.0])));
+        assert_eq!(
+            v * 2.0 / 4.0,
+            Versor::from(Motor::from([0.5, 1.0, 0.0, 0.0]))
+        );
+        assert_eq!(v - 1.0, Versor::from(e12 * 2.0));
+        assert_eq!(Versor::from(e1) + e2 - e1, Versor::from(e2));
+        assert_eq!(
+            v.reverse(),
+            Versor::from(Motor::from([1.0, -2.0, 0.0, 0.0]))
+        );
+    }
+
+    #[test]
+    fn geo() {
+        assert_eq!((e1 * e2) * e1, Versor::from(-e2));
+        let mirror = OddVersor::reflection(e1);
+        // A pure vector narrows to a k-vector, but a glide keeps its pseudoscalar part
+        assert_eq!(Versor::from(Motor::from(e12)) * mirror, Versor::from(-e2));
+        assert!(matches!(
+            Versor::from(Motor::trans_coords(0.0, 2.0)) * mirror,
+            Versor::Odd(_)
+        ));
+        assert_eq!(Versor::from(mirror) * mirror, Versor::from(1.0));
+        assert_eq!(Versor::from(e1).dual(), Versor::from(e20));
+        assert!(Versor::from(e0).is_ideal());
+    }
+}
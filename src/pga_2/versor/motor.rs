@@ -0,0 +1,504 @@
+use super::products;
+use crate::pga_2::*;
+use crate::util::float_eq;
+use std::ops::{Add, Div, Mul, Neg, Shl, Shr, Sub};
+use std::ops::{Index, IndexMut};
+use std::slice::SliceIndex;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Transformation<F: Float = f32> {
+    Rotation {
+        center: Bivector<F>,
+        angle: F,
+    },
+    Translation {
+        direction: Bivector<F>,
+    },
+    Reflection {
+        line: Vector<F>,
+    },
+    Glide {
+        line: Vector<F>,
+        direction: Bivector<F>,
+    },
+}
+
+impl<F: Float> Transformation<F> {
+    /// Counterclockwise rotation, taking the x axis towards the y axis for a positive angle
+    #[inline]
+    pub fn rotation(center: Bivector<F>, angle: F) -> Self {
+        Transformation::Rotation {
+            center: center.normalize(),
+            angle,
+        }
+    }
+    #[inline]
+    pub fn translation(direction: Bivector<F>) -> Self {
+        Transformation::Translation { direction }
+    }
+    #[inline]
+    pub fn trans_coords(x: F, y: F) -> Self {
+        Transformation::Translation {
+            direction: Bivector::direction(x, y),
+        }
+    }
+    #[inline]
+    pub fn reflection(line: Vector<F>) -> Self {
+        Transformation::Reflection {
+            line: line.normalize(),
+        }
+    }
+    /// Reflection in `line` followed by translation along `direction`, usually parallel to it
+    #[inline]
+    pub fn glide(line: Vector<F>, direction: Bivector<F>) -> Self {
+        Transformation::Glide {
+            line: line.normalize(),
+            direction,
+        }
+    }
+    /// Whether the transformation mirrors the plane, making it an `OddVersor` rather than a
+    /// `Motor`
+    #[inline]
+    pub fn is_reflection(&self) -> bool {
+        matches!(
+            self,
+            Transformation::Reflection { .. } | Transformation::Glide { .. }
+        )
+    }
+}
+
+// Basis: 1, e12, e20, e01
+#[derive(Copy, Clone, Debug)]
+pub struct Motor<F: Float = f32> {
+    pub(super) components: Lanes<F, 4>,
+}
+
+impl<F: Float> From<[F; 4]> for Motor<F> {
+    #[inline]
+    fn from(cs: [F; 4]) -> Self {
+        Motor {
+            components: Lanes::from(cs),
+        }
+    }
+}
+
+impl<F: Float> From<Lanes<F, 4>> for Motor<F> {
+    #[inline]
+    fn from(cs: Lanes<F, 4>) -> Self {
+        Motor { components: cs }
+    }
+}
+
+impl<F: Float> From<F> for Motor<F> {
+    #[inline]
+    fn from(s: F) -> Self {
+        Self::from([s, F::ZERO, F::ZERO, F::ZERO])
+    }
+}
+
+impl<F: Float> From<Bivector<F>> for Motor<F> {
+    #[inline]
+    fn from(bv: Bivector<F>) -> Self {
+        Self::from([F::ZERO, bv[0], bv[1], bv[2]])
+    }
+}
+
+impl<F: Float> From<(F, Bivector<F>)> for Motor<F> {
+    #[inline]
+    fn from(cs: (F, Bivector<F>)) -> Self {
+        Self::from([cs.0, cs.1[0], cs.1[1], cs.1[2]])
+    }
+}
+
+impl<F: Float> TryFrom<Transformation<F>> for Motor<F> {
+    type Error = PgaError;
+
+    /// The motor of a rigid motion. Reflections are odd versors, and fail with
+    /// `PgaError::ParityMismatch`.
+    #[inline]
+    fn try_from(t: Transformation<F>) -> Result<Self, Self::Error> {
+        use Transformation::*;
+        match t {
+            Rotation { center, angle } => Ok(Motor::rotation(center, angle)),
+            Translation { direction } => Ok(Motor::translation(direction)),
+            Reflection { .. } | Glide { .. } => Err(PgaError::ParityMismatch {
+                expected_odd: false,
+            }),
+        }
+    }
+}
+
+impl<F: Float, Idx: SliceIndex<[F]>> Index<Idx> for Motor<F> {
+    type Output = Idx::Output;
+    fn index(&self, index: Idx) -> &Self::Output {
+        &self.components[index]
+    }
+}
+
+impl<F: Float, Idx: SliceIndex<[F]>> IndexMut<Idx> for Motor<F> {
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        &mut self.components[index]
+    }
+}
+
+impl<F: Float> PartialEq for Motor<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.components
+            .as_array()
+            .iter()
+            .enumerate()
+            .fold(true, |acc, (i, e)| acc && float_eq(*e, other[i]))
+    }
+}
+
+impl<F: Float> Neg for Motor<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Motor {
+            components: -self.components,
+        }
+    }
+}
+
+impl<F: Float> Add for Motor<F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Motor {
+            components: self.components + rhs.components,
+        }
+    }
+}
+
+impl<F: Float> Sub for Motor<F> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Motor {
+            components: self.components - rhs.components,
+        }
+    }
+}
+
+impl<F: Float> Mul<F> for Motor<F> {
+    type Output = Self;
+    fn mul(self, rhs: F) -> Self::Output {
+        Motor {
+            components: self.components * Lanes::splat(rhs),
+        }
+    }
+}
+
+impl<F: Float> Mul for Motor<F> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Motor::from(products::even_even(self.components, rhs.components))
+    }
+}
+
+impl<F: Float> Mul<OddVersor<F>> for Motor<F> {
+    type Output = OddVersor<F>;
+    #[inline]
+    fn mul(self, rhs: OddVersor<F>) -> Self::Output {
+        OddVersor::from(products::even_odd(self.components, rhs.components))
+    }
+}
+
+impl<F: Float, T> Shr<T> for Motor<F>
+where
+    T: SingleGrade<Pga2<F>> + NonScalar + TryFrom<Multivector8<F>> + 'static,
+{
+    type Output = T;
+    fn shr(self, rhs: T) -> Self::Output {
+        let versor: Versor<F> = rhs.into();
+        super::projected(
+            Multivector8::from(self.reverse()) * versor * self,
+            rhs.highest_grade(),
+        )
+    }
+}
+
+impl<F: Float, T> Shl<T> for Motor<F>
+where
+    T: SingleGrade<Pga2<F>> + NonScalar + TryFrom<Multivector8<F>> + 'static,
+{
+    type Output = T;
+    fn shl(self, rhs: T) -> Self::Output {
+        let versor: Versor<F> = rhs.into();
+        super::projected(
+            Multivector8::from(self) * versor * self.reverse(),
+            rhs.highest_grade(),
+        )
+    }
+}
+
+impl<F: Float> Div<F> for Motor<F> {
+    type Output = Self;
+    fn div(self, rhs: F) -> Self::Output {
+        Motor {
+            components: self.components / Lanes::splat(rhs),
+        }
+    }
+}
+
+impl<F: Float> Multivector<Pga2<F>> for Motor<F> {
+    #[inline]
+    fn e(&self, basis: u8) -> F {
+        match basis {
+            0b000 => self[0],
+            0b011 => self[1],
+            0b101 => self[2],
+            0b110 => self[3],
+            _ => F::ZERO,
+        }
+    }
+
+    #[inline]
+    fn grade(&self, g: u8) -> AnyKVector<F> {
+        match g {
+            0 => self[0].into(),
+            2 => Bivector::from(self.components.extract::<1, 3>()).into(),
+            _ => F::ZERO.into(),
+        }
+    }
+
+    #[inline]
+    fn highest_grade(&self) -> u8 {
+        if self[1..4] != [F::ZERO; 3] {
+            return 2;
+        }
+        0
+    }
+
+    #[inline]
+    fn reverse(&self) -> Self {
+        Motor::from([self[0], -self[1], -self[2], -self[3]])
+    }
+
+    #[inline]
+    fn grade_involution(&self) -> Self {
+        *self
+    }
+
+    #[inline]
+    fn zero(&self) -> bool {
+        self.components == Lanes::splat(F::ZERO)
+    }
+
+    #[inline]
+    fn is_ideal(&self) -> bool {
+        self[0..2] == [F::ZERO; 2]
+    }
+
+    #[inline]
+    fn dual(self) -> Versor<F> {
+        Multivector8::from(self).dual()
+    }
+
+    #[inline]
+    fn undual(self) -> Versor<F> {
+        self.dual()
+    }
+
+    #[inline]
+    fn geo<T: Multivector<Pga2<F>>>(self, rhs: T) -> Versor<F> {
+        use Versor::*;
+        match rhs.into() {
+            Even(m) => Versor::from(self * m),
+            Odd(ov) => Versor::from(self * ov),
+            rhs => Multivector8::from(self).geo(rhs),
+        }
+    }
+}
+
+impl<F: Float> Motor<F> {
+    /// The motor of `Transformation::rotation`
+    #[inline]
+    pub fn rotation(center: Bivector<F>, angle: F) -> Self {
+        (center.normalize() * (angle / F::from(2.0))).exp()
+    }
+
+    /// The motor of `Transformation::translation`, `1 + (y * e20 - x * e01) / 2` for the
+    /// direction (x, y)
+    #[inline]
+    pub fn translation(direction: Bivector<F>) -> Self {
+        Self::from([
+            F::ONE,
+            F::ZERO,
+            -direction[2] / F::from(2.0),
+            direction[1] / F::from(2.0),
+        ])
+    }
+
+    #[inline]
+    pub fn trans_coords(x: F, y: F) -> Self {
+        Self::translation(Bivector::direction(x, y))
+    }
+
+    /// Applies the motor to `rhs`, failing with `PgaError::GradeMismatch` if parts of other
+    /// grades are left beyond rounding, as for the 3D `Motor::sandwich`
+    pub fn sandwich<T>(self, rhs: T) -> Result<T, PgaError>
+    where
+        T: SingleGrade<Pga2<F>> + NonScalar + TryFrom<Multivector8<F>> + 'static,
+    {
+        let versor: Versor<F> = rhs.into();
+        super::sandwiched(Multivector8::from(self.reverse()) * versor * self)
+    }
+
+    pub fn reverse_sandwich<T>(self, rhs: T) -> Result<T, PgaError>
+    where
+        T: SingleGrade<Pga2<F>> + NonScalar + TryFrom<Multivector8<F>> + 'static,
+    {
+        let versor: Versor<F> = rhs.into();
+        super::sandwiched(Multivector8::from(self) * versor * self.reverse())
+    }
+
+    /// Computes the bivector logarithm of the motor, such that `m.log().exp()` gives back the
+    /// normalized motor. As in 3D, whichever of the motor and its negation has a non-negative
+    /// scalar part is used, giving the smaller of the two rotations.
+    pub fn log(self) -> Bivector<F> {
+        let mut m = self.normalize();
+        if m[0] < F::ZERO && !float_eq(m[0], F::ZERO) {
+            m = -m;
+        }
+        let angle = m[1].atan2(m[0]);
+        let sinc = if float_eq(angle, F::ZERO) {
+            F::ONE
+        } else {
+            angle.sin() / angle
+        };
+        Bivector::from([angle, m[2] / sinc, m[3] / sinc])
+    }
+
+    /// Linear interpolation along the rotation or translation taking `a` to `b`
+    pub fn interpolate(a: Motor<F>, b: Motor<F>, t: F) -> Motor<F> {
+        let a = a.normalize();
+        let delta = a.reverse() * b.normalize();
+        a * (delta.log() * t).exp()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn rotate_point() {
+        let r = Motor::rotation(e12, PI / 2.0);
+        assert_eq!(r >> Bivector::point(1.0, 0.0), Bivector::point(0.0, 1.0));
+        assert_eq!(r << Bivector::point(0.0, 1.0), Bivector::point(1.0, 0.0));
+        let about = Bivector::point(1.0, 1.0);
+        let r = Motor::rotation(about, PI);
+        assert_eq!(r >> Bivector::point(2.0, 1.0), Bivector::point(0.0, 1.0));
+        assert_eq!(r >> about, about);
+    }
+
+    #[test]
+    fn rotate_point_in_f64() {
+        let r = Motor::<f64>::rotation(Bivector::point(0.0, 0.0), std::f64::consts::FRAC_PI_2);
+        assert_eq!(r >> Bivector::point(1.0, 0.0), Bivector::point(0.0, 1.0));
+        assert_eq!(r.log().exp(), r);
+    }
+
+    #[test]
+    fn translate() {
+        let t: Motor = Motor::trans_coords(3.0, -1.0);
+        assert_eq!(t >> Bivector::point(1.0, 2.0), Bivector::point(4.0, 1.0));
+        // Directions don't move, and lines move with the points on them
+        let d = Bivector::direction(1.0, 1.0);
+        assert_eq!(t >> d, d);
+        assert_eq!(
+            t >> Vector::line(1.0, 0.0, 2.0),
+            Vector::line(1.0, 0.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn sandwich_large_motor() {
+        let m: Motor = Motor::from([312.5, -820.25, 97.0, 401.75]);
+        let p = Bivector::point(12.5, -3.25);
+        assert_eq!(m.sandwich(p), Ok(m >> p));
+        assert_eq!(m.reverse_sandwich(p), Ok(m << p));
+        assert_eq!(
+            Motor::from([f32::NAN; 4]).sandwich(p),
+            Err(PgaError::GradeMismatch {
+                expected: Some(2),
+                found: None
+            })
+        );
+    }
+
+    #[test]
+    fn compose_left_to_right() {
+        let r = Motor::rotation(e12, PI / 2.0);
+        let t = Motor::trans_coords(1.0, 0.0);
+        let p = Bivector::point(1.0, 0.0);
+        assert_eq!((r * t) >> p, t >> (r >> p));
+        assert_eq!((r * t) >> p, Bivector::point(1.0, 1.0));
+    }
+
+    #[test]
+    fn log_and_interpolate() {
+        let a = Motor::trans_coords(2.0, 0.0);
+        let b = Motor::rotation(Bivector::point(1.0, 1.0), PI / 3.0) * a;
+        assert_eq!(b.log().exp(), b.normalize());
+        assert_eq!(a.log().exp(), a);
+        assert_eq!(Motor::interpolate(a, b, 0.0), a);
+        assert_eq!(Motor::interpolate(a, b, 1.0), b.normalize());
+        let half = Motor::interpolate(Motor::from(1.0), a, 0.5);
+        assert_eq!(half >> e12, Bivector::point(1.0, 0.0));
+    }
+
+    #[test]
+    fn grades() {
+        let m = Motor::from((2.0, e12));
+        assert_eq!(m.grade(0), AnyKVector::Zero(2.0));
+        assert_eq!(m.grade(2), AnyKVector::Two(e12));
+        assert_eq!(m.grade(1), AnyKVector::Zero(0.0));
+        assert_eq!(m.highest_grade(), 2);
+        assert_eq!(Motor::from(2.0).highest_grade(), 0);
+        assert_eq!(m.reverse(), Motor::from((2.0, -e12)));
+        assert_eq!((m.e(0b000), m.e(0b011)), (2.0, 1.0));
+        assert!(Motor::from(e20).is_ideal());
+        assert!(!m.is_ideal());
+    }
+
+    #[test]
+    fn rotate_line() {
+        // The y axis, pointing its normal along x, turns to the x axis with its normal along y
+        let r = Motor::rotation(e12, PI / 2.0);
+        assert_eq!(r >> e1, e2);
+        assert_eq!(r << e2, e1);
+        let m = r * Motor::trans_coords(1.0, 2.0);
+        assert_eq!(
+            m >> Vector::line(1.0, 0.0, 1.0),
+            Vector::line(0.0, 1.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn from_transformation() {
+        let center: Bivector = Bivector::point(1.0, -1.0) * 2.0;
+        assert_eq!(
+            Motor::try_from(Transformation::rotation(center, 0.5)),
+            Ok(Motor::rotation(center, 0.5))
+        );
+        let t: Transformation = Transformation::trans_coords(1.0, 2.0);
+        assert!(!t.is_reflection());
+        assert_eq!(
+            Motor::try_from(t).unwrap() * Motor::trans_coords(3.0, -1.0),
+            Motor::trans_coords(4.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn reverse_undoes() {
+        let m: Motor =
+            Motor::rotation(Bivector::point(2.0, 1.0), 0.7) * Motor::trans_coords(-1.0, 3.0);
+        assert_eq!(m * m.reverse(), Motor::from(1.0));
+        let p = Bivector::point(0.5, -2.0);
+        assert_eq!(m.reverse() >> (m >> p), p);
+        assert_eq!(m << (m >> p), p);
+    }
+}
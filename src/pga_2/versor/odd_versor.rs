@@ -0,0 +1,416 @@
+use super::products;
+use crate::pga_2::*;
+use crate::util::float_eq;
+use std::ops::{Add, Div, Mul, Neg, Shl, Shr, Sub};
+use std::ops::{Index, IndexMut};
+use std::slice::SliceIndex;
+
+// Basis: e1, e2, e0, e012
+#[derive(Clone, Copy, Debug)]
+pub struct OddVersor<F: Float = f32> {
+    pub(super) components: Lanes<F, 4>,
+}
+
+impl<F: Float> From<[F; 4]> for OddVersor<F> {
+    #[inline]
+    fn from(cs: [F; 4]) -> Self {
+        OddVersor {
+            components: Lanes::from(cs),
+        }
+    }
+}
+
+impl<F: Float> From<Lanes<F, 4>> for OddVersor<F> {
+    #[inline]
+    fn from(cs: Lanes<F, 4>) -> Self {
+        OddVersor { components: cs }
+    }
+}
+
+impl<F: Float> From<Vector<F>> for OddVersor<F> {
+    #[inline]
+    fn from(v: Vector<F>) -> Self {
+        Self::from([v[0], v[1], v[2], F::ZERO])
+    }
+}
+
+impl<F: Float> From<Pseudoscalar<F>> for OddVersor<F> {
+    #[inline]
+    fn from(ps: Pseudoscalar<F>) -> Self {
+        Self::from([F::ZERO, F::ZERO, F::ZERO, ps.0])
+    }
+}
+
+impl<F: Float> From<(Vector<F>, Pseudoscalar<F>)> for OddVersor<F> {
+    #[inline]
+    fn from(cs: (Vector<F>, Pseudoscalar<F>)) -> Self {
+        Self::from([cs.0[0], cs.0[1], cs.0[2], cs.1.0])
+    }
+}
+
+impl<F: Float> TryFrom<Transformation<F>> for OddVersor<F> {
+    type Error = PgaError;
+
+    /// The odd versor of a reflection. Rigid motions are motors, and fail with
+    /// `PgaError::ParityMismatch`.
+    #[inline]
+    fn try_from(t: Transformation<F>) -> Result<Self, Self::Error> {
+        use Transformation::*;
+        match t {
+            Reflection { line } => Ok(OddVersor::reflection(line)),
+            Glide { line, direction } => Ok(OddVersor::glide(line, direction)),
+            Rotation { .. } | Translation { .. } => {
+                Err(PgaError::ParityMismatch { expected_odd: true })
+            }
+        }
+    }
+}
+
+impl<F: Float, Idx: SliceIndex<[F]>> Index<Idx> for OddVersor<F> {
+    type Output = Idx::Output;
+    fn index(&self, index: Idx) -> &Self::Output {
+        &self.components[index]
+    }
+}
+
+impl<F: Float, Idx: SliceIndex<[F]>> IndexMut<Idx> for OddVersor<F> {
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        &mut self.components[index]
+    }
+}
+
+impl<F: Float> PartialEq for OddVersor<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.components
+            .as_array()
+            .iter()
+            .enumerate()
+            .fold(true, |acc, (i, e)| acc && float_eq(*e, other[i]))
+    }
+}
+
+impl<F: Float> Neg for OddVersor<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        OddVersor {
+            components: -self.components,
+        }
+    }
+}
+
+impl<F: Float> Add for OddVersor<F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        OddVersor {
+            components: self.components + rhs.components,
+        }
+    }
+}
+
+impl<F: Float> Sub for OddVersor<F> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        OddVersor {
+            components: self.components - rhs.components,
+        }
+    }
+}
+
+impl<F: Float> Mul<F> for OddVersor<F> {
+    type Output = Self;
+    fn mul(self, rhs: F) -> Self::Output {
+        OddVersor {
+            components: self.components * Lanes::splat(rhs),
+        }
+    }
+}
+
+impl<F: Float> Mul<Motor<F>> for OddVersor<F> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Motor<F>) -> Self::Output {
+        OddVersor::from(products::odd_even(self.components, rhs.components))
+    }
+}
+
+impl<F: Float> Mul for OddVersor<F> {
+    type Output = Motor<F>;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Motor::from(products::odd_odd(self.components, rhs.components))
+    }
+}
+
+impl<F: Float, T> Shr<T> for OddVersor<F>
+where
+    T: SingleGrade<Pga2<F>> + NonScalar + TryFrom<Multivector8<F>> + 'static,
+{
+    type Output = T;
+    fn shr(self, rhs: T) -> Self::Output {
+        let versor: Versor<F> = rhs.into();
+        let res: T = super::projected(
+            Multivector8::from(self.reverse()) * versor * self,
+            rhs.highest_grade(),
+        );
+        mirrored(res, rhs.highest_grade())
+    }
+}
+
+impl<F: Float, T> Shl<T> for OddVersor<F>
+where
+    T: SingleGrade<Pga2<F>> + NonScalar + TryFrom<Multivector8<F>> + 'static,
+{
+    type Output = T;
+    fn shl(self, rhs: T) -> Self::Output {
+        let versor: Versor<F> = rhs.into();
+        let res: T = super::projected(
+            Multivector8::from(self) * versor * self.reverse(),
+            rhs.highest_grade(),
+        );
+        mirrored(res, rhs.highest_grade())
+    }
+}
+
+impl<F: Float> Div<F> for OddVersor<F> {
+    type Output = Self;
+    fn div(self, rhs: F) -> Self::Output {
+        OddVersor {
+            components: self.components / Lanes::splat(rhs),
+        }
+    }
+}
+
+impl<F: Float> Multivector<Pga2<F>> for OddVersor<F> {
+    #[inline]
+    fn e(&self, basis: u8) -> F {
+        match basis {
+            0b010 => self[0],
+            0b001 => self[1],
+            0b100 => self[2],
+            0b111 => self[3],
+            _ => F::ZERO,
+        }
+    }
+
+    #[inline]
+    fn grade(&self, g: u8) -> AnyKVector<F> {
+        match g {
+            1 => Vector::from(self.components.extract::<0, 3>()).into(),
+            3 => Pseudoscalar(self[3]).into(),
+            _ => F::ZERO.into(),
+        }
+    }
+
+    #[inline]
+    fn highest_grade(&self) -> u8 {
+        if self[3] != F::ZERO {
+            return 3;
+        }
+        if self[0..3] != [F::ZERO; 3] {
+            return 1;
+        }
+        0
+    }
+
+    #[inline]
+    fn reverse(&self) -> Self {
+        OddVersor::from([self[0], self[1], self[2], -self[3]])
+    }
+
+    #[inline]
+    fn grade_involution(&self) -> Self {
+        -*self
+    }
+
+    #[inline]
+    fn zero(&self) -> bool {
+        self.components == Lanes::splat(F::ZERO)
+    }
+
+    #[inline]
+    fn is_ideal(&self) -> bool {
+        self[0..2] == [F::ZERO; 2]
+    }
+
+    #[inline]
+    fn dual(self) -> Versor<F> {
+        Multivector8::from(self).dual()
+    }
+
+    #[inline]
+    fn undual(self) -> Versor<F> {
+        self.dual()
+    }
+
+    #[inline]
+    fn geo<T: Multivector<Pga2<F>>>(self, rhs: T) -> Versor<F> {
+        use Versor::*;
+        match rhs.into() {
+            Even(m) => Versor::from(self * m),
+            Odd(ov) => Versor::from(self * ov),
+            rhs => Multivector8::from(self).geo(rhs),
+        }
+    }
+}
+
+impl<F: Float> OddVersor<F> {
+    /// The odd versor of `Transformation::reflection`
+    #[inline]
+    pub fn reflection(line: Vector<F>) -> Self {
+        OddVersor::from(line.normalize())
+    }
+
+    /// The odd versor of `Transformation::glide`
+    #[inline]
+    pub fn glide(line: Vector<F>, direction: Bivector<F>) -> Self {
+        OddVersor::reflection(line) * Motor::translation(direction)
+    }
+
+    /// Applies the reflection, giving the mirror image of `rhs`: lines have their normals
+    /// mirrored, and points keep their weight. The bare sandwich gets both backwards, so they're
+    /// negated.
+    pub fn sandwich<T>(self, rhs: T) -> Result<T, PgaError>
+    where
+        T: SingleGrade<Pga2<F>> + NonScalar + TryFrom<Multivector8<F>> + 'static,
+    {
+        let versor: Versor<F> = rhs.into();
+        super::sandwiched(Multivector8::from(self.reverse()) * versor * self)
+            .map(|res| mirrored(res, rhs.highest_grade()))
+    }
+
+    /// Applies the inverse of the reflection, with the same sign correction as `sandwich`
+    pub fn reverse_sandwich<T>(self, rhs: T) -> Result<T, PgaError>
+    where
+        T: SingleGrade<Pga2<F>> + NonScalar + TryFrom<Multivector8<F>> + 'static,
+    {
+        let versor: Versor<F> = rhs.into();
+        super::sandwiched(Multivector8::from(self) * versor * self.reverse())
+            .map(|res| mirrored(res, rhs.highest_grade()))
+    }
+}
+
+// Negates the grades whose orientation the bare sandwich gets backwards
+#[inline]
+fn mirrored<F: Float, T: Mul<F, Output = T>>(res: T, grade: u8) -> T {
+    if grade == 1 || grade == 2 {
+        res * -F::ONE
+    } else {
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reflect() {
+        let mirror: OddVersor = OddVersor::reflection(Vector::line(1.0, 0.0, 1.0));
+        assert_eq!(
+            mirror >> Bivector::point(3.0, 2.0),
+            Bivector::point(-1.0, 2.0)
+        );
+        assert_eq!(
+            mirror >> Vector::line(1.0, 1.0, 0.0),
+            Vector::line(-1.0, 1.0, -2.0)
+        );
+        assert_eq!(
+            mirror >> Bivector::direction(1.0, 1.0),
+            Bivector::direction(-1.0, 1.0)
+        );
+        assert_eq!(mirror * mirror, Motor::from(1.0));
+    }
+
+    #[test]
+    fn glide() {
+        let glide: OddVersor =
+            OddVersor::glide(Vector::line(0.0, 1.0, 0.0), Bivector::direction(2.0, 0.0));
+        assert_eq!(
+            glide >> Bivector::point(1.0, 3.0),
+            Bivector::point(3.0, -3.0)
+        );
+        assert_eq!(
+            glide << Bivector::point(3.0, -3.0),
+            Bivector::point(1.0, 3.0)
+        );
+        // Twice along the same glide is a plain translation
+        let twice = Motor::trans_coords(4.0, 0.0);
+        assert_eq!(glide * glide, twice);
+    }
+
+    #[test]
+    fn parity_mismatch() {
+        let reflection: Transformation = Transformation::reflection(Vector::line(1.0, 0.0, 1.0));
+        let translation: Transformation = Transformation::trans_coords(1.0, 2.0);
+        assert_eq!(
+            Motor::try_from(reflection),
+            Err(PgaError::ParityMismatch {
+                expected_odd: false
+            })
+        );
+        assert_eq!(
+            OddVersor::try_from(translation),
+            Err(PgaError::ParityMismatch { expected_odd: true })
+        );
+        assert_eq!(
+            Motor::try_from(translation),
+            Ok(Motor::trans_coords(1.0, 2.0))
+        );
+        assert!(matches!(
+            Versor::from(reflection),
+            Versor::Odd(_) | Versor::KVec(_)
+        ));
+    }
+
+    #[test]
+    fn grades() {
+        let ov = OddVersor::from((e1, e012 * 2.0));
+        assert_eq!(ov.grade(1), AnyKVector::One(e1));
+        assert_eq!(ov.grade(3), AnyKVector::Three(e012 * 2.0));
+        assert_eq!(ov.grade(2), AnyKVector::Zero(0.0));
+        assert_eq!(ov.highest_grade(), 3);
+        assert_eq!(OddVersor::from(e2).highest_grade(), 1);
+        assert_eq!(ov.reverse(), OddVersor::from((e1, e012 * -2.0)));
+        assert_eq!(ov.grade_involution(), -ov);
+        assert_eq!((ov.e(0b010), ov.e(0b111)), (1.0, 2.0));
+        assert!(OddVersor::from(e0).is_ideal());
+    }
+
+    #[test]
+    fn reflection_is_its_own_inverse() {
+        let mirror: OddVersor = OddVersor::reflection(Vector::line(1.0, 2.0, 3.0));
+        let p = Bivector::point(-1.0, 4.0);
+        assert_eq!(mirror >> (mirror >> p), p);
+        assert_eq!(mirror << p, mirror >> p);
+        // Points on the mirror stay put
+        let on = Bivector::point(1.0, 1.0);
+        assert_eq!(mirror >> on, on);
+    }
+
+    #[test]
+    fn two_reflections_rotate() {
+        let first = OddVersor::reflection(e2);
+        let second = OddVersor::reflection(Vector::line(1.0, -1.0, 0.0));
+        let m = first * second;
+        assert_eq!(m >> Bivector::point(1.0, 0.0), Bivector::point(0.0, 1.0));
+        assert_eq!(m >> Bivector::point(0.0, 1.0), Bivector::point(-1.0, 0.0));
+        let t = Motor::trans_coords(0.0, 2.0);
+        let p = Bivector::point(1.0, 1.0);
+        assert_eq!((t * first) >> p, first >> (t >> p));
+    }
+
+    #[test]
+    fn from_transformation() {
+        let glide = Transformation::glide(e2 * 2.0, Bivector::direction(1.0, 0.0));
+        assert!(glide.is_reflection());
+        assert_eq!(
+            OddVersor::try_from(glide),
+            Ok(OddVersor::glide(e2, Bivector::direction(1.0, 0.0)))
+        );
+        assert_eq!(
+            OddVersor::try_from(Transformation::reflection(e1)),
+            Ok(OddVersor::from(e1))
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::pga_2::multivector8::{GEO_INDICES, GEO_SIGNS};
+use crate::util::{Float, Lanes, swizzle};
+
+// Lanes of the motor and odd versor layouts within the full 8-component layout
+const EVEN_LANES: [usize; 4] = [0, 4, 5, 6];
+const ODD_LANES: [usize; 4] = [1, 2, 3, 7];
+
+// The full Cayley table restricted to 4-lane operands: multiplying lane i of the left-hand side
+// by lane INDICES[i][k] of the right-hand side contributes to lane k, scaled by SIGNS[i][k]
+struct VersorTable {
+    indices: [[usize; 4]; 4],
+    signs: [[f32; 4]; 4],
+}
+
+const fn versor_table(lhs: [usize; 4], rhs: [usize; 4], out: [usize; 4]) -> VersorTable {
+    let mut table = VersorTable {
+        indices: [[0; 4]; 4],
+        signs: [[0.0; 4]; 4],
+    };
+    let mut i = 0;
+    while i < 4 {
+        let mut k = 0;
+        while k < 4 {
+            let full_rhs = GEO_INDICES[lhs[i]][out[k]];
+            let mut j = 0;
+            while j < 4 {
+                if rhs[j] == full_rhs {
+                    table.indices[i][k] = j;
+                    table.signs[i][k] = GEO_SIGNS[lhs[i]][out[k]];
+                }
+                j += 1;
+            }
+            k += 1;
+        }
+        i += 1;
+    }
+    table
+}
+
+const EVEN_EVEN: VersorTable = versor_table(EVEN_LANES, EVEN_LANES, EVEN_LANES);
+const EVEN_ODD: VersorTable = versor_table(EVEN_LANES, ODD_LANES, ODD_LANES);
+const ODD_EVEN: VersorTable = versor_table(ODD_LANES, EVEN_LANES, ODD_LANES);
+const ODD_ODD: VersorTable = versor_table(ODD_LANES, ODD_LANES, EVEN_LANES);
+
+macro_rules! versor_product {
+    ($lhs:expr, $rhs:expr, $table:expr) => {{
+        let mut result = Lanes::splat(F::ZERO);
+        versor_product!(result, $lhs, $rhs, $table; 0, 1, 2, 3);
+        result
+    }};
+    ($result:ident, $lhs:expr, $rhs:expr, $table:expr; $($i:literal),*) => {
+        $(
+            $result += Lanes::splat($lhs[$i])
+                * Lanes::from_array($table.signs[$i].map(F::from))
+                * swizzle!($rhs, $table.indices[$i]);
+        )*
+    };
+}
+
+/// Motor * motor
+#[inline]
+pub(crate) fn even_even<F: Float>(lhs: Lanes<F, 4>, rhs: Lanes<F, 4>) -> Lanes<F, 4> {
+    versor_product!(lhs, rhs, EVEN_EVEN)
+}
+
+/// Motor * odd versor
+#[inline]
+pub(crate) fn even_odd<F: Float>(lhs: Lanes<F, 4>, rhs: Lanes<F, 4>) -> Lanes<F, 4> {
+    versor_product!(lhs, rhs, EVEN_ODD)
+}
+
+/// Odd versor * motor
+#[inline]
+pub(crate) fn odd_even<F: Float>(lhs: Lanes<F, 4>, rhs: Lanes<F, 4>) -> Lanes<F, 4> {
+    versor_product!(lhs, rhs, ODD_EVEN)
+}
+
+/// Odd versor * odd versor
+#[inline]
+pub(crate) fn odd_odd<F: Float>(lhs: Lanes<F, 4>, rhs: Lanes<F, 4>) -> Lanes<F, 4> {
+    versor_product!(lhs, rhs, ODD_ODD)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::pga_2::*;
+
+    #[test]
+    fn matches_full_product() {
+        let (m, ov) = (
+            Motor::from([0.3, -1.2, 0.7, 2.0]),
+            OddVersor::from([-0.6, 0.8, 1.5, -0.2]),
+        );
+        let (m2, ov2) = (m * 0.5 + Motor::from(e12), ov.reverse());
+        let full = |a: Multivector8, b: Multivector8| a.geo_full(b);
+        assert_eq!(
+            Multivector8::from(m * m2),
+            full(Multivector8::from(m), Multivector8::from(m2))
+        );
+        assert_eq!(
+            Multivector8::from(m * ov),
+            full(Multivector8::from(m), Multivector8::from(ov))
+        );
+        assert_eq!(
+            Multivector8::from(ov * m),
+            full(Multivector8::from(ov), Multivector8::from(m))
+        );
+        assert_eq!(
+            Multivector8::from(ov * ov2),
+            full(Multivector8::from(ov), Multivector8::from(ov2))
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::pga_3::*;
+use std::ops::{BitAnd, BitXor, Deref, Neg};
+use std::simd::{LaneCount, SupportedLaneCount};
+
+/// A k-vector which is known to be a blade, ie the outer product of vectors. Blades can only be
+/// built from coordinates, by joins and meets of other blades, or by checking an arbitrary
+/// k-vector with `Blade::new`; `Blade::unchecked` is the explicit escape hatch for callers that
+/// know better than the check.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Blade<T>(T);
+
+impl<F: Float, const K: u8, const N: usize> Blade<KVector<K, N, F>>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    pub fn new(kv: KVector<K, N, F>) -> Option<Self> {
+        kv.is_blade().then_some(Blade(kv))
+    }
+
+    #[inline]
+    pub const fn unchecked(kv: KVector<K, N, F>) -> Self {
+        Blade(kv)
+    }
+
+    #[inline]
+    pub const fn into_inner(self) -> KVector<K, N, F> {
+        self.0
+    }
+
+    #[inline]
+    pub fn normalize(self) -> Self {
+        Blade(self.0.normalize())
+    }
+}
+
+impl<T> Deref for Blade<T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Blade<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Blade(-self.0)
+    }
+}
+
+// The basis blades in any precision, unlike the f32 constants `e1`, `e12`, etc
+macro_rules! basis_blades {
+    ($kvector:ident, $n:literal; $($name:ident = $lane:literal),*) => {
+        impl<F: Float> Blade<$kvector<F>> {
+            $(
+                pub const $name: Self = {
+                    let mut cs = [F::ZERO; $n];
+                    cs[$lane] = F::ONE;
+                    Blade($kvector::from_array(cs))
+                };
+            )*
+        }
+    };
+}
+
+basis_blades!(Vector, 4; e1 = 0, e2 = 1, e3 = 2, e0 = 3);
+basis_blades!(Bivector, 6; e12 = 0, e31 = 1, e23 = 2, e01 = 3, e02 = 4, e03 = 5);
+basis_blades!(Trivector, 4; e123 = 0, e032 = 1, e013 = 2, e021 = 3);
+
+impl<F: Float> Blade<Vector<F>> {
+    /// The plane `ax + by + cz = d`
+    #[inline]
+    pub fn plane(a: F, b: F, c: F, d: F) -> Self {
+        Blade(Vector::from([a, b, c, d]))
+    }
+}
+
+impl<F: Float> Blade<Trivector<F>> {
+    #[inline]
+    pub fn point(x: F, y: F, z: F) -> Self {
+        Blade(Trivector::point(x, y, z))
+    }
+
+    #[inline]
+    pub fn direction(x: F, y: F, z: F) -> Self {
+        Blade(Trivector::direction(x, y, z))
+    }
+}
+
+// In 3D PGA the meet (^) and join (&) of two blades is always a blade, so these are the only
+// operations needed to build any blade from points or planes.
+macro_rules! blade_product {
+    ($trait:ident, $fn:ident, $lhs:ident, $rhs:ident => $output:ident) => {
+        impl<F: Float> $trait<Blade<$rhs<F>>> for Blade<$lhs<F>> {
+            type Output = Blade<$output<F>>;
+            #[inline]
+            fn $fn(self, rhs: Blade<$rhs<F>>) -> Self::Output {
+                Blade(self.0.$fn(rhs.0).assert::<$output<F>>())
+            }
+        }
+    };
+}
+
+blade_product!(BitXor, bitxor, Vector, Vector => Bivector);
+blade_product!(BitXor, bitxor, Vector, Bivector => Trivector);
+blade_product!(BitXor, bitxor, Bivector, Vector => Trivector);
+blade_product!(BitAnd, bitand, Trivector, Trivector => Bivector);
+blade_product!(BitAnd, bitand, Trivector, Bivector => Vector);
+blade_product!(BitAnd, bitand, Bivector, Trivector => Vector);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new_rejects_non_blades() {
+        let screw: Bivector = Bivector::from([1.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(Blade::new(screw), None);
+        assert_eq!(Blade::new(e12 + e01), Some(Blade::unchecked(e12 + e01)));
+        assert_eq!(Blade::unchecked(screw).into_inner(), screw);
+    }
+
+    #[test]
+    fn new_accepts_odd_grades() {
+        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let tv = Trivector::from([4.0, 3.0, 2.0, 1.0]);
+        assert!(Blade::new(v).is_some());
+        assert!(Blade::new(tv).is_some());
+    }
+
+    #[test]
+    fn join_of_points_is_line() {
+        let p1: Blade<Trivector> = Blade::point(100.0, -200.0, 50.0);
+        let p2 = Blade::point(-300.0, 20.0, 400.0);
+        let line = p1 & p2;
+        assert!(line.is_blade());
+        let expected =
+            Trivector::point(100.0, -200.0, 50.0) & Trivector::point(-300.0, 20.0, 400.0);
+        assert_eq!(AnyKVector::from(*line), expected);
+    }
+
+    #[test]
+    fn meet_of_planes_is_line() {
+        let line = Blade::plane(1.0, 0.0, 0.0, 1.0) ^ Blade::plane(0.0, 1.0, 0.0, -2.0);
+        assert!(line.is_blade());
+        let point = line ^ Blade::<Vector>::e3;
+        assert_eq!(*point.normalize(), Trivector::point(1.0, -2.0, 0.0));
+    }
+
+    #[test]
+    fn join_of_line_and_point_is_plane() {
+        let line = Blade::point(0.0, 0.0, 0.0) & Blade::point(1.0, 0.0, 0.0);
+        let plane = line & Blade::point(0.0, 1.0, 0.0);
+        assert!(plane.normalize() == Blade::e3 || plane.normalize() == -Blade::<Vector>::e3);
+    }
+}
@@ -0,0 +1,506 @@
+use crate::pga_3::multivector16::{
+    DUAL_INDICES, DUAL_SIGNS, GEO_INDICES, GEO_SIGNS, OUTER_SIGNS, REVERSE_SIGNS, UNDUAL_SIGNS,
+};
+use crate::pga_3::*;
+use crate::util::Float;
+use std::simd::{LaneCount, SupportedLaneCount};
+
+// Const-evaluable versions of the core products, so that scene constants and test fixtures can
+// be `const` items. Neither SIMD arithmetic nor operator traits can be called in a const fn, so
+// these run the same Cayley tables as `Multivector16` a lane at a time over plain arrays. At
+// runtime the operators are much faster and should be preferred. The `Float` methods of generic
+// code can't be called in a const fn either, so `const_api!` stamps out a copy for each of f32
+// and f64. Unlike generic methods, these need the float type named where inference can't see it,
+// eg `Trivector::<f32>::const_point`.
+
+// Lane of the first basis blade of each grade, and of each motor component, in the full layout
+const GRADE_OFFSETS: [usize; 5] = [0, 1, 5, 11, 15];
+const MOTOR_LANES: [usize; 8] = [0, 5, 6, 7, 8, 9, 10, 15];
+// Permutation indices for `reverse`, which only flips signs
+const IDENTITY: [usize; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+macro_rules! const_api {
+    ($($module:ident: $f:ident),*) => {$(
+        mod $module {
+            use super::*;
+
+            // Basis: 1, e1, e2, e3, e0, e12, e31, e23, e01, e02, e03, e123, e032, e013, e021, e0123
+            type Full = [$f; 16];
+
+            impl<const K: u8, const N: usize> KVector<K, N, $f>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                /// The meet `self ^ rhs` as a const fn. The result type is given by the caller, and
+                /// must have grade `K + G`.
+                pub const fn const_meet<const G: u8, const D: usize, const O: u8, const P: usize>(
+                    self,
+                    rhs: KVector<G, D, $f>,
+                ) -> KVector<O, P, $f>
+                where
+                    LaneCount<D>: SupportedLaneCount,
+                    LaneCount<P>: SupportedLaneCount,
+                {
+                    assert!(O == K + G, "Meet should have the sum of the grades");
+                    extract(&product(&embed(self), &embed(rhs), &OUTER_SIGNS))
+                }
+
+                /// The join `self & rhs` as a const fn. The result type is given by the caller, and
+                /// must have grade `K + G - 4`.
+                pub const fn const_join<const G: u8, const D: usize, const O: u8, const P: usize>(
+                    self,
+                    rhs: KVector<G, D, $f>,
+                ) -> KVector<O, P, $f>
+                where
+                    LaneCount<D>: SupportedLaneCount,
+                    LaneCount<P>: SupportedLaneCount,
+                {
+                    assert!(
+                        O + 4 == K + G,
+                        "Join should have the sum of the grades less 4"
+                    );
+                    let meet = product(&dual(&embed(self)), &dual(&embed(rhs)), &OUTER_SIGNS);
+                    extract(&undual(&meet))
+                }
+
+                /// `normalize` as a const fn
+                pub const fn const_normalize(self) -> Self {
+                    let cs = self.to_array();
+                    if K == 3 && cs[0] != 0.0 {
+                        return scale(self, 1.0 / cs[0]);
+                    }
+                    let mut ideal = true;
+                    let mut i = 0;
+                    while i < Self::ideal_index() {
+                        ideal &= cs[i].abs() < <$f as Float>::EPSILON;
+                        i += 1;
+                    }
+                    let full = embed(self);
+                    let magnitude = if ideal {
+                        eucl_norm(&dual(&full))
+                    } else {
+                        eucl_norm(&full)
+                    };
+                    scale(self, 1.0 / magnitude)
+                }
+            }
+
+            impl Trivector<$f> {
+                /// `point` as a const fn
+                pub const fn const_point(x: $f, y: $f, z: $f) -> Self {
+                    Self::from_array([1.0, -x, -y, -z])
+                }
+
+                /// `direction` as a const fn
+                pub const fn const_direction(x: $f, y: $f, z: $f) -> Self {
+                    Self::from_array([0.0, -x, -y, -z])
+                }
+            }
+
+            impl Bivector<$f> {
+                /// `exp` as a const fn
+                pub const fn const_exp(self) -> Motor<$f> {
+                    let b = self.to_array();
+                    let eucl_sq = b[0] * b[0] + b[1] * b[1] + b[2] * b[2];
+                    if eucl_sq == 0.0 {
+                        return Motor::from_array([1.0, b[0], b[1], b[2], b[3], b[4], b[5], 0.0]);
+                    }
+                    let eucl_norm = sqrt(eucl_sq as f64) as $f;
+                    let (sin, cos) = sin_cos(eucl_norm as f64);
+                    let (sin, cos) = (sin as $f, cos as $f);
+                    let sinc = sin / eucl_norm;
+                    let uv = -(b[0] * b[5] + b[1] * b[4] + b[2] * b[3]);
+                    let ideal = uv / eucl_sq * (cos - sinc);
+                    Motor::from_array([
+                        cos,
+                        b[0] * sinc,
+                        b[1] * sinc,
+                        b[2] * sinc,
+                        b[3] * sinc - b[2] * ideal,
+                        b[4] * sinc - b[1] * ideal,
+                        b[5] * sinc - b[0] * ideal,
+                        -uv * sinc,
+                    ])
+                }
+            }
+
+            impl Motor<$f> {
+                /// `rotation` as a const fn
+                pub const fn const_rotation(axis: Blade<Bivector<$f>>, angle: $f) -> Self {
+                    scale(axis.into_inner().const_normalize(), -angle / 2.0).const_exp()
+                }
+
+                /// `translation` as a const fn
+                pub const fn const_translation(direction: Trivector<$f>) -> Self {
+                    let d = direction.to_array();
+                    Self::from_array([1.0, 0.0, 0.0, 0.0, d[1] / 2.0, d[2] / 2.0, d[3] / 2.0, 0.0])
+                }
+
+                /// `screw` as a const fn
+                pub const fn const_screw(
+                    axis: Blade<Bivector<$f>>,
+                    angle: $f,
+                    distance: $f,
+                ) -> Self {
+                    // The axis times the pseudoscalar, ie the axis moved to its ideal part
+                    let a = axis.into_inner().const_normalize().to_array();
+                    let (r, s) = (-angle / 2.0, distance / 2.0);
+                    Bivector::from_array([
+                        a[0] * r,
+                        a[1] * r,
+                        a[2] * r,
+                        a[3] * r - a[2] * s,
+                        a[4] * r - a[1] * s,
+                        a[5] * r - a[0] * s,
+                    ])
+                    .const_exp()
+                }
+
+                /// `TryFrom<Transformation>` as a const fn. Axes are normalized here, as the
+                /// `Transformation` constructors would, since const code can build the variants
+                /// directly.
+                pub const fn from_transformation(t: Transformation<$f>) -> Result<Self, PgaError> {
+                    use Transformation::*;
+                    Ok(match t {
+                        Rotation { axis, angle } => Self::const_rotation(axis, angle),
+                        Translation { direction } => Self::const_translation(direction),
+                        Screw {
+                            axis,
+                            angle,
+                            distance,
+                        } => Self::const_screw(axis, angle, distance),
+                        Reflection { .. } | RotoReflection { .. } | Glide { .. } => {
+                            return Err(PgaError::ParityMismatch {
+                                expected_odd: false,
+                            });
+                        }
+                    })
+                }
+
+                /// `self * rhs` as a const fn
+                pub const fn const_mul(self, rhs: Motor<$f>) -> Motor<$f> {
+                    motor_from_full(&product(
+                        &motor_to_full(self),
+                        &motor_to_full(rhs),
+                        &GEO_SIGNS,
+                    ))
+                }
+
+                /// `self >> rhs` as a const fn
+                pub const fn const_sandwich<const K: u8, const N: usize>(
+                    self,
+                    rhs: KVector<K, N, $f>,
+                ) -> KVector<K, N, $f>
+                where
+                    LaneCount<N>: SupportedLaneCount,
+                {
+                    let m = motor_to_full(self);
+                    let reversed = product(&reverse(&m), &embed(rhs), &GEO_SIGNS);
+                    extract(&product(&reversed, &m, &GEO_SIGNS))
+                }
+
+                /// `normalize` as a const fn
+                pub const fn const_normalize(self) -> Motor<$f> {
+                    let m = motor_to_full(self);
+                    let squared = product(&m, &reverse(&m), &GEO_SIGNS);
+                    let root = sqrt(squared[0] as f64) as $f;
+                    let s = 1.0 / root;
+                    let ps = -squared[15] / (2.0 * root * root * root);
+                    self.const_mul(Motor::from_array([s, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, ps]))
+                }
+            }
+
+            pub(crate) const fn embed<const K: u8, const N: usize>(kv: KVector<K, N, $f>) -> Full
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                let cs = kv.to_array();
+                let mut full = [0.0; 16];
+                let mut i = 0;
+                while i < N {
+                    full[GRADE_OFFSETS[K as usize] + i] = cs[i];
+                    i += 1;
+                }
+                full
+            }
+
+            const fn extract<const K: u8, const N: usize>(full: &Full) -> KVector<K, N, $f>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                let mut cs = [0.0; N];
+                let mut i = 0;
+                while i < N {
+                    cs[i] = full[GRADE_OFFSETS[K as usize] + i];
+                    i += 1;
+                }
+                KVector::from_array(cs)
+            }
+
+            const fn motor_to_full(m: Motor<$f>) -> Full {
+                let cs = m.to_array();
+                let mut full = [0.0; 16];
+                let mut i = 0;
+                while i < 8 {
+                    full[MOTOR_LANES[i]] = cs[i];
+                    i += 1;
+                }
+                full
+            }
+
+            const fn motor_from_full(full: &Full) -> Motor<$f> {
+                let mut cs = [0.0; 8];
+                let mut i = 0;
+                while i < 8 {
+                    cs[i] = full[MOTOR_LANES[i]];
+                    i += 1;
+                }
+                Motor::from_array(cs)
+            }
+
+            const fn scale<const K: u8, const N: usize>(
+                kv: KVector<K, N, $f>,
+                s: $f,
+            ) -> KVector<K, N, $f>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                let mut cs = kv.to_array();
+                let mut i = 0;
+                while i < N {
+                    cs[i] *= s;
+                    i += 1;
+                }
+                KVector::from_array(cs)
+            }
+
+            // The scalar lane-at-a-time form of `cayley_product!`
+            const fn product(lhs: &Full, rhs: &Full, signs: &[[f32; 16]; 16]) -> Full {
+                let mut result = [0.0; 16];
+                let mut i = 0;
+                while i < 16 {
+                    let mut k = 0;
+                    while k < 16 {
+                        result[k] += lhs[i] * signs[i][k] as $f * rhs[GEO_INDICES[i][k]];
+                        k += 1;
+                    }
+                    i += 1;
+                }
+                result
+            }
+
+            const fn permute(mv: &Full, indices: &[usize; 16], signs: &[f32; 16]) -> Full {
+                let mut result = [0.0; 16];
+                let mut k = 0;
+                while k < 16 {
+                    result[k] = mv[indices[k]] * signs[k] as $f;
+                    k += 1;
+                }
+                result
+            }
+
+            const fn dual(mv: &Full) -> Full {
+                permute(mv, &DUAL_INDICES, &DUAL_SIGNS)
+            }
+
+            const fn undual(mv: &Full) -> Full {
+                permute(mv, &DUAL_INDICES, &UNDUAL_SIGNS)
+            }
+
+            const fn reverse(mv: &Full) -> Full {
+                permute(mv, &IDENTITY, &REVERSE_SIGNS)
+            }
+
+            const fn eucl_norm(mv: &Full) -> $f {
+                sqrt(product(&reverse(mv), mv, &GEO_SIGNS)[0] as f64) as $f
+            }
+        }
+    )*};
+}
+
+const_api!(single: f32, double: f64);
+
+pub(crate) use single::embed;
+
+// Newton's method from a first guess that halves the exponent, which is within f64 rounding of
+// `f64::sqrt` after six steps. The f32 API rounds its result.
+const fn sqrt(x: f64) -> f64 {
+    if x == 0.0 {
+        return 0.0;
+    } else if x < 0.0 {
+        return f64::NAN;
+    }
+    let mut root = f64::from_bits((x.to_bits() >> 1) + (1023 << 51));
+    let mut i = 0;
+    while i < 6 {
+        root = (root + x / root) / 2.0;
+        i += 1;
+    }
+    root
+}
+
+// Taylor series about the nearest multiple of a quarter turn, which converge to within f64
+// rounding by the tenth term
+const fn sin_cos(x: f64) -> (f64, f64) {
+    use std::f64::consts::FRAC_PI_2;
+    let quarters = x / FRAC_PI_2;
+    let mut turns = quarters as i64;
+    let remainder = quarters - turns as f64;
+    if remainder > 0.5 {
+        turns += 1;
+    } else if remainder < -0.5 {
+        turns -= 1;
+    }
+    let r = x - turns as f64 * FRAC_PI_2;
+    let (mut sin, mut cos) = (0.0, 0.0);
+    let (mut sin_term, mut cos_term) = (r, 1.0);
+    let mut n = 1.0;
+    while n <= 10.0 {
+        sin += sin_term;
+        cos += cos_term;
+        sin_term *= -r * r / ((2.0 * n) * (2.0 * n + 1.0));
+        cos_term *= -r * r / ((2.0 * n - 1.0) * (2.0 * n));
+        n += 1.0;
+    }
+    match turns.rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::float_eq;
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    const CORNER: Trivector = Trivector::<f32>::const_point(1.0, 2.0, 3.0);
+    const EDGE: Bivector = CORNER.const_join(Trivector::<f32>::const_point(-1.0, 0.5, 2.0));
+    const FLOOR: Vector = Vector::from_array([0.0, 1.0, 0.0, -1.0]);
+    const TURN: Motor = Motor::<f32>::const_rotation(Blade::e12, FRAC_PI_2);
+    const DOUBLE_CORNER: Trivector<f64> = Trivector::<f64>::const_point(1.0, 2.0, 3.0);
+    const DOUBLE_TURN: Motor<f64> =
+        Motor::<f64>::const_rotation(Blade::e12, std::f64::consts::FRAC_PI_2);
+
+    #[test]
+    fn const_items() {
+        assert_eq!(EDGE, (CORNER & Trivector::point(-1.0, 0.5, 2.0)).assert());
+        assert_eq!(CORNER, Trivector::point(1.0, 2.0, 3.0));
+        assert_eq!(
+            Trivector::<f32>::const_direction(1.0, 2.0, 3.0),
+            Trivector::direction(1.0, 2.0, 3.0)
+        );
+        let foot: Trivector = EDGE.const_meet(FLOOR);
+        assert_eq!(foot, (EDGE ^ FLOOR).assert());
+        assert_eq!(
+            TURN.const_sandwich(CORNER),
+            Motor::rotation(Blade::e12, FRAC_PI_2) >> CORNER
+        );
+    }
+
+    #[test]
+    fn products_match_operators() {
+        let a: Vector = Vector::from_array([1.0, -2.0, 0.5, 3.0]);
+        let b: Bivector = Bivector::from_array([0.5, 1.0, -1.5, 2.0, 0.25, -1.0]);
+        let t: Trivector = Trivector::from_array([2.0, 1.0, -3.0, 0.5]);
+        let ab: Trivector = a.const_meet(b);
+        let aa: Bivector = a.const_meet(FLOOR);
+        let bt: Vector = b.const_join(t);
+        let tt: Bivector = t.const_join(CORNER);
+        assert_eq!(ab, (a ^ b).assert());
+        assert_eq!(aa, (a ^ FLOOR).assert());
+        assert_eq!(bt, (b & t).assert());
+        assert_eq!(tt, (t & CORNER).assert());
+
+        let m = b.exp();
+        let n: Motor = Motor::trans_coords(1.0, -2.0, 0.5);
+        assert_eq!(b.const_exp(), m);
+        assert_eq!(m.const_mul(n), m * n);
+        assert_eq!(m.const_sandwich(b), m >> b);
+        assert_eq!(m.const_sandwich(a), m >> a);
+        assert_eq!((m * 3.0).const_normalize(), (m * 3.0).normalize());
+    }
+
+    #[test]
+    fn normalize_matches() {
+        let plane: Vector = Vector::from_array([3.0, 0.0, 4.0, 10.0]);
+        let ideal_line: Bivector = Bivector::from_array([0.0, 0.0, 0.0, 1.0, 2.0, 2.0]);
+        let point: Trivector = Trivector::from_array([2.0, -4.0, 6.0, 1.0]);
+        let direction: Trivector = Trivector::direction(3.0, 0.0, -4.0);
+        assert_eq!(plane.const_normalize(), plane.normalize());
+        assert_eq!(ideal_line.const_normalize(), ideal_line.normalize());
+        assert_eq!(point.const_normalize(), point.normalize());
+        assert_eq!(direction.const_normalize(), direction.normalize());
+    }
+
+    #[test]
+    fn transformations_match() {
+        let axis: Blade<Bivector> = Blade::new(
+            (Trivector::point(1.0, 0.0, 2.0) & Trivector::point(0.0, 3.0, -1.0)).assert(),
+        )
+        .unwrap();
+        for angle in [0.3, -2.0, PI, 7.5, -40.0] {
+            let rotation = Transformation::rotation(axis, angle);
+            let screw = Transformation::screw(axis, angle, 1.5);
+            assert_eq!(
+                Motor::<f32>::from_transformation(rotation),
+                Motor::try_from(rotation)
+            );
+            assert_eq!(
+                Motor::<f32>::from_transformation(screw),
+                Motor::try_from(screw)
+            );
+        }
+        let translation: Transformation = Transformation::trans_coords(1.0, -2.0, 0.5);
+        assert_eq!(
+            Motor::<f32>::from_transformation(translation),
+            Motor::try_from(translation)
+        );
+        assert_eq!(
+            Motor::<f32>::from_transformation(Transformation::reflection(e1)),
+            Err(PgaError::ParityMismatch {
+                expected_odd: false
+            })
+        );
+    }
+
+    #[test]
+    fn double_precision() {
+        let corner = Trivector::<f64>::point(1.0, 2.0, 3.0);
+        let edge: Bivector<f64> =
+            DOUBLE_CORNER.const_join(Trivector::<f64>::const_point(-1.0, 0.5, 2.0));
+        assert_eq!(DOUBLE_CORNER, corner);
+        assert_eq!(edge, (corner & Trivector::point(-1.0, 0.5, 2.0)).assert());
+        assert_eq!(
+            DOUBLE_TURN.const_sandwich(DOUBLE_CORNER),
+            Motor::rotation(Blade::e12, std::f64::consts::FRAC_PI_2) >> corner
+        );
+
+        let b = Bivector::<f64>::from_array([0.5, 1.0, -1.5, 2.0, 0.25, -1.0]);
+        let m = b.exp();
+        let n: Motor<f64> = Motor::trans_coords(1.0, -2.0, 0.5);
+        assert_eq!(b.const_exp(), m);
+        assert_eq!(m.const_mul(n), m * n);
+        assert_eq!(m.const_sandwich(b), m >> b);
+        assert_eq!((m * 3.0).const_normalize(), (m * 3.0).normalize());
+        let axis = Blade::new(edge).unwrap();
+        let screw = Transformation::screw(axis, 2.0, 1.5);
+        assert_eq!(
+            Motor::<f64>::from_transformation(screw),
+            Motor::try_from(screw)
+        );
+    }
+
+    #[test]
+    fn scalar_functions() {
+        for x in [1e-12, 1e-6, 0.5, 2.0, 1e6, 1e12] {
+            assert!(float_eq(sqrt(x), x.sqrt()));
+        }
+        for x in [0.0, 0.7, -1.2, 3.0, 100.0] {
+            let (sin, cos) = sin_cos(x);
+            assert!(float_eq(sin, x.sin()) && float_eq(cos, x.cos()));
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use crate::pga_3::*;
+
+// Least-squares fits to point sets. The centroid is the normalized sum of the normalized points,
+// and everything else comes from the eigenvectors of a small symmetric matrix: the covariance of
+// the points for planes and lines, and Horn's 4x4 matrix for registration, whose top eigenvector
+// is the quaternion of the best rotation. Points are expected to be finite.
+
+impl<F: Float> Trivector<F> {
+    /// The mean of the points, or `None` if there are none
+    pub fn centroid(points: &[Trivector<F>]) -> Option<Trivector<F>> {
+        if points.is_empty() {
+            return None;
+        }
+        let sum = points
+            .iter()
+            .fold(Trivector::from([F::ZERO; 4]), |acc, p| acc + p.normalize());
+        Some(sum.normalize())
+    }
+
+    /// The covariance matrix of the points' coordinates about their centroid, with rows and
+    /// columns in x, y, z order, or `None` if there are no points
+    pub fn covariance(points: &[Trivector<F>]) -> Option<[[F; 3]; 3]> {
+        let c = coordinates(Trivector::centroid(points)?);
+        let mut cov = [[F::ZERO; 3]; 3];
+        for p in points {
+            let p = coordinates(*p);
+            for i in 0..3 {
+                for j in 0..3 {
+                    cov[i][j] += (p[i] - c[i]) * (p[j] - c[j]);
+                }
+            }
+        }
+        let n = F::from_f64(points.len() as f64);
+        Some(cov.map(|row| row.map(|f| f / n)))
+    }
+}
+
+impl<F: Float> Vector<F> {
+    /// The plane minimizing the sum of squared distances to the points, through their centroid
+    /// and normal to the direction they vary least along. `None` for fewer than three points.
+    pub fn fit_plane(points: &[Trivector<F>]) -> Option<Vector<F>> {
+        if points.len() < 3 {
+            return None;
+        }
+        let c = coordinates(Trivector::centroid(points)?);
+        let (values, vectors) = symmetric_eigen(Trivector::covariance(points)?);
+        let n = column(vectors, smallest(values));
+        let d = n[0] * c[0] + n[1] * c[1] + n[2] * c[2];
+        Some(Vector::from([n[0], n[1], n[2], d]))
+    }
+}
+
+impl<F: Float> Bivector<F> {
+    /// The line minimizing the sum of squared distances to the points, through their centroid
+    /// along the direction they vary most along. `None` for fewer than two points.
+    pub fn fit_line(points: &[Trivector<F>]) -> Option<Bivector<F>> {
+        if points.len() < 2 {
+            return None;
+        }
+        let centroid = Trivector::centroid(points)?;
+        let (values, vectors) = symmetric_eigen(Trivector::covariance(points)?);
+        let d = column(vectors, largest(values));
+        Some(Bivector::through(
+            centroid,
+            Trivector::direction(d[0], d[1], d[2]),
+        ))
+    }
+}
+
+impl<F: Float> Motor<F> {
+    /// The rigid motion `m` minimizing the sum of squared distances from `m >> from[i]` to
+    /// `to[i]`, by Horn's quaternion form of the Kabsch algorithm. The rotation is about the
+    /// centroid of `from`, and is only unique when the points aren't all on one line. `None` if
+    /// the slices are empty or differ in length.
+    pub fn register(from: &[Trivector<F>], to: &[Trivector<F>]) -> Option<Motor<F>> {
+        if from.len() != to.len() {
+            return None;
+        }
+        let ca = coordinates(Trivector::centroid(from)?);
+        let cb = coordinates(Trivector::centroid(to)?);
+        // Cross-covariance of the centred points, s[i][j] = sum of a_i * b_j
+        let mut s = [[F::ZERO; 3]; 3];
+        for (a, b) in from.iter().zip(to) {
+            let (a, b) = (coordinates(*a), coordinates(*b));
+            for i in 0..3 {
+                for j in 0..3 {
+                    s[i][j] += (a[i] - ca[i]) * (b[j] - cb[j]);
+                }
+            }
+        }
+        let [[sxx, sxy, sxz], [syx, syy, syz], [szx, szy, szz]] = s;
+        let horn = [
+            [sxx + syy + szz, syz - szy, szx - sxz, sxy - syx],
+            [syz - szy, sxx - syy - szz, sxy + syx, szx + sxz],
+            [szx - sxz, sxy + syx, -sxx + syy - szz, syz + szy],
+            [sxy - syx, szx + sxz, syz + szy, -sxx - syy + szz],
+        ];
+        let (values, vectors) = symmetric_eigen(horn);
+        let [w, x, y, z] = column(vectors, largest(values));
+        let rotation = [x, y, z, w];
+        let rotated =
+            Motor::from_quaternion(rotation, [F::ZERO; 3]) >> Trivector::point(ca[0], ca[1], ca[2]);
+        let r = coordinates(rotated);
+        Some(Motor::from_quaternion(
+            rotation,
+            [cb[0] - r[0], cb[1] - r[1], cb[2] - r[2]],
+        ))
+    }
+}
+
+#[inline]
+fn coordinates<F: Float>(p: Trivector<F>) -> [F; 3] {
+    [p.x(), p.y(), p.z()]
+}
+
+#[inline]
+fn column<F: Float, const N: usize>(m: [[F; N]; N], j: usize) -> [F; N] {
+    m.map(|row| row[j])
+}
+
+fn smallest<F: Float, const N: usize>(values: [F; N]) -> usize {
+    (0..N).fold(0, |min, i| if values[i] < values[min] { i } else { min })
+}
+
+fn largest<F: Float, const N: usize>(values: [F; N]) -> usize {
+    (0..N).fold(0, |max, i| if values[i] > values[max] { i } else { max })
+}
+
+const MAX_SWEEPS: usize = 50;
+
+// Cyclic Jacobi eigenvalue algorithm, returning the eigenvalues and a matrix with the matching
+// unit eigenvectors as its columns. Each rotation zeroes one off-diagonal entry, and sweeping
+// over all of them converges quadratically.
+fn symmetric_eigen<F: Float, const N: usize>(mut a: [[F; N]; N]) -> ([F; N], [[F; N]; N]) {
+    let mut v = [[F::ZERO; N]; N];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = F::ONE;
+    }
+    for _ in 0..MAX_SWEEPS {
+        let off: F = (0..N)
+            .flat_map(|p| (p + 1..N).map(move |q| (p, q)))
+            .map(|(p, q)| a[p][q] * a[p][q])
+            .sum();
+        if off <= F::MIN_POSITIVE {
+            break;
+        }
+        for p in 0..N {
+            for q in p + 1..N {
+                if a[p][q] == F::ZERO {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (F::from(2.0) * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + F::ONE).sqrt());
+                let c = F::ONE / (t * t + F::ONE).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (rp, rq) = (a[p], a[q]);
+                a[p] = std::array::from_fn(|k| c * rp[k] - s * rq[k]);
+                a[q] = std::array::from_fn(|k| s * rp[k] + c * rq[k]);
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    (std::array::from_fn(|i| a[i][i]), v)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::float_eq;
+    use std::f32::consts::PI;
+
+    fn grid() -> Vec<Trivector> {
+        let mut points = vec![];
+        for i in 0..4 {
+            for j in 0..3 {
+                points.push(Trivector::point(i as f32, j as f32, 0.0));
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn centroid_and_covariance() {
+        let points = [
+            Trivector::point(1.0, 0.0, 0.0),
+            Trivector::point(-1.0, 0.0, 0.0) * 2.0,
+            Trivector::point(0.0, 3.0, 3.0),
+            Trivector::point(0.0, -3.0, 1.0),
+        ];
+        assert_eq!(
+            Trivector::centroid(&points),
+            Some(Trivector::point(0.0, 0.0, 1.0))
+        );
+        let cov = <Trivector>::covariance(&points).unwrap();
+        assert_eq!(cov[0], [0.5, 0.0, 0.0]);
+        assert_eq!(cov[1], [0.0, 4.5, 1.5]);
+        assert_eq!(cov[2], [0.0, 1.5, 1.5]);
+        assert_eq!(<Trivector>::centroid(&[]), None);
+    }
+
+    #[test]
+    fn eigen_decomposition() {
+        let m = [[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 5.0]];
+        let (values, vectors) = symmetric_eigen(m);
+        let mut sorted = values;
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!(float_eq(sorted[0], 1.0) && float_eq(sorted[1], 3.0) && float_eq(sorted[2], 5.0));
+        for (j, value) in values.iter().enumerate() {
+            let v = column(vectors, j);
+            for i in 0..3 {
+                let mv = (0..3).map(|k| m[i][k] * v[k]).sum::<f32>();
+                assert!(float_eq(mv, value * v[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn fit_plane() {
+        let tilt = Motor::rotation(Blade::e23, PI / 5.0) * Motor::trans_coords(1.0, -2.0, 3.0);
+        let points: Vec<Trivector> = grid().into_iter().map(|p| tilt >> p).collect();
+        let plane = Vector::fit_plane(&points).unwrap();
+        let expected = (tilt >> e3).normalize();
+        assert!(plane == expected || plane == -expected);
+        for p in points {
+            assert!(float_eq(p.distance_to_plane(plane), 0.0));
+        }
+        assert_eq!(Vector::fit_plane(&grid()[0..2]), None);
+    }
+
+    #[test]
+    fn fit_line() {
+        let points = [
+            Trivector::point(0.0, 1.0, 1.1),
+            Trivector::point(1.0, 1.0, 0.9),
+            Trivector::point(2.0, 1.0, 0.9),
+            Trivector::point(3.0, 1.0, 1.1),
+        ];
+        let line: Bivector = Bivector::fit_line(&points).unwrap();
+        let x_axis = Bivector::through(
+            Trivector::point(0.0, 1.0, 1.0),
+            Trivector::direction(1.0, 0.0, 0.0),
+        );
+        assert!(line == x_axis || line == -x_axis);
+        assert_eq!(Bivector::fit_line(&points[0..1]), None);
+    }
+
+    #[test]
+    fn register() {
+        let m = Motor::rotation(
+            Blade::new(Bivector::through(
+                e123,
+                Trivector::direction(1.0, 2.0, -1.0),
+            ))
+            .unwrap(),
+            1.2,
+        ) * Motor::trans_coords(4.0, 0.5, -3.0);
+        let mut from = grid();
+        from.push(Trivector::point(1.0, 1.0, 2.0));
+        let to: Vec<Trivector> = from.iter().map(|p| m >> *p).collect();
+        let found = Motor::register(&from, &to).unwrap();
+        for (a, b) in from.iter().zip(&to) {
+            assert!(float_eq((found >> *a).distance_to_point(*b), 0.0));
+        }
+        // A single pair is matched by a translation
+        let single = Motor::register(&from[0..1], &to[0..1]).unwrap();
+        assert_eq!(single >> from[1], from[1] + to[0].normalize() - from[0]);
+        assert_eq!(Motor::register(&from, &to[1..]), None);
+    }
+}
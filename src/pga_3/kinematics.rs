@@ -0,0 +1,491 @@
+use crate::pga_3::*;
+use std::fmt::{Display, Formatter};
+
+// Articulated bodies as trees of joints. Each joint's frame sits at its rest motor in its parent's
+// frame, and moves about or along its axis line in its own frame, so its world motor is
+//
+//     world = motion * rest * parent_world
+//
+// as motors compose left to right. Every joint motion is `(generator * (-value / 2)).exp()` for a
+// fixed bivector generator, which also gives the velocity of any point as the joint moves, and so
+// the Jacobian used by the inverse kinematics solver.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum KinematicsError {
+    /// A joint's parent doesn't come before it in the skeleton
+    ParentNotBefore { joint: usize, parent: usize },
+    /// The skeleton has no joint at the index
+    NoSuchJoint(usize),
+    /// Joint limits with the minimum above the maximum, or with a NaN bound
+    InvalidLimits,
+}
+
+impl Display for KinematicsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KinematicsError::ParentNotBefore { joint, parent } => {
+                write!(
+                    f,
+                    "joint {joint} has parent {parent}, which doesn't come before it"
+                )
+            }
+            KinematicsError::NoSuchJoint(index) => write!(f, "no joint at index {index}"),
+            KinematicsError::InvalidLimits => write!(f, "joint limits are empty or NaN"),
+        }
+    }
+}
+
+impl std::error::Error for KinematicsError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum JointKind {
+    /// Turns about its axis by its value in radians, left-handedly about the axis direction as
+    /// `Transformation::rotation` does
+    Revolute,
+    /// Slides along its axis direction by its value
+    Prismatic,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Joint<F: Float = f32> {
+    /// Index of the parent joint in the skeleton, or `None` for a root
+    pub parent: Option<usize>,
+    /// Motor placing the joint's frame in its parent's frame, or in the world for a root
+    pub rest: Motor<F>,
+    /// Line in the joint's own frame that it turns about or slides along
+    pub axis: Blade<Bivector<F>>,
+    pub kind: JointKind,
+    /// Current angle or distance
+    pub value: F,
+    /// Range the inverse kinematics solver keeps the value within, if any
+    pub limits: Option<(F, F)>,
+}
+
+impl<F: Float> Joint<F> {
+    #[inline]
+    pub fn revolute(parent: Option<usize>, rest: Motor<F>, axis: Blade<Bivector<F>>) -> Self {
+        Self::new(parent, rest, axis, JointKind::Revolute)
+    }
+
+    #[inline]
+    pub fn prismatic(parent: Option<usize>, rest: Motor<F>, axis: Blade<Bivector<F>>) -> Self {
+        Self::new(parent, rest, axis, JointKind::Prismatic)
+    }
+
+    #[inline]
+    pub fn new(
+        parent: Option<usize>,
+        rest: Motor<F>,
+        axis: Blade<Bivector<F>>,
+        kind: JointKind,
+    ) -> Self {
+        Self {
+            parent,
+            rest,
+            axis: axis.normalize(),
+            kind,
+            value: F::ZERO,
+            limits: None,
+        }
+    }
+
+    /// Limits the value to `[min, max]`, clamping the current value into it. Fails with
+    /// `KinematicsError::InvalidLimits` if `min` is above `max` or either is NaN.
+    #[inline]
+    pub fn with_limits(mut self, min: F, max: F) -> Result<Self, KinematicsError> {
+        if min.is_nan() || max.is_nan() || min > max {
+            return Err(KinematicsError::InvalidLimits);
+        }
+        self.limits = Some((min, max));
+        self.set(self.value);
+        Ok(self)
+    }
+
+    /// The motion of the joint at its current value, in its own frame
+    #[inline]
+    pub fn motion(&self) -> Motor<F> {
+        match self.kind {
+            JointKind::Revolute => Motor::rotation(self.axis, self.value),
+            JointKind::Prismatic => Motor::translation(self.axis.direction() * self.value),
+        }
+    }
+
+    /// The bivector `g` for which the joint's motion at value `v` is `(g * (-v / 2)).exp()`: the
+    /// normalized axis for a revolute joint, and the ideal line of its direction for a prismatic one
+    #[inline]
+    pub fn generator(&self) -> Bivector<F> {
+        match self.kind {
+            JointKind::Revolute => *self.axis,
+            JointKind::Prismatic => {
+                let a = self.axis;
+                Bivector::from([F::ZERO, F::ZERO, F::ZERO, a[2], a[1], a[0]])
+            }
+        }
+    }
+
+    // Unlike `clamp`, `max` and `min` don't panic on limits set directly to an empty range
+    #[inline]
+    fn set(&mut self, value: F) {
+        self.value = match self.limits {
+            Some((min, max)) => value.max(min).min(max),
+            None => value,
+        };
+    }
+}
+
+/// A tree of joints, stored so that every joint comes after its parent
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Skeleton<F: Float = f32> {
+    joints: Vec<Joint<F>>,
+}
+
+const DAMPING: f32 = 0.5;
+const MAX_STEP: f32 = 0.5;
+
+impl<F: Float> Skeleton<F> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a joint, returning its index. Fails with `KinematicsError::ParentNotBefore` if its
+    /// parent hasn't been added yet.
+    pub fn add(&mut self, joint: Joint<F>) -> Result<usize, KinematicsError> {
+        let index = self.joints.len();
+        check_parent(index, joint.parent)?;
+        self.joints.push(joint);
+        Ok(index)
+    }
+
+    #[inline]
+    pub fn joints(&self) -> &[Joint<F>] {
+        &self.joints
+    }
+
+    #[inline]
+    pub fn joint(&self, index: usize) -> &Joint<F> {
+        &self.joints[index]
+    }
+
+    /// Sets the value of a joint, clamped to its limits if it has any
+    #[inline]
+    pub fn set_value(&mut self, index: usize, value: F) {
+        self.joints[index].set(value);
+    }
+
+    /// Replaces a joint. Fails with `KinematicsError::NoSuchJoint` if there's no joint at
+    /// `index`, and with `KinematicsError::ParentNotBefore` if its parent doesn't come before it.
+    pub fn replace(&mut self, index: usize, joint: Joint<F>) -> Result<(), KinematicsError> {
+        check_parent(index, joint.parent)?;
+        let slot = self
+            .joints
+            .get_mut(index)
+            .ok_or(KinematicsError::NoSuchJoint(index))?;
+        *slot = joint;
+        Ok(())
+    }
+
+    /// The world motor of every joint, composed from the roots to the leaves
+    pub fn forward(&self) -> Vec<Motor<F>> {
+        let mut world: Vec<Motor<F>> = Vec::with_capacity(self.joints.len());
+        for joint in &self.joints {
+            let local = joint.motion() * joint.rest;
+            world.push(match joint.parent {
+                Some(parent) => local * world[parent],
+                None => local,
+            });
+        }
+        world
+    }
+
+    /// The world motor of one joint
+    #[inline]
+    pub fn world(&self, joint: usize) -> Motor<F> {
+        self.forward()[joint]
+    }
+
+    /// The joints from a root down to `end`, inclusive
+    pub fn chain(&self, end: usize) -> Vec<usize> {
+        let mut chain = vec![end];
+        while let Some(parent) = self.joints[*chain.last().unwrap()].parent {
+            chain.push(parent);
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// The rate of change of the world position of `point` as each joint in the chain to `end`
+    /// moves, as directions. `point` is in world coordinates.
+    pub fn jacobian(&self, end: usize, point: Trivector<F>) -> Vec<(usize, Trivector<F>)> {
+        let world = self.forward();
+        let p = point.normalize();
+        let (x, y, z) = (p.x(), p.y(), p.z());
+        self.chain(end)
+            .into_iter()
+            .map(|i| {
+                // A point moves with velocity p x d - m under the line with direction d and
+                // moment m, which covers ideal lines too
+                let g = world[i] >> self.joints[i].generator();
+                let (d, m) = (g.direction(), g.moment());
+                let (dx, dy, dz) = (-d[1], -d[2], -d[3]);
+                let v = Trivector::direction(y * dz - z * dy, z * dx - x * dz, x * dy - y * dx);
+                (i, v - m)
+            })
+            .collect()
+    }
+
+    /// Moves the joints in the chain to `end` so that `effector`, a point in the frame of `end`,
+    /// reaches `target` in the world, by damped least squares on the Jacobian. Joint limits are
+    /// respected. Returns whether the effector got within `tolerance` of the target.
+    pub fn solve_ik(
+        &mut self,
+        end: usize,
+        effector: Trivector<F>,
+        target: Trivector<F>,
+        iterations: usize,
+        tolerance: F,
+    ) -> bool {
+        let (damping, max_step) = (F::from(DAMPING), F::from(MAX_STEP));
+        let target = target.normalize();
+        for _ in 0..iterations {
+            let reached = (self.world(end) >> effector).normalize();
+            let mut error = [
+                target.x() - reached.x(),
+                target.y() - reached.y(),
+                target.z() - reached.z(),
+            ];
+            let distance = error.iter().map(|e| *e * *e).sum::<F>().sqrt();
+            if distance <= tolerance {
+                return true;
+            }
+            // Far targets are approached in short steps, as the Jacobian is only good locally
+            if distance > max_step {
+                error = error.map(|e| e * max_step / distance);
+            }
+            let columns: Vec<(usize, [F; 3])> = self
+                .jacobian(end, reached)
+                .into_iter()
+                .map(|(i, v)| (i, [-v[1], -v[2], -v[3]]))
+                .collect();
+            // Solve (J J^T + damping^2 I) y = error, then step the joints by J^T y
+            let mut jjt = [[F::ZERO; 3]; 3];
+            for (r, row) in jjt.iter_mut().enumerate() {
+                for (c, entry) in row.iter_mut().enumerate() {
+                    *entry = columns.iter().map(|(_, v)| v[r] * v[c]).sum();
+                }
+                row[r] += damping * damping;
+            }
+            let Some(y) = solve3(jjt, error) else {
+                return false;
+            };
+            for (i, v) in columns {
+                let value = self.joints[i].value + v[0] * y[0] + v[1] * y[1] + v[2] * y[2];
+                self.set_value(i, value);
+            }
+        }
+        let reached = (self.world(end) >> effector).normalize();
+        reached.distance_to_point(target) <= tolerance
+    }
+}
+
+// Parents come before their children, which also rules out cycles
+fn check_parent(joint: usize, parent: Option<usize>) -> Result<(), KinematicsError> {
+    match parent {
+        Some(parent) if parent >= joint => Err(KinematicsError::ParentNotBefore { joint, parent }),
+        _ => Ok(()),
+    }
+}
+
+// Cramer's rule for a 3x3 system
+fn solve3<F: Float>(a: [[F; 3]; 3], b: [F; 3]) -> Option<[F; 3]> {
+    let det = |m: [[F; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let d = det(a);
+    if d.abs() <= F::MIN_POSITIVE {
+        return None;
+    }
+    Some(std::array::from_fn(|c| {
+        let mut m = a;
+        for (row, bi) in m.iter_mut().zip(b) {
+            row[c] = bi;
+        }
+        det(m) / d
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f32::consts::PI;
+
+    // A planar arm in the xy plane: a shoulder at the origin, an elbow and a wrist one unit apart
+    // along x, all turning about lines parallel to z
+    fn arm() -> Skeleton {
+        let z_axis =
+            Blade::new(Bivector::through(e123, Trivector::direction(0.0, 0.0, 1.0))).unwrap();
+        let step = Motor::trans_coords(1.0, 0.0, 0.0);
+        let mut arm = Skeleton::new();
+        let shoulder = arm
+            .add(Joint::revolute(None, Motor::from(1.0), z_axis))
+            .unwrap();
+        let elbow = arm
+            .add(Joint::revolute(Some(shoulder), step, z_axis))
+            .unwrap();
+        arm.add(Joint::revolute(Some(elbow), step, z_axis)).unwrap();
+        arm
+    }
+
+    #[test]
+    fn generators_exponentiate_to_motions() {
+        let axis: Blade<Bivector> = Blade::new(Bivector::through(
+            Trivector::point(1.0, 2.0, 0.0),
+            Trivector::direction(1.0, -1.0, 2.0),
+        ))
+        .unwrap();
+        for kind in [JointKind::Revolute, JointKind::Prismatic] {
+            let mut joint = Joint::new(None, Motor::from(1.0), axis, kind);
+            joint.value = 0.7;
+            assert_eq!(joint.motion(), (joint.generator() * -0.35).exp());
+        }
+    }
+
+    #[test]
+    fn forward_kinematics() {
+        let mut arm = arm();
+        let wrist = (arm.forward()[2] >> e123).normalize();
+        assert_eq!(wrist, Trivector::point(2.0, 0.0, 0.0));
+
+        // Turning the shoulder by -90 degrees turns the whole arm right-handedly to point along y,
+        // and the elbow by another -90 degrees bends the forearm along -x
+        arm.set_value(0, -PI / 2.0);
+        assert_eq!(
+            (arm.world(2) >> e123).normalize(),
+            Trivector::point(0.0, 2.0, 0.0)
+        );
+        arm.set_value(1, -PI / 2.0);
+        let world = arm.forward();
+        assert_eq!(world[2], arm.world(2));
+        assert_eq!(
+            (world[2] >> e123).normalize(),
+            Trivector::point(-1.0, 1.0, 0.0)
+        );
+        assert_eq!(arm.chain(2), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn prismatic_joints_slide() {
+        let mut skeleton = Skeleton::new();
+        let base = skeleton
+            .add(Joint::revolute(None, Motor::from(1.0), Blade::e12))
+            .unwrap();
+        let slider = skeleton
+            .add(Joint::prismatic(
+                Some(base),
+                Motor::trans_coords(0.0, 0.0, 1.0),
+                Blade::new(Bivector::through(e123, Trivector::direction(2.0, 0.0, 0.0))).unwrap(),
+            ))
+            .unwrap();
+        skeleton.set_value(slider, 3.0);
+        assert_eq!(
+            (skeleton.world(slider) >> e123).normalize(),
+            Trivector::point(3.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn jacobian_matches_finite_differences() {
+        let mut arm = arm();
+        arm.set_value(0, 0.3);
+        arm.set_value(1, -0.8);
+        arm.replace(2, Joint::prismatic(Some(1), arm.joint(2).rest, Blade::e31))
+            .unwrap();
+        let effector = Trivector::point(0.5, 0.2, 0.1);
+        let point = (arm.world(2) >> effector).normalize();
+        let h = 1e-3;
+        for (i, v) in arm.jacobian(2, point) {
+            let mut moved = arm.clone();
+            moved.set_value(i, arm.joint(i).value + h);
+            let after = (moved.world(2) >> effector).normalize();
+            let mut moved = arm.clone();
+            moved.set_value(i, arm.joint(i).value - h);
+            let before = (moved.world(2) >> effector).normalize();
+            for (k, f) in [Trivector::x, Trivector::y, Trivector::z]
+                .iter()
+                .enumerate()
+            {
+                let fd = (f(&after) - f(&before)) / (2.0 * h);
+                assert!((fd + v[k + 1]).abs() < 1e-2);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_kinematics_reaches_targets() {
+        let mut arm = arm();
+        let effector = Trivector::point(1.0, 0.0, 0.0);
+        let target = Trivector::point(1.0, 1.5, 0.0);
+        assert!(arm.solve_ik(2, effector, target, 100, 1e-3));
+        assert!((arm.world(2) >> effector).distance_to_point(target) <= 1e-3);
+
+        // Out of reach, the arm stretches towards the target
+        let mut arm = self::arm();
+        assert!(!arm.solve_ik(2, effector, Trivector::point(0.0, 5.0, 0.0), 100, 1e-3));
+        let reached = (arm.world(2) >> effector).normalize();
+        assert!(reached.x().abs() < 1e-2);
+        assert!((reached.y() - 3.0).abs() < 1e-2);
+
+        // Limits are respected
+        let mut arm = self::arm();
+        arm.replace(1, arm.joint(1).with_limits(-0.5, 0.5).unwrap())
+            .unwrap();
+        arm.solve_ik(2, effector, Trivector::point(0.0, 1.0, 0.0), 100, 1e-3);
+        assert!(arm.joint(1).value.abs() <= 0.5);
+    }
+
+    #[test]
+    fn parents_come_first() {
+        let mut arm = arm();
+        let rest = arm.joint(1).rest;
+        assert_eq!(
+            arm.replace(1, Joint::revolute(Some(2), rest, Blade::e12)),
+            Err(KinematicsError::ParentNotBefore {
+                joint: 1,
+                parent: 2
+            })
+        );
+        assert_eq!(
+            arm.add(Joint::revolute(Some(3), rest, Blade::e12)),
+            Err(KinematicsError::ParentNotBefore {
+                joint: 3,
+                parent: 3
+            })
+        );
+        assert_eq!(
+            arm.replace(5, Joint::revolute(Some(2), rest, Blade::e12)),
+            Err(KinematicsError::NoSuchJoint(5))
+        );
+        assert_eq!(arm, self::arm());
+    }
+
+    #[test]
+    fn joint_limits() {
+        let mut joint: Joint = Joint::revolute(None, Motor::from(1.0), Blade::e12);
+        joint.value = 2.0;
+        assert_eq!(joint.with_limits(-1.0, 1.0).unwrap().value, 1.0);
+        assert_eq!(
+            joint.with_limits(1.0, -1.0),
+            Err(KinematicsError::InvalidLimits)
+        );
+        assert_eq!(
+            joint.with_limits(f32::NAN, 1.0),
+            Err(KinematicsError::InvalidLimits)
+        );
+        // Limits set directly to an empty range don't panic
+        joint.limits = Some((1.0, -1.0));
+        joint.set(0.0);
+        assert_eq!(joint.value, -1.0);
+    }
+}
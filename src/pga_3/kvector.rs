@@ -7,7 +7,7 @@ pub use scalar::*;
 use std::any::Any;
 use std::cmp::PartialEq;
 use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Neg, Sub};
-use std::simd::{LaneCount, Simd, SupportedLaneCount, f32x4, simd_swizzle};
+use std::simd::{LaneCount, SupportedLaneCount};
 use std::slice::SliceIndex;
 pub use trivector::*;
 pub use vector::*;
@@ -20,17 +20,23 @@ mod scalar;
 mod trivector;
 mod vector;
 
+/// A k-vector of grade `K` with `N` components, which is one of `Vector`, `Bivector` or
+/// `Trivector`. Scalars and pseudoscalars have their own types.
+///
+/// The products below match on `K` and on the grades of their intermediate results, which the
+/// algebra fixes, eg the square of a bivector has only a scalar and a pseudoscalar part. The arms
+/// that would break those rules are `unreachable!`, and other `K` and `N` fail to compile.
 #[derive(Debug, Copy, Clone)]
-pub struct KVector<const K: u8, const N: usize>
+pub struct KVector<const K: u8, const N: usize, F: Float = f32>
 where
     LaneCount<N>: SupportedLaneCount,
 {
-    components: Simd<f32, N>,
+    components: Lanes<F, N>,
 }
 
-impl<Idx, const K: u8, const N: usize> Index<Idx> for KVector<K, N>
+impl<F: Float, Idx, const K: u8, const N: usize> Index<Idx> for KVector<K, N, F>
 where
-    Idx: SliceIndex<[f32]>,
+    Idx: SliceIndex<[F]>,
     LaneCount<N>: SupportedLaneCount,
 {
     type Output = Idx::Output;
@@ -40,9 +46,9 @@ where
     }
 }
 
-impl<Idx, const K: u8, const N: usize> IndexMut<Idx> for KVector<K, N>
+impl<F: Float, Idx, const K: u8, const N: usize> IndexMut<Idx> for KVector<K, N, F>
 where
-    Idx: SliceIndex<[f32], Output = f32>,
+    Idx: SliceIndex<[F], Output = F>,
     LaneCount<N>: SupportedLaneCount,
 {
     fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
@@ -50,7 +56,7 @@ where
     }
 }
 
-impl<const K: u8, const N: usize> PartialEq for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize> PartialEq for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
@@ -63,7 +69,7 @@ where
     }
 }
 
-impl<const K: u8, const N: usize> Add for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize> Add for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
@@ -77,7 +83,7 @@ where
     }
 }
 
-impl<const K: u8, const N: usize> Sub for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize> Sub for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
@@ -91,7 +97,7 @@ where
     }
 }
 
-impl<const K: u8, const N: usize> Neg for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize> Neg for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
@@ -105,53 +111,40 @@ where
     }
 }
 
-impl<const K: u8, const N: usize> Mul<Scalar> for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize> Mul<F> for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
     type Output = Self;
 
     #[inline]
-    fn mul(self, other: Scalar) -> Self {
+    fn mul(self, other: F) -> Self {
         Self {
-            components: self.components * Simd::splat(other),
+            components: self.components * Lanes::splat(other),
         }
     }
 }
 
-impl<const K: u8, const N: usize, T> Mul<T> for KVector<K, N>
-where
-    LaneCount<N>: SupportedLaneCount,
-    T: Multivector,
-    T: NonScalar,
-{
-    type Output = Versor;
-    #[inline]
-    fn mul(self, other: T) -> Versor {
-        self.geo(other)
-    }
-}
-
-impl<const K: u8, const N: usize> Div<Scalar> for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize> Div<F> for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
     type Output = Self;
 
     #[inline]
-    fn div(self, other: Scalar) -> Self {
+    fn div(self, other: F) -> Self {
         Self {
-            components: self.components / Simd::splat(other),
+            components: self.components / Lanes::splat(other),
         }
     }
 }
 
-impl<const K: u8, const N: usize, T> BitXor<T> for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize, T> BitXor<T> for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
-    T: SingleGrade,
+    T: SingleGrade<Pga3<F>>,
 {
-    type Output = AnyKVector;
+    type Output = AnyKVector<F>;
 
     #[inline]
     fn bitxor(self, rhs: T) -> Self::Output {
@@ -159,12 +152,12 @@ where
     }
 }
 
-impl<const K: u8, const N: usize, T> BitAnd<T> for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize, T> BitAnd<T> for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
-    T: SingleGrade,
+    T: SingleGrade<Pga3<F>>,
 {
-    type Output = AnyKVector;
+    type Output = AnyKVector<F>;
 
     #[inline]
     fn bitand(self, rhs: T) -> Self::Output {
@@ -172,12 +165,12 @@ where
     }
 }
 
-impl<const K: u8, const N: usize, T> BitOr<T> for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize, T> BitOr<T> for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
-    T: SingleGrade,
+    T: SingleGrade<Pga3<F>>,
 {
-    type Output = AnyKVector;
+    type Output = AnyKVector<F>;
 
     #[inline]
     fn bitor(self, rhs: T) -> Self::Output {
@@ -185,29 +178,28 @@ where
     }
 }
 
-impl<const K: u8, const N: usize> From<[f32; N]> for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize> From<[F; N]> for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
     #[inline]
-    fn from(value: [f32; N]) -> Self {
-        Self {
-            components: Simd::from(value),
-        }
+    fn from(value: [F; N]) -> Self {
+        Self::from_array(value)
     }
 }
 
-impl<const K: u8, const N: usize> From<Simd<f32, N>> for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize> From<Lanes<F, N>> for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
     #[inline]
-    fn from(value: Simd<f32, N>) -> Self {
+    fn from(value: Lanes<F, N>) -> Self {
+        let () = Self::GRADE;
         Self { components: value }
     }
 }
 
-impl<const K: u8, const N: usize> Multivector for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize> Multivector<Pga3<F>> for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
@@ -218,21 +210,21 @@ where
 
     #[inline]
     fn grade_involution(&self) -> Self {
-        *self * (-1_i32).pow(K as u32) as Scalar
+        *self * F::from((-1_i32).pow(K as u32) as f32)
     }
 
     // basis is a binary value where the bits, from right-to-
     // left, represent e3, e2, e1, and e0 respectively being
     // present in the basis being requested
     #[inline]
-    fn e(&self, basis: u8) -> f32 {
+    fn e(&self, basis: u8) -> F {
         match K {
             1 => match basis {
                 0b0100 => self[0],
                 0b0010 => self[1],
                 0b0001 => self[2],
                 0b1000 => self[3],
-                _ => 0.0,
+                _ => F::ZERO,
             },
             2 => match basis {
                 0b0110 => self[0],
@@ -241,25 +233,25 @@ where
                 0b1100 => self[3],
                 0b1010 => self[4],
                 0b1001 => self[5],
-                _ => 0.0,
+                _ => F::ZERO,
             },
             3 => match basis {
                 0b0111 => self[0],
                 0b1011 => self[1],
                 0b1101 => self[2],
                 0b1110 => self[3],
-                _ => 0.0,
+                _ => F::ZERO,
             },
-            _ => 0.0,
+            _ => F::ZERO,
         }
     }
 
     #[inline]
-    fn grade(&self, g: u8) -> AnyKVector {
+    fn grade(&self, g: u8) -> AnyKVector<F> {
         if g == K {
             (*self).into()
         } else {
-            AnyKVector::Zero(0.0)
+            AnyKVector::Zero(F::ZERO)
         }
     }
 
@@ -270,58 +262,58 @@ where
 
     #[inline]
     fn zero(&self) -> bool {
-        self.components == Simd::splat(0.0)
+        self.components == Lanes::splat(F::ZERO)
     }
 
     #[inline]
     fn is_ideal(&self) -> bool {
         self[0..Self::ideal_index()]
             .iter()
-            .fold(true, |acc, f| acc && float_eq(*f, 0.0))
+            .fold(true, |acc, f| acc && float_eq(*f, F::ZERO))
     }
 
     #[inline]
-    fn dual(self) -> Versor {
+    fn dual(self) -> Versor<F> {
         match K {
-            1 => Versor::KVec(AnyKVector::Three(Trivector::from(simd_swizzle!(
+            1 => Versor::KVec(AnyKVector::Three(Trivector::from(swizzle!(
                 self.components,
                 [3, 0, 1, 2]
             )))),
             2 => Versor::KVec(AnyKVector::Two(Bivector::from([
                 self[5], self[4], self[3], self[2], self[1], self[0],
             ]))),
-            3 => Versor::KVec(AnyKVector::One(Vector::from(-simd_swizzle!(
+            3 => Versor::KVec(AnyKVector::One(Vector::from(-swizzle!(
                 self.components,
                 [1, 2, 3, 0]
             )))),
-            _ => Versor::from(0.0),
+            _ => Versor::from(F::ZERO),
         }
     }
 
     #[inline]
-    fn undual(self) -> Versor {
+    fn undual(self) -> Versor<F> {
         match K {
             1 => -self.dual(),
             2 => self.dual(),
             3 => -self.dual(),
-            _ => Versor::from(0.0),
+            _ => Versor::from(F::ZERO),
         }
     }
 
     #[inline]
-    fn geo<T: Multivector>(self, rhs: T) -> Versor {
+    fn geo<T: Multivector<Pga3<F>>>(self, rhs: T) -> Versor<F> {
         use AnyKVector::*;
         use Versor::*;
         match rhs.into() {
             Even(m) => {
                 let Two(rhs_g2) = m.grade(2) else {
-                    panic!("Grade 2 of motor should be a bivector")
+                    unreachable!("Grade 2 of motor should be a bivector")
                 };
                 match K % 2 {
                     0 => {
                         let t1 = {
                             let Two(bv) = (self * m.e(0b0000)).into() else {
-                                panic!("Scaled bivector should be a bivector");
+                                unreachable!("Scaled bivector should be a bivector");
                             };
                             Motor::from(bv)
                         };
@@ -331,15 +323,17 @@ where
                                 Zero(s) => Motor::from(s),
                                 Two(bv) => Motor::from(bv),
                                 Four(ps) => Motor::from(ps),
-                                _ => panic!("Invalid product of bivectors"),
+                                _ => unreachable!("Invalid product of bivectors"),
                             },
-                            _ => panic!("Bivector * Bivector should be a motor"),
+                            _ => unreachable!("Bivector<F> * Bivector<F> should be a motor"),
                         };
                         let t3 = {
                             let bv = match self.inner(m.grade(4)) {
-                                Zero(0.0) => Bivector::from([0.0; 6]),
+                                Zero(z) if z == F::ZERO => Bivector::from([F::ZERO; 6]),
                                 Two(bv) => bv,
-                                _ => panic!("Bivector | Pseudoscalar should be a bivector"),
+                                _ => unreachable!(
+                                    "Bivector<F> | Pseudoscalar<F> should be a bivector"
+                                ),
                             };
                             Motor::from(bv)
                         };
@@ -349,52 +343,55 @@ where
                         let t1 = match (self * m.e(0b0000)).into() {
                             One(v) => OddVersor::from(v),
                             Three(tv) => OddVersor::from(tv),
-                            _ => panic!("Scaled odd K-vector should still be odd K-vector"),
+                            _ => unreachable!("Scaled odd K-vector should still be odd K-vector"),
                         };
                         let t2 = match rhs_g2.reverse_geo_kvector(self) {
                             Odd(ov) => ov,
-                            KVec(Zero(0.0)) => OddVersor::from([0.0; 8]),
+                            KVec(Zero(z)) if z == F::ZERO => OddVersor::from([F::ZERO; 8]),
                             KVec(One(v)) => OddVersor::from(v),
                             KVec(Three(tv)) => OddVersor::from(tv),
-                            _ => panic!("Odd K-Vector * Motor should be an odd versor"),
+                            _ => unreachable!("Odd K-Vector<F> * Motor<F> should be an odd versor"),
                         };
                         let t3 = match self.inner(m.grade(4)) {
                             One(v) => OddVersor::from(v),
                             Three(tv) => OddVersor::from(tv),
                             _ => {
-                                panic!(
-                                    "Odd K-Vector * Pseudoscalar should still be an odd K-vector"
+                                unreachable!(
+                                    "Odd K-Vector<F> * Pseudoscalar<F> should still be an odd K-vector"
                                 )
                             }
                         };
                         Versor::from(t1 + t2 + t3)
                     }
-                    _ => panic!("Anything mod 2 should be 0 or 1"),
+                    _ => unreachable!("Anything mod 2 should be 0 or 1"),
                 }
             }
             Odd(ov) => {
                 let One(rhs_g1) = ov.grade(1) else {
-                    panic!("Grade 1 of odd versor should be a trivector");
+                    unreachable!("Grade 1 of odd versor should be a trivector");
                 };
                 let Three(rhs_g3) = ov.grade(3) else {
-                    panic!("Grade 3 of odd versor should be a trivector");
+                    unreachable!("Grade 3 of odd versor should be a trivector");
                 };
                 match K % 2 {
                     0 => {
-                        use std::simd::f32x8;
                         let t1 = match rhs_g1.reverse_geo_kvector(self) {
                             Odd(ov) => ov,
-                            KVec(Zero(0.0)) => OddVersor::from(f32x8::splat(0.0)),
+                            KVec(Zero(z)) if z == F::ZERO => {
+                                OddVersor::from(Lanes::<F, 8>::splat(F::ZERO))
+                            }
                             KVec(One(v)) => OddVersor::from(v),
                             KVec(Three(tv)) => OddVersor::from(tv),
-                            _ => panic!("vector * bivector should be an odd versor"),
+                            _ => unreachable!("vector * bivector should be an odd versor"),
                         };
                         let t2 = match rhs_g3.reverse_geo_kvector(self) {
                             Odd(ov) => ov,
-                            KVec(Zero(0.0)) => OddVersor::from(f32x8::splat(0.0)),
+                            KVec(Zero(z)) if z == F::ZERO => {
+                                OddVersor::from(Lanes::<F, 8>::splat(F::ZERO))
+                            }
                             KVec(One(v)) => OddVersor::from(v),
                             KVec(Three(tv)) => OddVersor::from(tv),
-                            _ => panic!("trivector * bivector should be an odd versor"),
+                            _ => unreachable!("trivector * bivector should be an odd versor"),
                         };
                         Versor::from(t1 + t2)
                     }
@@ -404,18 +401,18 @@ where
                             KVec(Zero(s)) => Motor::from(s),
                             KVec(Two(bv)) => Motor::from(bv),
                             KVec(Four(ps)) => Motor::from(ps),
-                            _ => panic!("vector * odd k-vector should be a motor"),
+                            _ => unreachable!("vector * odd k-vector should be a motor"),
                         };
                         let t2 = match rhs_g3.reverse_geo_kvector(self) {
                             Even(m) => m,
                             KVec(Zero(s)) => Motor::from(s),
                             KVec(Two(bv)) => Motor::from(bv),
                             KVec(Four(ps)) => Motor::from(ps),
-                            _ => panic!("trivector * odd k-vector should be a motor"),
+                            _ => unreachable!("trivector * odd k-vector should be a motor"),
                         };
                         Versor::from(t1 + t2)
                     }
-                    _ => panic!("Anything mod 2 should be 0 or 1"),
+                    _ => unreachable!("Anything mod 2 should be 0 or 1"),
                 }
             }
             KVec(kv) => match kv {
@@ -425,11 +422,12 @@ where
                 Three(tv) => tv.reverse_geo_kvector(self),
                 Four(ps) => self.inner(ps).into(),
             },
+            Mixed(mv) => Multivector16::from(self).geo(mv),
         }
     }
 
     fn normalize(self) -> Self {
-        if K == 3 && self[0] != 0.0 {
+        if K == 3 && self[0] != F::ZERO {
             self / self[0]
         } else {
             self / self.magnitude()
@@ -445,105 +443,110 @@ where
                 Some(self.reverse() / self.magnitude().powi(2))
             }
             2 => {
-                let mut square = match self * self {
-                    Versor::Even(m) => m,
-                    Versor::KVec(AnyKVector::Zero(s)) => Motor::from(s),
-                    Versor::KVec(AnyKVector::Four(ps)) => Motor::from(ps),
-                    _ => panic!("Square of bivector should be scalar + pseudoscalar"),
-                };
-                square[7] = -square[7];
-                match (self * square) / square[0].powi(2) {
+                // B B = B | B + B ^ B, and B (B | B - B ^ B) is a bivector
+                let s = self.inner(self).e(0b0000);
+                if float_eq(s, F::ZERO) {
+                    return None;
+                }
+                let p = self.outer(self).e(0b1111);
+                let conjugate =
+                    Motor::from([s, F::ZERO, F::ZERO, F::ZERO, F::ZERO, F::ZERO, F::ZERO, -p]);
+                match (self * conjugate) / s.powi(2) {
                     Versor::KVec(AnyKVector::Two(bv)) => {
                         Some(Self::from(bv.components.extract::<0, N>()))
                     }
-                    _ => panic!("Inverse of a bivector should be a bivector"),
+                    _ => unreachable!("Inverse of a bivector should be a bivector"),
                 }
             }
-            _ => panic!("Attempt to invert kvector of invalid grade: {K}"),
+            _ => unreachable!("Attempt to invert kvector of invalid grade: {K}"),
         }
     }
 }
 
-impl<const K: u8, const N: usize> SingleGrade for KVector<K, N>
+impl<F: Float, const K: u8, const N: usize> SingleGrade<Pga3<F>> for KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
+    const GRADE: Option<u8> = Some(K);
+
     #[inline]
-    fn outer<T: SingleGrade>(self, rhs: T) -> AnyKVector {
+    fn outer<T: SingleGrade<Pga3<F>>>(self, rhs: T) -> AnyKVector<F> {
         use AnyKVector::*;
-        let rev_mult = if K == 1 { -1.0 } else { 1.0 };
+        let rev_mult = if K == 1 { -F::ONE } else { F::ONE };
         match rhs.into() {
             Zero(s) => (self * s).into(),
             One(v) => v.outer_kvector(self.grade_involution()),
             Two(bv) => bv.outer_kvector(self),
             Three(tv) => tv.outer_kvector(self * rev_mult),
-            Four(_) => 0.0.into(),
+            Four(_) => F::ZERO.into(),
         }
     }
 
     #[inline]
-    fn inner<T: SingleGrade>(self, rhs: T) -> AnyKVector {
+    fn inner<T: SingleGrade<Pga3<F>>>(self, rhs: T) -> AnyKVector<F> {
         use AnyKVector::*;
-        let rev_mult = if K == 1 { -1.0 } else { 1.0 };
+        let rev_mult = if K == 1 { -F::ONE } else { F::ONE };
         match rhs.into() {
             Zero(s) => (self * s).into(),
             One(v) => v.inner_kvector(-self.grade_involution()),
             Two(bv) => bv.inner_kvector(self * rev_mult),
             Three(tv) => tv.inner_kvector(self),
             Four(ps) => {
-                let result = -Self::from(Simd::load_or(self.real_part(), Simd::splat(0.0)))
+                // e123 * e0123 = e0, whereas the dual of e123 is -e0
+                let dual_mult = if K == 3 { -F::ONE } else { F::ONE };
+                let result = -Self::from(Lanes::load_or(self.real_part(), Lanes::splat(F::ZERO)))
                     .dual()
                     .grade_involution()
-                    * ps.0;
+                    * (ps.0 * dual_mult);
                 match result {
                     Versor::KVec(kv) => kv,
-                    _ => panic!("Negative dual of real part of a k-vector should be a k-vector"),
+                    _ => unreachable!(
+                        "Negative dual of real part of a k-vector should be a k-vector"
+                    ),
                 }
             }
         }
     }
 
     #[inline]
-    fn assert<T: SingleGrade + 'static>(self) -> T {
-        use std::any::Any;
-        let Some(res) = (&self as &dyn Any).downcast_ref::<T>() else {
-            panic!("Single-grade assert failed");
-        };
-        *res
+    fn try_assert<T: SingleGrade<Pga3<F>> + 'static>(self) -> Result<T, PgaError> {
+        downcast_grade(&self, K)
     }
 
     #[inline]
-    fn scale(self, scale: Trivector) -> Self {
-        match K {
-            1 => {
-                let mut res = self;
-                res[3] = self[3] * (self | scale.undual().assert::<Vector>()).assert::<Scalar>()
-                    / (self[0] + self[1] + self[2]);
-                res
-            }
-            2 => {
-                let origin = ((e123 | self) * self)
-                    .normalize()
-                    .assert::<Trivector>()
-                    .scale(scale);
-                let forwards = Trivector::from([0.0, -self[2], -self[1], -self[0]]);
-                ((origin + forwards.scale(scale)) & origin).assert::<Self>()
-            }
-            3 => {
-                let new = self.components.extract::<0, 4>() * scale.components;
-                Self::from(Simd::load_or(new.as_array(), Simd::from([0.0; N])))
-            }
-            _ => panic!("Invalid K-vector grade"),
+    fn is_blade(self) -> bool {
+        match (&self as &dyn Any).downcast_ref::<Bivector<F>>() {
+            Some(bv) => bv.is_simple(),
+            None => true,
         }
     }
 }
 
-impl<const K: u8, const N: usize> KVector<K, N>
+impl<F: Float, const K: u8, const N: usize> KVector<K, N, F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
+    const GRADE: () = assert!(
+        matches!((K, N), (1, 4) | (2, 6) | (3, 4)),
+        "K-vectors are vectors, bivectors or trivectors"
+    );
+
+    /// The k-vector with the given components, as a const alternative to `From<[Float; N]>`
+    #[inline]
+    pub const fn from_array(cs: [F; N]) -> Self {
+        let () = Self::GRADE;
+        Self {
+            components: Lanes::from_array(cs),
+        }
+    }
+
     #[inline]
-    const fn ideal_index() -> usize {
+    pub const fn to_array(self) -> [F; N] {
+        self.components.to_array()
+    }
+
+    #[inline]
+    pub(crate) const fn ideal_index() -> usize {
         match K {
             1 => 3,
             2 => 3,
@@ -553,205 +556,211 @@ where
     }
 
     #[inline]
-    const fn reverse_multiplier() -> Scalar {
+    fn reverse_multiplier() -> F {
         match K {
-            2 => -1.0,
-            3 => -1.0,
-            _ => 1.0,
+            2 => -F::ONE,
+            3 => -F::ONE,
+            _ => F::ONE,
         }
     }
 
     #[inline]
-    pub fn real_part(&self) -> &[f32] {
+    pub fn real_part(&self) -> &[F] {
         &self[0..Self::ideal_index()]
     }
 
     #[inline]
-    pub fn ideal_part(&self) -> &[f32] {
+    pub fn ideal_part(&self) -> &[F] {
         &self[Self::ideal_index()..N]
     }
 
     #[inline]
     pub fn snap_to_zero(mut self) -> Self {
         for f in &mut self.components[0..N] {
-            if float_eq(*f, 0.0) {
-                *f = 0.0;
+            if float_eq(*f, F::ZERO) {
+                *f = F::ZERO;
             }
         }
         self
     }
 
-    pub fn inner_kvector<const G: u8, const D: usize>(self, rhs: KVector<G, D>) -> AnyKVector
+    pub fn inner_kvector<const G: u8, const D: usize>(self, rhs: KVector<G, D, F>) -> AnyKVector<F>
     where
         LaneCount<D>: SupportedLaneCount,
     {
-        fn inner_vector_trivector(v: Vector, tv: Trivector) -> Bivector {
-            let t1 = simd_swizzle!(v.components, [2, 1, 0, 2, 0, 1]);
-            let t2 = simd_swizzle!(tv.components, [0, 0, 0, 2, 3, 1]);
-            let t3 = Simd::from([0.0, 0.0, 0.0, v[1], v[2], v[0]]);
-            let t4 = Simd::from([0.0, 0.0, 0.0, tv[3], tv[1], tv[2]]);
+        fn inner_vector_trivector<F: Float>(v: Vector<F>, tv: Trivector<F>) -> Bivector<F> {
+            let t1 = swizzle!(v.components, [2, 1, 0, 2, 0, 1]);
+            let t2 = swizzle!(tv.components, [0, 0, 0, 2, 3, 1]);
+            let t3 = Lanes::from([F::ZERO, F::ZERO, F::ZERO, v[1], v[2], v[0]]);
+            let t4 = Lanes::from([F::ZERO, F::ZERO, F::ZERO, tv[3], tv[1], tv[2]]);
             Bivector::from(t1 * t2 - t3 * t4).into()
         }
-        fn inner_vector_bivector(v: Vector, bv: Bivector) -> Vector {
-            let mut t1 = simd_swizzle!(v.components, [2, 0, 1, 0]);
+        fn inner_vector_bivector<F: Float>(v: Vector<F>, bv: Bivector<F>) -> Vector<F> {
+            let mut t1 = swizzle!(v.components, [2, 0, 1, 0]);
             t1[3] = -t1[3];
-            let t2 = simd_swizzle!(bv.components, [1, 0, 2, 3]);
-            let t3 = simd_swizzle!(v.components, [1, 2, 0, 1]);
-            let t4 = simd_swizzle!(bv.components, [0, 2, 1, 4]);
-            let t5 = Simd::from([0.0, 0.0, 0.0, v[2] * bv[5]]);
+            let t2 = swizzle!(bv.components, [1, 0, 2, 3]);
+            let t3 = swizzle!(v.components, [1, 2, 0, 1]);
+            let t4 = swizzle!(bv.components, [0, 2, 1, 4]);
+            let t5 = Lanes::from([F::ZERO, F::ZERO, F::ZERO, v[2] * bv[5]]);
             Vector::from(t1 * t2 - t3 * t4 - t5)
         }
-        fn inner_bivector_trivector(bv: Bivector, tv: Trivector) -> Vector {
-            let t1 = Simd::from([0.0, 0.0, 0.0, bv[0] * tv[3]]);
-            let mut t2 = simd_swizzle!(bv.components, [2, 1, 0, 1]);
+        fn inner_bivector_trivector<F: Float>(bv: Bivector<F>, tv: Trivector<F>) -> Vector<F> {
+            let t1 = Lanes::from([F::ZERO, F::ZERO, F::ZERO, bv[0] * tv[3]]);
+            let mut t2 = swizzle!(bv.components, [2, 1, 0, 1]);
             t2[3] = -t2[3];
-            let t3 = simd_swizzle!(tv.components, [0, 0, 0, 2]);
-            let t4 = Simd::from([0.0, 0.0, 0.0, bv[2] * tv[1]]);
+            let t3 = swizzle!(tv.components, [0, 0, 0, 2]);
+            let t4 = Lanes::from([F::ZERO, F::ZERO, F::ZERO, bv[2] * tv[1]]);
             Vector::from(t1 - t2 * t3 + t4)
         }
         match K as i8 - G as i8 {
             0 => {
                 let Some(rhs) = (&rhs as &dyn Any).downcast_ref::<Self>() else {
-                    return 0.0.into();
+                    return F::ZERO.into();
                 };
-                (self.components * rhs.components * Simd::splat(Self::reverse_multiplier()))
+                (self.components * rhs.components * Lanes::splat(Self::reverse_multiplier()))
                     [0..Self::ideal_index()]
                     .iter()
-                    .fold(0.0, |acc, f| acc + f)
+                    .fold(F::ZERO, |acc, f| acc + *f)
                     .into()
             }
             1 => match K {
                 2 => {
-                    let Some(v) = (&rhs as &dyn Any).downcast_ref::<Vector>() else {
-                        panic!(
+                    let Some(v) = (&rhs as &dyn Any).downcast_ref::<Vector<F>>() else {
+                        unreachable!(
                             "Right-hand-side of inner product where K - G = 1 and K = 2 should be a vector"
                         );
                     };
-                    let Some(bv) = (&self as &dyn Any).downcast_ref::<Bivector>() else {
-                        panic!(
+                    let Some(bv) = (&self as &dyn Any).downcast_ref::<Bivector<F>>() else {
+                        unreachable!(
                             "Left-hand-side of inner product where K - G = 1 and K = 2 should be a bivector"
                         );
                     };
                     (-inner_vector_bivector(*v, *bv)).into()
                 }
                 3 => {
-                    let Some(bv) = (&rhs as &dyn Any).downcast_ref::<Bivector>() else {
-                        panic!(
+                    let Some(bv) = (&rhs as &dyn Any).downcast_ref::<Bivector<F>>() else {
+                        unreachable!(
                             "Right-hand-side of inner product where K - G = 1 and K = 3 should be a bivector"
                         );
                     };
-                    let Some(tv) = (&self as &dyn Any).downcast_ref::<Trivector>() else {
-                        panic!(
+                    let Some(tv) = (&self as &dyn Any).downcast_ref::<Trivector<F>>() else {
+                        unreachable!(
                             "Left-hand-side of inner product where K - G = 1 and K = 3 should be a trivector"
                         );
                     };
                     inner_bivector_trivector(*bv, *tv).into()
                 }
-                _ => panic!(
+                _ => unreachable!(
                     "Left-hand-side of inner product with output grade 1 where K > G should be of grade 2 or 3"
                 ),
             },
             -1 => match K {
                 1 => {
-                    let Some(v) = (&self as &dyn Any).downcast_ref::<Vector>() else {
-                        panic!(
+                    let Some(v) = (&self as &dyn Any).downcast_ref::<Vector<F>>() else {
+                        unreachable!(
                             "Left-hand-side of inner product where K - G = -1 and K = 1 should be a vector"
                         );
                     };
-                    let Some(bv) = (&rhs as &dyn Any).downcast_ref::<Bivector>() else {
-                        panic!(
+                    let Some(bv) = (&rhs as &dyn Any).downcast_ref::<Bivector<F>>() else {
+                        unreachable!(
                             "Left-hand-side of inner product where K - G = -1 and K = 1 should be a bivector"
                         );
                     };
                     (inner_vector_bivector(*v, *bv)).into()
                 }
                 2 => {
-                    let Some(bv) = (&self as &dyn Any).downcast_ref::<Bivector>() else {
-                        panic!(
+                    let Some(bv) = (&self as &dyn Any).downcast_ref::<Bivector<F>>() else {
+                        unreachable!(
                             "Left-hand-side of inner product where K - G = -1 and K = 2 should be a bivector"
                         );
                     };
-                    let Some(tv) = (&rhs as &dyn Any).downcast_ref::<Trivector>() else {
-                        panic!(
+                    let Some(tv) = (&rhs as &dyn Any).downcast_ref::<Trivector<F>>() else {
+                        unreachable!(
                             "Right-hand-side of inner product where K - G = -1 and K = 2 should be a trivector"
                         );
                     };
                     inner_bivector_trivector(*bv, *tv).into()
                 }
-                _ => panic!(
+                _ => unreachable!(
                     "Left-hand-side of inner product with output grade 1 where K < G should be of grade 1 or 2"
                 ),
             },
             2 => {
-                let Some(v) = (&rhs as &dyn Any).downcast_ref::<Vector>() else {
-                    panic!("Right-hand-side of inner product where K - G = 2 should be a vector");
+                let Some(v) = (&rhs as &dyn Any).downcast_ref::<Vector<F>>() else {
+                    unreachable!(
+                        "Right-hand-side of inner product where K - G = 2 should be a vector"
+                    );
                 };
-                let Some(tv) = (&self as &dyn Any).downcast_ref::<Trivector>() else {
-                    panic!("Left-hand-side of inner product where K - G = 2 should be a trivector");
+                let Some(tv) = (&self as &dyn Any).downcast_ref::<Trivector<F>>() else {
+                    unreachable!(
+                        "Left-hand-side of inner product where K - G = 2 should be a trivector"
+                    );
                 };
                 inner_vector_trivector(*v, *tv).into()
             }
             -2 => {
-                let Some(v) = (&self as &dyn Any).downcast_ref::<Vector>() else {
-                    panic!("Left-hand-side of inner product where K - G = -2 should be a vector");
+                let Some(v) = (&self as &dyn Any).downcast_ref::<Vector<F>>() else {
+                    unreachable!(
+                        "Left-hand-side of inner product where K - G = -2 should be a vector"
+                    );
                 };
-                let Some(tv) = (&rhs as &dyn Any).downcast_ref::<Trivector>() else {
-                    panic!(
+                let Some(tv) = (&rhs as &dyn Any).downcast_ref::<Trivector<F>>() else {
+                    unreachable!(
                         "Right-hand-side of inner product where K - G = -2 should be a trivector"
                     );
                 };
                 inner_vector_trivector(*v, *tv).into()
             }
-            _ => panic!("Absolute difference of the grade of KVectors should be <= 2"),
+            _ => unreachable!("Absolute difference of the grade of KVectors should be <= 2"),
         }
     }
 
-    pub fn outer_kvector<const G: u8, const D: usize>(self, rhs: KVector<G, D>) -> AnyKVector
+    pub fn outer_kvector<const G: u8, const D: usize>(self, rhs: KVector<G, D, F>) -> AnyKVector<F>
     where
         LaneCount<D>: SupportedLaneCount,
     {
-        let result: AnyKVector = match K + G {
+        let result: AnyKVector<F> = match K + G {
             2 => {
                 const I1: [usize; 6] = [0, 2, 1, 3, 3, 3];
                 const I2: [usize; 6] = [1, 0, 2, 0, 1, 2];
-                let t1 = simd_swizzle!(self.components, I1);
-                let t2 = simd_swizzle!(rhs.components, I2);
-                let t3 = simd_swizzle!(self.components, I2);
-                let t4 = simd_swizzle!(rhs.components, I1);
+                let t1 = swizzle!(self.components, I1);
+                let t2 = swizzle!(rhs.components, I2);
+                let t3 = swizzle!(self.components, I2);
+                let t4 = swizzle!(rhs.components, I1);
                 Bivector::from(t1 * t2 - t3 * t4).into()
             }
             3 => {
-                let t1 = f32x4::from([
+                let t1 = Lanes::<F, 4>::from([
                     self.e(0b0100) * rhs.e(0b0011),
                     -self.e(0b1000) * rhs.e(0b0011),
                     -self.e(0b1000) * rhs.e(0b0101),
                     -self.e(0b1000) * rhs.e(0b0110),
                 ]);
-                let t2 = f32x4::from([
+                let t2 = Lanes::<F, 4>::from([
                     self.e(0b0010) * rhs.e(0b0101),
                     self.e(0b0010) * rhs.e(0b1001),
                     -self.e(0b0100) * rhs.e(0b1001),
                     self.e(0b0100) * rhs.e(0b1010),
                 ]);
-                let t3 = f32x4::from([
+                let t3 = Lanes::<F, 4>::from([
                     self.e(0b0001) * rhs.e(0b0110),
                     -self.e(0b0001) * rhs.e(0b1010),
                     self.e(0b0001) * rhs.e(0b1100),
                     -self.e(0b0010) * rhs.e(0b1100),
                 ]);
-                let t4 = f32x4::from([
+                let t4 = Lanes::<F, 4>::from([
                     self.e(0b0110) * rhs.e(0b0001),
                     -self.e(0b0011) * rhs.e(0b1000),
                     -self.e(0b0101) * rhs.e(0b1000),
                     -self.e(0b0110) * rhs.e(0b1000),
                 ]);
-                let t5 = f32x4::from([
+                let t5 = Lanes::<F, 4>::from([
                     self.e(0b0101) * rhs.e(0b0010),
                     self.e(0b1001) * rhs.e(0b0010),
                     -self.e(0b1001) * rhs.e(0b0100),
                     self.e(0b1010) * rhs.e(0b0100),
                 ]);
-                let t6 = f32x4::from([
+                let t6 = Lanes::<F, 4>::from([
                     self.e(0b0011) * rhs.e(0b0100),
                     -self.e(0b1010) * rhs.e(0b0001),
                     self.e(0b1100) * rhs.e(0b0001),
@@ -780,28 +789,31 @@ where
                         - self.e(0b1110) * rhs.e(0b0001),
                 )
                 .into(),
-                _ => panic!(
+                _ => unreachable!(
                     "Absolute difference of grades of KVectors whose grades sum to 4 should be either 0 or 2"
                 ),
             },
-            _ => 0.0.into(),
+            _ => F::ZERO.into(),
         };
         if result.zero() {
-            AnyKVector::Zero(0.0)
+            AnyKVector::Zero(F::ZERO)
         } else {
             result
         }
     }
 
     #[inline]
-    pub fn reverse_geo_kvector<const G: u8, const D: usize>(self, rhs: KVector<G, D>) -> Versor
+    pub fn reverse_geo_kvector<const G: u8, const D: usize>(
+        self,
+        rhs: KVector<G, D, F>,
+    ) -> Versor<F>
     where
         LaneCount<D>: SupportedLaneCount,
     {
         self.reverse().geo_kvector(rhs.reverse()).reverse()
     }
 
-    pub fn geo_kvector<const G: u8, const D: usize>(self, rhs: KVector<G, D>) -> Versor
+    pub fn geo_kvector<const G: u8, const D: usize>(self, rhs: KVector<G, D, F>) -> Versor<F>
     where
         LaneCount<D>: SupportedLaneCount,
     {
@@ -811,7 +823,7 @@ where
                 2 => {
                     let s = match self | rhs {
                         Zero(s) => s,
-                        _ => panic!(
+                        _ => unreachable!(
                             "First term of geometric product between vectors should be a scalar"
                         ),
                     };
@@ -823,57 +835,57 @@ where
                                 return Versor::from(Zero(s));
                             }
                         }
-                        Zero(0.0) => return Versor::from(Zero(s)),
-                        _ => panic!(
+                        Zero(z) if z == F::ZERO => return Versor::from(Zero(s)),
+                        _ => unreachable!(
                             "Second term of geometric product between vectors should be a bivector"
                         ),
                     };
-                    Versor::from(Motor::from((s, bv, Pseudoscalar(0.0))))
+                    Versor::from(Motor::from((s, bv, Pseudoscalar(F::ZERO))))
                 }
                 _ => match K == G {
                     false => {
                         let bv = match self | rhs {
                             Two(bv) => bv,
-                            Zero(0.0) => return Versor::from(self ^ rhs),
-                            _ => panic!(
+                            Zero(z) if z == F::ZERO => return Versor::from(self ^ rhs),
+                            _ => unreachable!(
                                 "First term of geometric product producing a motor where K != G should be a bivector"
                             ),
                         };
                         let ps = match self ^ rhs {
                             Four(ps) => ps,
-                            Zero(0.0) => return Versor::from(Two(bv)),
-                            _ => panic!(
+                            Zero(z) if z == F::ZERO => return Versor::from(Two(bv)),
+                            _ => unreachable!(
                                 "Second term of geometric product producing a motor where K != G should be a pseudoscalar"
                             ),
                         };
-                        Versor::from(Motor::from((0.0, bv, ps)))
+                        Versor::from(Motor::from((F::ZERO, bv, ps)))
                     }
                     true => {
                         let s = match self | rhs {
                             Zero(s) => s,
-                            _ => panic!(
+                            _ => unreachable!(
                                 "First term of geometric product producing a motor where K == G should be a scalar"
                             ),
                         };
                         let bv = {
                             match rhs.into() {
                                 Two(rhs) => {
-                                    let t1 = simd_swizzle!(self.components, [1, 2, 0, 0, 2, 1]);
-                                    let t2 = simd_swizzle!(rhs.components, [2, 0, 1, 4, 5, 3]);
-                                    let t3 = simd_swizzle!(self.components, [2, 0, 1, 1, 0, 2]);
-                                    let t4 = simd_swizzle!(rhs.components, [1, 2, 0, 5, 3, 4]);
-                                    let t5 = Simd::from([
-                                        0.0,
-                                        0.0,
-                                        0.0,
+                                    let t1 = swizzle!(self.components, [1, 2, 0, 0, 2, 1]);
+                                    let t2 = swizzle!(rhs.components, [2, 0, 1, 4, 5, 3]);
+                                    let t3 = swizzle!(self.components, [2, 0, 1, 1, 0, 2]);
+                                    let t4 = swizzle!(rhs.components, [1, 2, 0, 5, 3, 4]);
+                                    let t5 = Lanes::from([
+                                        F::ZERO,
+                                        F::ZERO,
+                                        F::ZERO,
                                         self[5] * rhs[1],
                                         self[3] * rhs[0],
                                         self[4] * rhs[2],
                                     ]);
-                                    let t6 = Simd::from([
-                                        0.0,
-                                        0.0,
-                                        0.0,
+                                    let t6 = Lanes::from([
+                                        F::ZERO,
+                                        F::ZERO,
+                                        F::ZERO,
                                         self[4] * rhs[0],
                                         self[5] * rhs[2],
                                         self[3] * rhs[1],
@@ -882,24 +894,24 @@ where
                                 }
                                 Three(rhs) => {
                                     let t1 =
-                                        self.components.extract::<1, 3>() * Simd::splat(rhs[0]);
+                                        self.components.extract::<1, 3>() * Lanes::splat(rhs[0]);
                                     let t2 =
-                                        rhs.components.extract::<1, 3>() * Simd::splat(self[0]);
+                                        rhs.components.extract::<1, 3>() * Lanes::splat(self[0]);
                                     let result =
-                                        (t1 - t2).resize::<6>(0.0).rotate_elements_right::<3>();
+                                        (t1 - t2).resize::<6>(F::ZERO).rotate_elements_right::<3>();
                                     Bivector::from(result)
                                 }
-                                _ => panic!(
+                                _ => unreachable!(
                                     "Geometric product producing a motor where K == G should be between same-grade elements"
                                 ),
                             }
                         };
                         let ps = match self ^ rhs {
                             Four(ps) => ps,
-                            Zero(0.0) => {
-                                return Versor::from(Motor::from((s, bv, Pseudoscalar(0.0))));
+                            Zero(z) if z == F::ZERO => {
+                                return Versor::from(Motor::from((s, bv, Pseudoscalar(F::ZERO))));
                             }
-                            _ => panic!(
+                            _ => unreachable!(
                                 "Last term of geometric product between bivectors should be a pseudoscalar"
                             ),
                         };
@@ -910,8 +922,8 @@ where
             1 => {
                 let v = match self | rhs {
                     One(v) => v,
-                    Zero(0.0) => return Versor::from(self ^ rhs),
-                    _ => panic!(
+                    Zero(z) if z == F::ZERO => return Versor::from(self ^ rhs),
+                    _ => unreachable!(
                         "First term of geometric product producing an odd versor should be a vector"
                     ),
                 };
@@ -919,8 +931,8 @@ where
                     3 => {
                         let tv = match self ^ rhs {
                             Three(tv) => tv,
-                            Zero(0.0) => return Versor::from(v),
-                            _ => panic!(
+                            Zero(z) if z == F::ZERO => return Versor::from(v),
+                            _ => unreachable!(
                                 "Second term of geometric product producing an odd versor should be a trivector"
                             ),
                         };
@@ -930,44 +942,48 @@ where
                         let (bv, tv, sign_correction) = match (N, D) {
                             (4, 6) => (
                                 (&rhs as &dyn Any)
-                                    .downcast_ref::<Bivector>()
+                                    .downcast_ref::<Bivector<F>>()
                                     .expect("rhs should be a bivector")
                                     .components,
                                 (&self as &dyn Any)
-                                    .downcast_ref::<Trivector>()
+                                    .downcast_ref::<Trivector<F>>()
                                     .expect("lhs should be a trivector")
                                     .components,
-                                -1.0,
+                                -F::ONE,
                             ),
                             (6, 4) => (
                                 (&self as &dyn Any)
-                                    .downcast_ref::<Bivector>()
-                                    .expect("rhs should be a bivector")
+                                    .downcast_ref::<Bivector<F>>()
+                                    .expect("lhs should be a bivector")
                                     .components,
                                 (&rhs as &dyn Any)
-                                    .downcast_ref::<Trivector>()
-                                    .expect("lhs should be a trivector")
+                                    .downcast_ref::<Trivector<F>>()
+                                    .expect("rhs should be a trivector")
                                     .components,
-                                1.0,
+                                F::ONE,
                             ),
                             _ => {
-                                panic!("Invalid geometric product arguments: {:?}/{:?}", self, rhs)
+                                unreachable!(
+                                    "Invalid geometric product arguments: {:?}/{:?}",
+                                    self, rhs
+                                )
                             }
                         };
-                        let t1 = simd_swizzle!(bv, [0, 2, 1]);
-                        let t2 = simd_swizzle!(tv, [2, 3, 1]);
-                        let t3 = simd_swizzle!(bv, [1, 0, 2]);
-                        let t4 = simd_swizzle!(tv, [3, 1, 2]);
-                        let t5 = simd_swizzle!(bv, [3, 4, 5]);
-                        let t6 = simd_swizzle!(tv, [0, 0, 0]);
+                        let t1 = swizzle!(bv, [0, 2, 1]);
+                        let t2 = swizzle!(tv, [2, 3, 1]);
+                        let t3 = swizzle!(bv, [1, 0, 2]);
+                        let t4 = swizzle!(tv, [3, 1, 2]);
+                        let t5 = swizzle!(bv, [3, 4, 5]);
+                        let t6 = swizzle!(tv, [0, 0, 0]);
                         let r = t1 * t2 - t3 * t4 - t5 * t6;
-                        let triv_part = Trivector::from([0.0, r[0], r[1], r[2]]) * sign_correction;
+                        let triv_part =
+                            Trivector::from([F::ZERO, r[0], r[1], r[2]]) * sign_correction;
                         Versor::from(OddVersor::from((v, triv_part)))
                     }
-                    _ => panic!("Can't multiply {K}-vector and {G}-vector"),
+                    _ => unreachable!("Can't multiply {K}-vector and {G}-vector"),
                 }
             }
-            _ => panic!("Anything mod 2 should be either 0 or 1"),
+            _ => unreachable!("Anything mod 2 should be either 0 or 1"),
         }
     }
 }
@@ -978,7 +994,7 @@ mod tests {
 
     #[test]
     fn outer_vector_vector() {
-        let v1 = Vector::from([2.0, 3.0, 4.0, 1.0]);
+        let v1: Vector = Vector::from([2.0, 3.0, 4.0, 1.0]);
         let v2 = Vector::from([3.0, 2.0, 1.0, 4.0]);
         if let AnyKVector::Two(bv) = v1 ^ v2 {
             assert_eq!(bv, Bivector::from([-5.0, 10.0, -5.0, -5.0, -10.0, -15.0]));
@@ -989,7 +1005,7 @@ mod tests {
 
     #[test]
     fn outer_vector_bivector() {
-        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let v: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
         let bv = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
 
         if let AnyKVector::Three(tv) = v ^ bv {
@@ -1007,7 +1023,7 @@ mod tests {
 
     #[test]
     fn outer_bivector_bivector() {
-        let bv1 = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let bv1: Bivector = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
         let bv2 = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
 
         if let AnyKVector::Four(Pseudoscalar(n)) = bv1 ^ bv2 {
@@ -1025,7 +1041,7 @@ mod tests {
 
     #[test]
     fn outer_vector_trivector() {
-        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let v: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
         let tv = Trivector::from([4.0, 3.0, 2.0, 1.0]);
 
         if let AnyKVector::Four(Pseudoscalar(n)) = v ^ tv {
@@ -1043,7 +1059,7 @@ mod tests {
 
     #[test]
     fn outer_zeros() {
-        let bv = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let bv: Bivector = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
         let tv = Trivector::from([1.0, 2.0, 3.0, 4.0]);
 
         if let AnyKVector::Zero(n) = bv ^ tv {
@@ -1067,9 +1083,9 @@ mod tests {
 
     #[test]
     fn undual_inverse_of_dual() {
-        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
-        let bv = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
-        let tv = Trivector::from([1.0, 2.0, 3.0, 4.0]);
+        let v: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let bv: Bivector = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+        let tv: Trivector = Trivector::from([1.0, 2.0, 3.0, 4.0]);
 
         assert_eq!(v.dual().undual(), v.into());
         assert_eq!(v.undual().dual(), v.into());
@@ -1081,16 +1097,16 @@ mod tests {
 
     #[test]
     fn dual_vector_trivector() {
-        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let v: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
         assert_eq!(v.dual(), Trivector::from([4.0, 1.0, 2.0, 3.0]).into());
 
-        let tv = Trivector::from([1.0, 2.0, 3.0, 4.0]);
+        let tv: Trivector = Trivector::from([1.0, 2.0, 3.0, 4.0]);
         assert_eq!(tv.dual(), Vector::from([-2.0, -3.0, -4.0, -1.0]).into());
     }
 
     #[test]
     fn dual_bivector() {
-        let bv = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+        let bv: Bivector = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
         assert_eq!(
             bv.dual(),
             Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).into()
@@ -1099,7 +1115,7 @@ mod tests {
 
     #[test]
     fn regressive_zero() {
-        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let v: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
         let bv = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
 
         if let AnyKVector::Zero(n) = v & bv {
@@ -1111,7 +1127,7 @@ mod tests {
 
     #[test]
     fn regressive_bivector_trivector() {
-        let bv = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+        let bv: Bivector = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
         let tv = Trivector::from([1.0, 2.0, 3.0, 4.0]);
 
         if let AnyKVector::One(v) = tv & bv {
@@ -1129,7 +1145,7 @@ mod tests {
 
     #[test]
     fn regressive_trivector_trivector() {
-        let tv1 = Trivector::from([1.0, 2.0, 3.0, 4.0]);
+        let tv1: Trivector = Trivector::from([1.0, 2.0, 3.0, 4.0]);
         let tv2 = Trivector::from([4.0, 3.0, 2.0, 1.0]);
 
         if let AnyKVector::Two(bv) = tv1 & tv2 {
@@ -1141,21 +1157,21 @@ mod tests {
 
     #[test]
     fn inner_vector_vector() {
-        let v1 = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let v1: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
         let v2 = Vector::from([4.0, 3.0, 2.0, 1.0]);
         assert_eq!(v1 | v2, 16.0.into());
     }
 
     #[test]
     fn inner_bivector_bivector() {
-        let bv1 = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let bv1: Bivector = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
         let bv2 = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
         assert_eq!(bv1 | bv2, (-28.0).into());
     }
 
     #[test]
     fn inner_vector_bivector() {
-        let bv = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let bv: Bivector = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
         let v = Vector::from([4.0, 3.0, 2.0, 1.0]);
         let expected = AnyKVector::One(Vector::from([-1.0, 2.0, -1.0, 43.0]));
         assert_eq!(bv | v, expected);
@@ -1164,7 +1180,7 @@ mod tests {
 
     #[test]
     fn inner_bivector_trivector() {
-        let bv = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let bv: Bivector = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
         let tv = Trivector::from([4.0, 3.0, 2.0, 1.0]);
         let expected = AnyKVector::One(Vector::from([-12.0, -8.0, -4.0, 14.0]));
         assert_eq!(bv | tv, expected);
@@ -1182,7 +1198,7 @@ mod tests {
 
     #[test]
     fn geo_vector_vector() {
-        let v1 = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let v1: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
         let v2 = Vector::from([4.0, 3.0, 2.0, 1.0]);
         let expected = Versor::Even(Motor::from([16.0, -5.0, 10.0, -5.0, 15.0, 10.0, 5.0, 0.0]));
         assert_eq!(v1 * v2, expected);
@@ -1191,7 +1207,7 @@ mod tests {
 
     #[test]
     fn geo_vector_bivector() {
-        let v = Vector::from([2.0, 1.0, 2.0, 1.0]);
+        let v: Vector = Vector::from([2.0, 1.0, 2.0, 1.0]);
         let bv = Bivector::from([-5.0, 10.0, -5.0, 15.0, 10.0, 5.0]);
         let expected = Versor::from(OddVersor::from([
             25.0, 0.0, -25.0, -50.0, -10.0, -10.0, 10.0, 10.0,
@@ -1202,7 +1218,7 @@ mod tests {
 
     #[test]
     fn geo_vector_trivector() {
-        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let v: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
         let tv = Trivector::from([4.0, 3.0, 2.0, 1.0]);
         let expected = Versor::Even(Motor::from([0.0, 12.0, 8.0, 4.0, 4.0, -8.0, 4.0, 26.0]));
         assert_eq!(v * tv, expected);
@@ -1211,7 +1227,7 @@ mod tests {
 
     #[test]
     fn geo_bivector_bivector() {
-        let bv1 = Bivector::from([-5.0, 10.0, -5.0, 15.0, 10.0, 5.0]);
+        let bv1: Bivector = Bivector::from([-5.0, 10.0, -5.0, 15.0, 10.0, 5.0]);
         let bv2 = Bivector::from([12.0, 8.0, 4.0, 4.0, -8.0, 4.0]);
         let expected = Versor::Even(Motor::from([
             0.0, 80.0, -40.0, -160.0, -80.0, 160.0, -80.0, 80.0,
@@ -1222,7 +1238,7 @@ mod tests {
 
     #[test]
     fn geo_bivector_trivector() {
-        let bv = Bivector::from([-5.0, 10.0, -5.0, 15.0, 10.0, 5.0]);
+        let bv: Bivector = Bivector::from([-5.0, 10.0, -5.0, 15.0, 10.0, 5.0]);
         let tv = Trivector::from([4.0, 3.0, 2.0, 1.0]);
         let expected = Versor::from(OddVersor::from([
             20.0, -40.0, 20.0, 0.0, 0.0, -80.0, -30.0, 20.0,
@@ -1233,7 +1249,7 @@ mod tests {
 
     #[test]
     fn geo_trivector_trivector() {
-        let tv1 = Trivector::from([1.0, 2.0, 3.0, 4.0]);
+        let tv1: Trivector = Trivector::from([1.0, 2.0, 3.0, 4.0]);
         let tv2 = Trivector::from([4.0, 3.0, 2.0, 1.0]);
         let expected = Versor::Even(Motor::from([-4.0, 0.0, 0.0, 0.0, 5.0, 10.0, 15.0, 0.0]));
         assert_eq!(tv1 * tv2, expected);
@@ -1242,20 +1258,20 @@ mod tests {
 
     #[test]
     fn vector_times_inverse_1() {
-        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let v: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
         assert_eq!(v * v.inverse().unwrap(), Versor::KVec(1.0.into()));
     }
 
     #[test]
     fn trivector_times_inverse_1() {
-        let tv = Trivector::from([1.0, 2.0, 3.0, 4.0]);
+        let tv: Trivector = Trivector::from([1.0, 2.0, 3.0, 4.0]);
         assert_eq!(tv * tv.inverse().unwrap(), Versor::KVec(1.0.into()));
     }
 
     #[test]
     fn bivector_times_inverse_1() {
-        let bv1 = Bivector::from([-5.0, 10.0, -5.0, 15.0, 10.0, 5.0]);
-        let bv2 = Bivector::from([-5.0, 10.0, -5.0, 13.0, 10.0, 5.0]);
+        let bv1: Bivector = Bivector::from([-5.0, 10.0, -5.0, 15.0, 10.0, 5.0]);
+        let bv2: Bivector = Bivector::from([-5.0, 10.0, -5.0, 13.0, 10.0, 5.0]);
         assert_eq!(
             bv1.geo(bv1.inverse().unwrap()).snap(),
             Versor::KVec(1.0.into())
@@ -1266,15 +1282,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ideal_bivector_inverse() {
+        assert_eq!(e01.inverse(), None);
+        // Small enough that its square would be snapped to zero
+        let tiny = crate::util::EPSILON / 10.0;
+        let bv = Bivector::from([tiny, 0.0, tiny, 1.0, 0.0, 0.0]);
+        assert_eq!(bv.inverse(), None);
+        assert_eq!(bv.try_inverse(), Err(PgaError::NonInvertible));
+    }
+
     #[test]
     fn vector_normalized_mag_1() {
-        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let v: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
         assert!(float_eq(v.normalize().magnitude(), 1.0));
     }
 
     #[test]
     fn bivector_normalized_mag_1() {
-        let bv = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let bv: Bivector = Bivector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
         assert!(float_eq(bv.normalize().magnitude(), 1.0));
     }
 }
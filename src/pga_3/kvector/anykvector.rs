@@ -6,15 +6,15 @@ use std::ops::{BitAnd, BitOr, BitXor, Div, Mul, Neg};
 use std::simd::{LaneCount, SupportedLaneCount};
 
 #[derive(Copy, Clone, Debug)]
-pub enum AnyKVector {
-    Zero(Scalar),
-    One(Vector),
-    Two(Bivector),
-    Three(Trivector),
-    Four(Pseudoscalar),
+pub enum AnyKVector<F: Float = f32> {
+    Zero(F),
+    One(Vector<F>),
+    Two(Bivector<F>),
+    Three(Trivector<F>),
+    Four(Pseudoscalar<F>),
 }
 
-impl PartialEq for AnyKVector {
+impl<F: Float> PartialEq for AnyKVector<F> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Zero(f1), Zero(f2)) => float_eq(*f1, *f2),
@@ -27,7 +27,7 @@ impl PartialEq for AnyKVector {
     }
 }
 
-impl Neg for AnyKVector {
+impl<F: Float> Neg for AnyKVector<F> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -41,10 +41,10 @@ impl Neg for AnyKVector {
     }
 }
 
-impl Mul<Scalar> for AnyKVector {
+impl<F: Float> Mul<F> for AnyKVector<F> {
     type Output = Self;
 
-    fn mul(self, rhs: Scalar) -> Self::Output {
+    fn mul(self, rhs: F) -> Self::Output {
         match self {
             Zero(s) => Zero(s * rhs),
             One(v) => One(v * rhs),
@@ -55,22 +55,10 @@ impl Mul<Scalar> for AnyKVector {
     }
 }
 
-impl<T> Mul<T> for AnyKVector
-where
-    T: Multivector,
-    T: NonScalar,
-{
-    type Output = Versor;
-
-    fn mul(self, rhs: T) -> Self::Output {
-        self.geo(rhs)
-    }
-}
-
-impl Div<Scalar> for AnyKVector {
+impl<F: Float> Div<F> for AnyKVector<F> {
     type Output = Self;
 
-    fn div(self, rhs: Scalar) -> Self::Output {
+    fn div(self, rhs: F) -> Self::Output {
         match self {
             Zero(s) => Zero(s / rhs),
             One(v) => One(v / rhs),
@@ -81,8 +69,8 @@ impl Div<Scalar> for AnyKVector {
     }
 }
 
-impl<T: SingleGrade> BitXor<T> for AnyKVector {
-    type Output = AnyKVector;
+impl<F: Float, T: SingleGrade<Pga3<F>>> BitXor<T> for AnyKVector<F> {
+    type Output = AnyKVector<F>;
 
     #[inline]
     fn bitxor(self, rhs: T) -> Self::Output {
@@ -90,8 +78,8 @@ impl<T: SingleGrade> BitXor<T> for AnyKVector {
     }
 }
 
-impl<T: SingleGrade> BitAnd<T> for AnyKVector {
-    type Output = AnyKVector;
+impl<F: Float, T: SingleGrade<Pga3<F>>> BitAnd<T> for AnyKVector<F> {
+    type Output = AnyKVector<F>;
 
     #[inline]
     fn bitand(self, rhs: T) -> Self::Output {
@@ -99,49 +87,49 @@ impl<T: SingleGrade> BitAnd<T> for AnyKVector {
     }
 }
 
-impl<T: SingleGrade> BitOr<T> for AnyKVector {
-    type Output = AnyKVector;
+impl<F: Float, T: SingleGrade<Pga3<F>>> BitOr<T> for AnyKVector<F> {
+    type Output = AnyKVector<F>;
     #[inline]
     fn bitor(self, rhs: T) -> Self::Output {
         self.inner(rhs)
     }
 }
 
-impl<const K: u8, const N: usize> From<KVector<K, N>> for AnyKVector
+impl<F: Float, const K: u8, const N: usize> From<KVector<K, N, F>> for AnyKVector<F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
-    fn from(value: KVector<K, N>) -> AnyKVector {
-        if let Some(vector) = (&value as &dyn Any).downcast_ref::<Vector>() {
+    fn from(value: KVector<K, N, F>) -> AnyKVector<F> {
+        if let Some(vector) = (&value as &dyn Any).downcast_ref::<Vector<F>>() {
             return One(*vector);
         }
-        if let Some(bivector) = (&value as &dyn Any).downcast_ref::<Bivector>() {
+        if let Some(bivector) = (&value as &dyn Any).downcast_ref::<Bivector<F>>() {
             return Two(*bivector);
         }
-        if let Some(trivector) = (&value as &dyn Any).downcast_ref::<Trivector>() {
+        if let Some(trivector) = (&value as &dyn Any).downcast_ref::<Trivector<F>>() {
             return Three(*trivector);
         }
-        return Zero(0.0);
+        return Zero(F::ZERO);
     }
 }
 
-impl From<Scalar> for AnyKVector {
-    fn from(value: Scalar) -> Self {
+impl<F: Float> From<F> for AnyKVector<F> {
+    fn from(value: F) -> Self {
         Zero(value)
     }
 }
 
-impl From<Pseudoscalar> for AnyKVector {
-    fn from(value: Pseudoscalar) -> Self {
+impl<F: Float> From<Pseudoscalar<F>> for AnyKVector<F> {
+    fn from(value: Pseudoscalar<F>) -> Self {
         Four(value)
     }
 }
 
-impl Multivector for AnyKVector {
+impl<F: Float> Multivector<Pga3<F>> for AnyKVector<F> {
     #[inline]
     fn reverse(&self) -> Self {
         match self {
-            Zero(s) => s.reverse().into(),
+            Zero(s) => Multivector::<Pga3<F>>::reverse(s).into(),
             One(v) => v.reverse().into(),
             Two(bv) => bv.reverse().into(),
             Three(tv) => tv.reverse().into(),
@@ -152,7 +140,7 @@ impl Multivector for AnyKVector {
     #[inline]
     fn grade_involution(&self) -> Self {
         match self {
-            Zero(s) => s.grade_involution().into(),
+            Zero(s) => Multivector::<Pga3<F>>::grade_involution(s).into(),
             One(v) => v.grade_involution().into(),
             Two(bv) => bv.grade_involution().into(),
             Three(tv) => tv.grade_involution().into(),
@@ -161,9 +149,9 @@ impl Multivector for AnyKVector {
     }
 
     #[inline]
-    fn e(&self, basis: u8) -> f32 {
+    fn e(&self, basis: u8) -> F {
         match self {
-            Zero(s) => s.e(basis),
+            Zero(s) => Multivector::<Pga3<F>>::e(s, basis),
             One(v) => v.e(basis),
             Two(bv) => bv.e(basis),
             Three(tv) => tv.e(basis),
@@ -174,7 +162,7 @@ impl Multivector for AnyKVector {
     #[inline]
     fn grade(&self, g: u8) -> Self {
         match self {
-            Zero(s) => s.grade(g),
+            Zero(s) => Multivector::<Pga3<F>>::grade(s, g),
             One(v) => v.grade(g),
             Two(bv) => bv.grade(g),
             Three(tv) => tv.grade(g),
@@ -196,7 +184,7 @@ impl Multivector for AnyKVector {
     #[inline]
     fn zero(&self) -> bool {
         match self {
-            Zero(s) => s.zero(),
+            Zero(s) => Multivector::<Pga3<F>>::zero(s),
             One(v) => v.zero(),
             Two(bv) => bv.zero(),
             Three(tv) => tv.zero(),
@@ -207,7 +195,7 @@ impl Multivector for AnyKVector {
     #[inline]
     fn is_ideal(&self) -> bool {
         match self {
-            Zero(s) => s.is_ideal(),
+            Zero(s) => Multivector::<Pga3<F>>::is_ideal(s),
             One(v) => v.is_ideal(),
             Two(bv) => bv.is_ideal(),
             Three(tv) => tv.is_ideal(),
@@ -216,12 +204,10 @@ impl Multivector for AnyKVector {
     }
 
     #[inline]
-    fn dual(self) -> Versor {
+    fn dual(self) -> Versor<F> {
         match self {
-            Zero(s) => match s {
-                0.0 => Versor::KVec(Zero(0.0)),
-                s => Versor::KVec(Four(Pseudoscalar(s))),
-            },
+            Zero(s) if s == F::ZERO => Versor::KVec(Zero(F::ZERO)),
+            Zero(s) => Versor::KVec(Four(Pseudoscalar(s))),
             One(v) => v.dual(),
             Two(bv) => bv.dual(),
             Three(tv) => tv.dual(),
@@ -230,7 +216,7 @@ impl Multivector for AnyKVector {
     }
 
     #[inline]
-    fn undual(self) -> Versor {
+    fn undual(self) -> Versor<F> {
         match self {
             One(v) => v.undual(),
             Three(tv) => tv.undual(),
@@ -239,7 +225,7 @@ impl Multivector for AnyKVector {
     }
 
     #[inline]
-    fn geo<T: Multivector>(self, rhs: T) -> Versor {
+    fn geo<T: Multivector<Pga3<F>>>(self, rhs: T) -> Versor<F> {
         match self {
             Zero(s) => (rhs * s).into(),
             One(v) => v.geo(rhs),
@@ -261,14 +247,16 @@ impl Multivector for AnyKVector {
     }
 }
 
-impl SingleGrade for AnyKVector {
+impl<F: Float> SingleGrade<Pga3<F>> for AnyKVector<F> {
+    const GRADE: Option<u8> = None;
+
     #[inline]
-    fn outer<T: SingleGrade>(self, rhs: T) -> AnyKVector {
+    fn outer<T: SingleGrade<Pga3<F>>>(self, rhs: T) -> AnyKVector<F> {
         self.outer_self(rhs.into())
     }
 
     #[inline]
-    fn inner<T: SingleGrade>(self, rhs: T) -> AnyKVector {
+    fn inner<T: SingleGrade<Pga3<F>>>(self, rhs: T) -> AnyKVector<F> {
         match self {
             Zero(s) => (rhs * s).into(),
             One(v) => v.inner(rhs),
@@ -279,30 +267,26 @@ impl SingleGrade for AnyKVector {
     }
 
     #[inline]
-    fn assert<T: SingleGrade + 'static>(self) -> T {
+    fn try_assert<T: SingleGrade<Pga3<F>> + 'static>(self) -> Result<T, PgaError> {
         match self {
-            Zero(s) => s.assert::<T>(),
-            One(v) => v.assert::<T>(),
-            Two(bv) => bv.assert::<T>(),
-            Three(tv) => tv.assert::<T>(),
-            Four(ps) => ps.assert::<T>(),
+            Zero(s) => s.try_assert::<T>(),
+            One(v) => v.try_assert::<T>(),
+            Two(bv) => bv.try_assert::<T>(),
+            Three(tv) => tv.try_assert::<T>(),
+            Four(ps) => ps.try_assert::<T>(),
         }
     }
 
     #[inline]
-    fn scale(self, scale: Trivector) -> Self {
-        use AnyKVector::*;
+    fn is_blade(self) -> bool {
         match self {
-            Zero(s) => Zero(s.scale(scale)),
-            One(v) => One(v.scale(scale)),
-            Two(bv) => Two(bv.scale(scale)),
-            Three(tv) => Three(tv.scale(scale)),
-            Four(ps) => Four(ps.scale(scale)),
+            Two(bv) => bv.is_simple(),
+            _ => true,
         }
     }
 }
 
-impl AnyKVector {
+impl<F: Float> AnyKVector<F> {
     #[inline]
     pub fn outer_self(self, rhs: Self) -> Self {
         match self {
@@ -314,7 +298,7 @@ impl AnyKVector {
                 if let Zero(s) = rhs {
                     Four(ps * s)
                 } else {
-                    Zero(0.0)
+                    Zero(F::ZERO)
                 }
             }
         }
@@ -324,19 +308,19 @@ impl AnyKVector {
 #[macro_export]
 macro_rules! type_of {
     (Zero) => {
-        Scalar
+        F
     };
     (One) => {
-        Vector
+        Vector<F>
     };
     (Two) => {
-        Bivector
+        Bivector<F>
     };
     (Three) => {
-        Trivector
+        Trivector<F>
     };
     (Four) => {
-        Pseudoscalar
+        Pseudoscalar<F>
     };
 }
 
@@ -355,7 +339,7 @@ macro_rules! anykvec_sum {
         }
         match result {
             Some(r) => $variant(r),
-            None => AnyKVector::Zero(0.0),
+            None => AnyKVector::Zero(F::ZERO),
         }
     }};
 }
@@ -1,49 +1,150 @@
 use crate::pga_3::*;
-use std::simd::Simd;
+use crate::util::float_eq;
 
-pub type Bivector = KVector<2, 6>;
+pub type Bivector<F = f32> = KVector<2, 6, F>;
 
 pub const e12: Bivector = Bivector {
-    components: Simd::from_array([1.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
+    components: Lanes::from_array([1.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
 };
 pub const e31: Bivector = Bivector {
-    components: Simd::from_array([0.0, 1.0, 0.0, 0.0, 0.0, 0.0]),
+    components: Lanes::from_array([0.0, 1.0, 0.0, 0.0, 0.0, 0.0]),
 };
 pub const e23: Bivector = Bivector {
-    components: Simd::from_array([0.0, 0.0, 1.0, 0.0, 0.0, 0.0]),
+    components: Lanes::from_array([0.0, 0.0, 1.0, 0.0, 0.0, 0.0]),
 };
 pub const e01: Bivector = Bivector {
-    components: Simd::from_array([0.0, 0.0, 0.0, 1.0, 0.0, 0.0]),
+    components: Lanes::from_array([0.0, 0.0, 0.0, 1.0, 0.0, 0.0]),
 };
 pub const e02: Bivector = Bivector {
-    components: Simd::from_array([0.0, 0.0, 0.0, 0.0, 1.0, 0.0]),
+    components: Lanes::from_array([0.0, 0.0, 0.0, 0.0, 1.0, 0.0]),
 };
 pub const e03: Bivector = Bivector {
-    components: Simd::from_array([0.0, 0.0, 0.0, 0.0, 0.0, 1.0]),
+    components: Lanes::from_array([0.0, 0.0, 0.0, 0.0, 0.0, 1.0]),
 };
 
-impl From<(Trivector, Trivector)> for Bivector {
-    fn from(tvs: (Trivector, Trivector)) -> Self {
+impl<F: Float> From<(Trivector<F>, Trivector<F>)> for Bivector<F> {
+    fn from(tvs: (Trivector<F>, Trivector<F>)) -> Self {
         match tvs.1.is_ideal() {
             true => (tvs.0 + tvs.1) & tvs.0,
             false => tvs.0 & tvs.1,
         }
-        .assert::<Bivector>()
+        .assert::<Bivector<F>>()
     }
 }
 
-impl Bivector {
-    pub fn exp(&self) -> Motor {
-        use std::cmp::Ordering::*;
-        match self.eucl_norm().partial_cmp(&0.0) {
-            Some(Equal) => Motor::from((1.0, *self, Pseudoscalar(0.0))),
-            Some(Greater) => Motor::from((
-                self.magnitude().cos(),
-                self.normalize() * self.magnitude().sin(),
-                Pseudoscalar(0.0),
-            )),
-            _ => panic!("Magnitude of bivector should be non-negative and non-NaN"),
+// A line with unit direction `d` through a point `p` has Plücker coordinates `(d, m)` for the
+// moment `m = p x d`, and is `dx * e23 + dy * e31 + dz * e12 - mx * e01 - my * e02 - mz * e03`.
+// Directions and moments are passed around as ideal points.
+impl<F: Float> Bivector<F> {
+    /// The line through `point` along the ideal point `direction`, with the weight of `direction`
+    #[inline]
+    pub fn through(point: Trivector<F>, direction: Trivector<F>) -> Bivector<F> {
+        Bivector::from((point.normalize(), direction))
+    }
+
+    /// The line with Plücker coordinates `(direction, moment)`, which should be perpendicular
+    #[inline]
+    pub fn from_plucker(direction: Trivector<F>, moment: Trivector<F>) -> Bivector<F> {
+        let (d, m) = (direction, moment);
+        Bivector::from([-d[3], -d[2], -d[1], m[1], m[2], m[3]])
+    }
+
+    /// The line where two planes meet, running along the cross product of their normals. Parallel
+    /// planes meet in an ideal line.
+    #[inline]
+    pub fn from_planes(a: Vector<F>, b: Vector<F>) -> Bivector<F> {
+        (a ^ b).assert::<Bivector<F>>()
+    }
+
+    /// The direction the line runs along, as an ideal point
+    #[inline]
+    pub fn direction(&self) -> Trivector<F> {
+        Trivector::direction(self[2], self[1], self[0])
+    }
+
+    /// The moment of the line about the origin, `p x d` for any point `p` on it, as an ideal point
+    #[inline]
+    pub fn moment(&self) -> Trivector<F> {
+        Trivector::direction(-self[3], -self[4], -self[5])
+    }
+
+    /// The point on the line closest to the origin, `d x m / |d|^2`
+    #[inline]
+    pub fn closest_to_origin(&self) -> Trivector<F> {
+        Blade::<Trivector<F>>::e123.foot_on_line(*self)
+    }
+
+    /// Splits the bivector into a Euclidean line and an ideal line which commute with each
+    /// other and sum to the original bivector. For a bivector that is already a simple line,
+    /// the ideal part is zero; for one with no Euclidean part, all of it is the ideal part.
+    pub fn split(&self) -> (Bivector<F>, Bivector<F>) {
+        let eucl_sq = self[0].powi(2) + self[1].powi(2) + self[2].powi(2);
+        if eucl_sq == F::ZERO {
+            return (Bivector::from([F::ZERO; 6]), *self);
         }
+        let ideal = self.eucl_dual() * (self.pitch_product() / eucl_sq);
+        (*self - ideal, ideal)
+    }
+
+    /// Closed-form exponential of any bivector. A bivector `B` can be written as `u * L + v * L * I`
+    /// for a normalized line `L`, where the two terms commute, so
+    /// `exp(B) = cos(u) + sin(u) * L + v * cos(u) * L * I - v * sin(u) * I`.
+    pub fn exp(&self) -> Motor<F> {
+        let eucl_sq = self[0].powi(2) + self[1].powi(2) + self[2].powi(2);
+        if eucl_sq == F::ZERO {
+            return Motor::from((F::ONE, *self, Pseudoscalar(F::ZERO)));
+        }
+        let eucl_norm = eucl_sq.sqrt();
+        let (sin, cos) = eucl_norm.sin_cos();
+        let sinc = sin / eucl_norm;
+        let uv = self.pitch_product();
+        Motor::from((
+            cos,
+            *self * sinc + self.eucl_dual() * (uv / eucl_sq * (cos - sinc)),
+            Pseudoscalar(-uv * sinc),
+        ))
+    }
+
+    /// Whether the bivector is a single line (possibly ideal), ie whether `B ^ B = 0`. The
+    /// check is relative to the bivector's size so that joins of distant points still pass.
+    pub fn is_simple(&self) -> bool {
+        let norm_sq = self.coefficient_norm_sq();
+        norm_sq == F::ZERO || float_eq(self.pitch_product() / norm_sq, F::ZERO)
+    }
+
+    /// The commutator product `(ab - ba) / 2`, which for two bivectors is again a bivector. It
+    /// is how a rate of motion acts on a line: `a.commutator(b)` is the velocity of the line `b`
+    /// when moved along the rate `a`.
+    #[inline]
+    pub fn commutator(self, rhs: Bivector<F>) -> Bivector<F> {
+        let (a, b) = (self, rhs);
+        Bivector::from([
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+            a[0] * b[4] - a[1] * b[5] - a[4] * b[0] + a[5] * b[1],
+            a[2] * b[5] - a[0] * b[3] + a[3] * b[0] - a[5] * b[2],
+            a[1] * b[3] - a[2] * b[4] - a[3] * b[1] + a[4] * b[2],
+        ])
+    }
+
+    // Product of the rotational and translational magnitudes, -(B ^ B) / 2
+    #[inline]
+    pub(crate) fn pitch_product(&self) -> F {
+        -(self[0] * self[5] + self[1] * self[4] + self[2] * self[3])
+    }
+
+    // Sum of the squares of all six coefficients, which sets the scale of the tolerances of
+    // products that mix the Euclidean and ideal parts
+    #[inline]
+    pub(crate) fn coefficient_norm_sq(&self) -> F {
+        self.components.as_array().iter().map(|f| f.powi(2)).sum()
+    }
+
+    // Euclidean part of the bivector multiplied by the pseudoscalar
+    #[inline]
+    pub(crate) fn eucl_dual(&self) -> Bivector<F> {
+        Bivector::from([F::ZERO, F::ZERO, F::ZERO, -self[2], -self[1], -self[0]])
     }
 }
 
@@ -59,7 +160,7 @@ mod test {
         else {
             panic!("v * v should = bv");
         };
-        let expected = Motor::from([
+        let expected: Motor = Motor::from([
             0.5403022766,
             0.0,
             -0.3763172328,
@@ -71,4 +172,93 @@ mod test {
         ]);
         assert_eq!(bv.normalize().exp(), expected);
     }
+
+    #[test]
+    fn exp_ideal() {
+        let bv: Bivector = Bivector::from([0.0, 0.0, 0.0, 1.0, -2.0, 3.0]);
+        assert_eq!(bv.exp(), Motor::from((1.0, bv, Pseudoscalar(0.0))));
+    }
+
+    #[test]
+    fn split_commuting_simple_parts() {
+        let bv: Bivector = Bivector::from([0.1, 0.2, 0.3, 0.4, 0.5, 0.6]);
+        let (eucl, ideal) = bv.split();
+        assert_eq!(eucl + ideal, bv);
+        assert!((eucl ^ eucl).zero());
+        assert!((ideal ^ ideal).zero());
+        assert!(ideal.is_ideal());
+        assert_eq!(eucl * ideal, ideal * eucl);
+    }
+
+    #[test]
+    fn exp_screw_is_product_of_split_exps() {
+        let bv: Bivector = Bivector::from([0.1, 0.2, 0.3, 0.4, 0.5, 0.6]);
+        let (eucl, ideal) = bv.split();
+        assert_eq!(bv.exp(), eucl.exp() * ideal.exp());
+        assert!(float_eq(bv.exp().magnitude(), 1.0));
+    }
+
+    #[test]
+    fn exp_matches_screw_transformation() {
+        use std::f32::consts::PI;
+        let axis = Bivector::from((
+            Trivector::point(1.0, -2.0, 0.5),
+            Trivector::direction(0.0, 1.0, 1.0),
+        ))
+        .normalize();
+        let (angle, distance) = (PI / 3.0, 2.5);
+        let bv_i = (axis * e0123 * distance / 2.0).assert::<Bivector>();
+        let product = bv_i.exp() * axis.mul(-angle / 2.0).exp();
+        let generator = axis * (-angle / 2.0) + bv_i;
+        assert_eq!(generator.exp(), product.normalize());
+        assert_eq!(
+            Motor::screw(Blade::new(axis).unwrap(), angle, distance),
+            product.normalize()
+        );
+    }
+
+    #[test]
+    fn line_constructors() {
+        let p: Trivector = Trivector::point(1.0, 2.0, 0.0);
+        let d = Trivector::direction(0.0, 0.0, 1.0);
+        let line = Bivector::through(p * 3.0, d);
+        assert_eq!(line, Bivector::from((p, d)));
+        assert_eq!(line.direction(), d);
+        // m = (1, 2, 0) x (0, 0, 1)
+        assert_eq!(line.moment(), Trivector::direction(2.0, -1.0, 0.0));
+        assert_eq!(
+            Bivector::from_plucker(line.direction(), line.moment()),
+            line
+        );
+        let x_is_1 = Vector::from([1.0, 0.0, 0.0, 1.0]);
+        let y_is_2 = Vector::from([0.0, 1.0, 0.0, 2.0]);
+        assert_eq!(Bivector::from_planes(x_is_1, y_is_2), line);
+        assert!(Bivector::from_planes(e1, e1 * 2.0 + e0).is_ideal());
+    }
+
+    #[test]
+    fn closest_to_origin() {
+        let line: Bivector = Bivector::from_plucker(
+            Trivector::direction(0.0, 2.0, 0.0),
+            Trivector::direction(0.0, 0.0, -6.0),
+        );
+        // d x m / |d|^2 = (0, 2, 0) x (0, 0, -6) / 4
+        assert_eq!(line.closest_to_origin(), Trivector::point(-3.0, 0.0, 0.0));
+        let p = Trivector::point(1.0, 1.0, 1.0);
+        let through_p: Bivector = Bivector::through(p, Trivector::direction(1.0, -1.0, 0.0));
+        assert_eq!(through_p.closest_to_origin(), p);
+    }
+
+    #[test]
+    fn commutator() {
+        let a: Bivector = Bivector::from([1.0, -2.0, 0.5, 3.0, 1.5, -1.0]);
+        let b = Bivector::from([0.5, 2.0, -1.0, -2.0, 0.0, 4.0]);
+        let (ma, mb) = (Multivector16::from(a), Multivector16::from(b));
+        let expected = (ma.geo_full(mb) - mb.geo_full(ma)) / 2.0;
+        assert_eq!(Multivector16::from(a.commutator(b)), expected);
+        assert_eq!(a.commutator(b), -b.commutator(a));
+        // A rotation about a line leaves the line where it is
+        assert!(e12.commutator(e12 * 3.0).zero());
+        assert!(e12.commutator(e03).zero());
+    }
 }
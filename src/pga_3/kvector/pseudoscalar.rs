@@ -1,20 +1,20 @@
 use crate::pga_3::*;
 use crate::util::float_eq;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Sub};
 
 #[derive(Copy, Clone, Debug)]
-pub struct Pseudoscalar(pub f32);
+pub struct Pseudoscalar<F: Float = f32>(pub F);
 
 pub const e0123: Pseudoscalar = Pseudoscalar(1.0);
 
-impl PartialEq for Pseudoscalar {
+impl<F: Float> PartialEq for Pseudoscalar<F> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         float_eq(self.0, other.0)
     }
 }
 
-impl Neg for Pseudoscalar {
+impl<F: Float> Neg for Pseudoscalar<F> {
     type Output = Self;
 
     #[inline]
@@ -23,7 +23,7 @@ impl Neg for Pseudoscalar {
     }
 }
 
-impl Add for Pseudoscalar {
+impl<F: Float> Add for Pseudoscalar<F> {
     type Output = Self;
     #[inline]
     fn add(self, rhs: Self) -> Self::Output {
@@ -31,7 +31,7 @@ impl Add for Pseudoscalar {
     }
 }
 
-impl Sub for Pseudoscalar {
+impl<F: Float> Sub for Pseudoscalar<F> {
     type Output = Self;
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
@@ -39,31 +39,47 @@ impl Sub for Pseudoscalar {
     }
 }
 
-impl Mul<Scalar> for Pseudoscalar {
+impl<F: Float> Mul<F> for Pseudoscalar<F> {
     type Output = Self;
     #[inline]
-    fn mul(self, rhs: Scalar) -> Self::Output {
+    fn mul(self, rhs: F) -> Self::Output {
         Pseudoscalar(self.0 * rhs)
     }
 }
 
-impl<T: Multivector + NonScalar> Mul<T> for Pseudoscalar {
-    type Output = Versor;
+impl<F: Float> Div<F> for Pseudoscalar<F> {
+    type Output = Self;
     #[inline]
-    fn mul(self, rhs: T) -> Self::Output {
-        self.geo(rhs)
+    fn div(self, rhs: F) -> Self::Output {
+        Pseudoscalar(self.0 / rhs)
     }
 }
 
-impl Div<Scalar> for Pseudoscalar {
-    type Output = Self;
+impl<F: Float, T: SingleGrade<Pga3<F>>> BitXor<T> for Pseudoscalar<F> {
+    type Output = AnyKVector<F>;
     #[inline]
-    fn div(self, rhs: Scalar) -> Self::Output {
-        Pseudoscalar(self.0 / rhs)
+    fn bitxor(self, rhs: T) -> Self::Output {
+        self.outer(rhs)
+    }
+}
+
+impl<F: Float, T: SingleGrade<Pga3<F>>> BitAnd<T> for Pseudoscalar<F> {
+    type Output = AnyKVector<F>;
+    #[inline]
+    fn bitand(self, rhs: T) -> Self::Output {
+        self.regressive(rhs)
     }
 }
 
-impl Multivector for Pseudoscalar {
+impl<F: Float, T: SingleGrade<Pga3<F>>> BitOr<T> for Pseudoscalar<F> {
+    type Output = AnyKVector<F>;
+    #[inline]
+    fn bitor(self, rhs: T) -> Self::Output {
+        self.inner(rhs)
+    }
+}
+
+impl<F: Float> Multivector<Pga3<F>> for Pseudoscalar<F> {
     #[inline]
     fn reverse(&self) -> Self {
         *self
@@ -75,18 +91,18 @@ impl Multivector for Pseudoscalar {
     }
 
     #[inline]
-    fn e(&self, basis: u8) -> f32 {
+    fn e(&self, basis: u8) -> F {
         match basis {
             0b1111 => self.0,
-            _ => 0.0,
+            _ => F::ZERO,
         }
     }
 
     #[inline]
-    fn grade(&self, g: u8) -> AnyKVector {
+    fn grade(&self, g: u8) -> AnyKVector<F> {
         match g {
             4 => AnyKVector::Four(*self),
-            _ => AnyKVector::Zero(0.0),
+            _ => AnyKVector::Zero(F::ZERO),
         }
     }
 
@@ -97,7 +113,7 @@ impl Multivector for Pseudoscalar {
 
     #[inline]
     fn zero(&self) -> bool {
-        float_eq(self.0, 0.0)
+        float_eq(self.0, F::ZERO)
     }
 
     #[inline]
@@ -106,68 +122,62 @@ impl Multivector for Pseudoscalar {
     }
 
     #[inline]
-    fn dual(self) -> Versor {
+    fn dual(self) -> Versor<F> {
         Versor::KVec(AnyKVector::Zero(self.0))
     }
 
     #[inline]
-    fn undual(self) -> Versor {
+    fn undual(self) -> Versor<F> {
         self.dual()
     }
 
     #[inline]
-    fn geo<T: Multivector>(self, rhs: T) -> Versor {
+    fn geo<T: Multivector<Pga3<F>>>(self, rhs: T) -> Versor<F> {
         use Versor::*;
         match rhs.into() {
             KVec(kv) => self.inner(kv).into(),
             Even(m) => {
                 let ps = Pseudoscalar(m[0] * self.0);
-                let bv = Bivector::from([0.0, 0.0, 0.0, -m[3], -m[2], -m[1]]) * self.0;
-                Even(Motor::from((0.0, bv, ps)))
+                let bv = Bivector::from([F::ZERO, F::ZERO, F::ZERO, -m[3], -m[2], -m[1]]) * self.0;
+                Even(Motor::from((F::ZERO, bv, ps)))
             }
             Odd(ov) => {
-                let v = Vector::from([-ov[4], 0.0, 0.0, 0.0]);
-                let tv = Trivector::from([0.0, ov[0], ov[1], ov[2]]);
+                let v = Vector::from([F::ZERO, F::ZERO, F::ZERO, -ov[4]]) * self.0;
+                let tv = Trivector::from([F::ZERO, -ov[0], -ov[1], -ov[2]]) * self.0;
                 Odd(OddVersor::from((v, tv)))
             }
+            Mixed(mv) => Multivector16::from(self).geo(mv),
         }
     }
 }
 
-impl SingleGrade for Pseudoscalar {
+impl<F: Float> SingleGrade<Pga3<F>> for Pseudoscalar<F> {
+    const GRADE: Option<u8> = Some(4);
+
     #[inline]
-    fn outer<T: SingleGrade>(self, rhs: T) -> AnyKVector {
+    fn outer<T: SingleGrade<Pga3<F>>>(self, rhs: T) -> AnyKVector<F> {
         for g in 1..=4 {
             if !rhs.grade(g).zero() {
-                return 0.0.into();
+                return F::ZERO.into();
             }
         }
         (self * rhs.e(0b0000)).into()
     }
 
     #[inline]
-    fn inner<T: SingleGrade>(self, rhs: T) -> AnyKVector {
+    fn inner<T: SingleGrade<Pga3<F>>>(self, rhs: T) -> AnyKVector<F> {
         use AnyKVector::*;
         match rhs.into() {
             Zero(s) => (self * s).into(),
             One(v) => -v.inner(self),
             Two(bv) => bv.inner(self),
             Three(tv) => -tv.inner(self),
-            Four(_) => 0.0.into(),
+            Four(_) => F::ZERO.into(),
         }
     }
 
     #[inline]
-    fn assert<T: SingleGrade + 'static>(self) -> T {
-        use std::any::Any;
-        let Some(res) = (&self as &dyn Any).downcast_ref::<T>() else {
-            panic!("Single-grade assert failed");
-        };
-        *res
-    }
-
-    #[inline]
-    fn scale(self, _: Trivector) -> Self {
-        panic!("Cannot scale pseudoscalar");
+    fn try_assert<T: SingleGrade<Pga3<F>> + 'static>(self) -> Result<T, PgaError> {
+        downcast_grade(&self, 4)
     }
 }
@@ -1,75 +1,54 @@
 use crate::pga_3::*;
-use crate::util::float_eq;
+use crate::util::{Float, float_eq};
 use std::simd::{LaneCount, SupportedLaneCount};
 
-pub type Scalar = f32;
-
-impl<const K: u8, const N: usize> Mul<KVector<K, N>> for Scalar
-where
-    LaneCount<N>: SupportedLaneCount,
-{
-    type Output = KVector<K, N>;
-    #[inline]
-    fn mul(self, other: KVector<K, N>) -> Self::Output {
-        other * self
-    }
-}
-
-impl Mul<Pseudoscalar> for Scalar {
-    type Output = Pseudoscalar;
-    #[inline]
-    fn mul(self, other: Pseudoscalar) -> Self::Output {
-        other * self
-    }
-}
-
-impl Mul<AnyKVector> for Scalar {
-    type Output = AnyKVector;
-    #[inline]
-    fn mul(self, other: AnyKVector) -> Self::Output {
-        other * self
-    }
-}
-
-impl Mul<Motor> for Scalar {
-    type Output = Motor;
-    #[inline]
-    fn mul(self, other: Motor) -> Self::Output {
-        other * self
-    }
-}
+pub type Scalar<F = f32> = F;
+
+// A generic `impl<F: Float> Mul<Motor<F>> for F` would break the orphan rule, so scalars on the
+// left are implemented for each float type
+macro_rules! scalar_mul {
+    ($($f:ty),*) => {$(
+        impl<const K: u8, const N: usize> Mul<KVector<K, N, $f>> for $f
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = KVector<K, N, $f>;
+            #[inline]
+            fn mul(self, other: KVector<K, N, $f>) -> Self::Output {
+                other * self
+            }
+        }
 
-impl Mul<OddVersor> for Scalar {
-    type Output = OddVersor;
-    #[inline]
-    fn mul(self, other: OddVersor) -> Self::Output {
-        other * self
-    }
+        scalar_mul!($f; Pseudoscalar, AnyKVector, Motor, OddVersor, Versor);
+    )*};
+    ($f:ty; $($t:ident),*) => {$(
+        impl Mul<$t<$f>> for $f {
+            type Output = $t<$f>;
+            #[inline]
+            fn mul(self, other: $t<$f>) -> Self::Output {
+                other * self
+            }
+        }
+    )*};
 }
 
-impl Mul<Versor> for Scalar {
-    type Output = Versor;
-    #[inline]
-    fn mul(self, other: Versor) -> Self::Output {
-        other * self
-    }
-}
+scalar_mul!(f32, f64);
 
-impl Multivector for Scalar {
+impl<F: Float> Multivector<Pga3<F>> for F {
     #[inline]
-    fn e(&self, basis: u8) -> f32 {
+    fn e(&self, basis: u8) -> F {
         match basis {
             0b0000 => *self,
-            _ => 0.0,
+            _ => F::ZERO,
         }
     }
 
     #[inline]
-    fn grade(&self, g: u8) -> AnyKVector {
+    fn grade(&self, g: u8) -> AnyKVector<F> {
         if g == 0 {
             AnyKVector::Zero(*self)
         } else {
-            AnyKVector::Zero(0.0)
+            AnyKVector::Zero(F::ZERO)
         }
     }
 
@@ -80,7 +59,7 @@ impl Multivector for Scalar {
 
     #[inline]
     fn zero(&self) -> bool {
-        float_eq(*self, 0.0)
+        float_eq(*self, F::ZERO)
     }
 
     #[inline]
@@ -99,43 +78,36 @@ impl Multivector for Scalar {
     }
 
     #[inline]
-    fn dual(self) -> Versor {
+    fn dual(self) -> Versor<F> {
         Versor::KVec(AnyKVector::Four(Pseudoscalar(self)))
     }
 
     #[inline]
-    fn undual(self) -> Versor {
-        self.dual()
+    fn undual(self) -> Versor<F> {
+        Multivector::<Pga3<F>>::dual(self)
     }
 
     #[inline]
-    fn geo<T: Multivector>(self, rhs: T) -> Versor {
+    fn geo<T: Multivector<Pga3<F>>>(self, rhs: T) -> Versor<F> {
         (rhs * self).into()
     }
 }
 
-impl SingleGrade for Scalar {
-    #[inline]
-    fn outer<T: SingleGrade>(self, rhs: T) -> AnyKVector {
-        (rhs * self).into()
-    }
+impl<F: Float> SingleGrade<Pga3<F>> for F {
+    const GRADE: Option<u8> = Some(0);
 
     #[inline]
-    fn inner<T: SingleGrade>(self, rhs: T) -> AnyKVector {
+    fn outer<T: SingleGrade<Pga3<F>>>(self, rhs: T) -> AnyKVector<F> {
         (rhs * self).into()
     }
 
     #[inline]
-    fn assert<T: SingleGrade + 'static>(self) -> T {
-        use std::any::Any;
-        let Some(res) = (&self as &dyn Any).downcast_ref::<T>() else {
-            panic!("Single-grade assert failed");
-        };
-        *res
+    fn inner<T: SingleGrade<Pga3<F>>>(self, rhs: T) -> AnyKVector<F> {
+        (rhs * self).into()
     }
 
     #[inline]
-    fn scale(self, _: Trivector) -> Self {
-        panic!("Cannot scale scalar");
+    fn try_assert<T: SingleGrade<Pga3<F>> + 'static>(self) -> Result<T, PgaError> {
+        downcast_grade(&self, 0)
     }
 }
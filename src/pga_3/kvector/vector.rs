@@ -1,22 +1,15 @@
 use super::*;
-use std::simd::Simd;
 
-pub type Vector = KVector<1, 4>;
+pub type Vector<F = f32> = KVector<1, 4, F>;
 pub const e1: Vector = Vector {
-    components: Simd::from_array([1.0, 0.0, 0.0, 0.0]),
+    components: Lanes::from_array([1.0, 0.0, 0.0, 0.0]),
 };
 pub const e2: Vector = Vector {
-    components: Simd::from_array([0.0, 1.0, 0.0, 0.0]),
+    components: Lanes::from_array([0.0, 1.0, 0.0, 0.0]),
 };
 pub const e3: Vector = Vector {
-    components: Simd::from_array([0.0, 0.0, 1.0, 0.0]),
+    components: Lanes::from_array([0.0, 0.0, 1.0, 0.0]),
 };
 pub const e0: Vector = Vector {
-    components: Simd::from_array([0.0, 0.0, 0.0, 1.0]),
+    components: Lanes::from_array([0.0, 0.0, 0.0, 1.0]),
 };
-
-impl Vector {
-    pub fn scale_slope(self, scale: Trivector) -> Self {
-        Vector::from(self.components * scale.undual().assert::<Vector>().components)
-    }
-}
@@ -0,0 +1,682 @@
+use crate::pga_3::*;
+use crate::util::{Float, Lanes, float_eq, swizzle};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Neg, Sub};
+use std::slice::SliceIndex;
+
+// Basis: 1, e1, e2, e3, e0, e12, e31, e23, e01, e02, e03, e123, e032, e013, e021, e0123
+#[derive(Copy, Clone, Debug)]
+pub struct Multivector16<F: Float = f32> {
+    components: Lanes<F, 16>,
+}
+
+pub(crate) const GRADES: [u8; 16] = [0, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 4];
+
+// Multiplying the basis blade in lane i by the basis blade in lane GEO_INDICES[i][k]
+// gives the basis blade in lane k, scaled by GEO_SIGNS[i][k]
+pub(crate) const GEO_INDICES: [[usize; 16]; 16] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [1, 0, 5, 6, 8, 2, 3, 11, 4, 14, 13, 7, 15, 10, 9, 12],
+    [2, 5, 0, 7, 9, 1, 11, 3, 14, 4, 12, 6, 10, 15, 8, 13],
+    [3, 6, 7, 0, 10, 11, 1, 2, 13, 12, 4, 5, 9, 8, 15, 14],
+    [4, 8, 9, 10, 0, 14, 13, 12, 1, 2, 3, 15, 7, 6, 5, 11],
+    [5, 2, 1, 11, 14, 0, 7, 6, 9, 8, 15, 3, 13, 12, 4, 10],
+    [6, 3, 11, 1, 13, 7, 0, 5, 10, 15, 8, 2, 14, 4, 12, 9],
+    [7, 11, 3, 2, 12, 6, 5, 0, 15, 10, 9, 1, 4, 14, 13, 8],
+    [8, 4, 14, 13, 1, 9, 10, 15, 0, 5, 6, 12, 11, 3, 2, 7],
+    [9, 14, 4, 12, 2, 8, 15, 10, 5, 0, 7, 13, 3, 11, 1, 6],
+    [10, 13, 12, 4, 3, 15, 8, 9, 6, 7, 0, 14, 2, 1, 11, 5],
+    [11, 7, 6, 5, 15, 3, 2, 1, 12, 13, 14, 0, 8, 9, 10, 4],
+    [12, 15, 10, 9, 7, 13, 14, 4, 11, 3, 2, 8, 0, 5, 6, 1],
+    [13, 10, 15, 8, 6, 12, 4, 14, 3, 11, 1, 9, 5, 0, 7, 2],
+    [14, 9, 8, 15, 5, 4, 12, 13, 2, 1, 11, 10, 6, 7, 0, 3],
+    [15, 12, 13, 14, 11, 10, 9, 8, 7, 6, 5, 4, 1, 2, 3, 0],
+];
+
+#[rustfmt::skip]
+pub(crate) const GEO_SIGNS: [[f32; 16]; 16] = [
+    [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+    [1.0, 1.0, 1.0, -1.0, -1.0, 1.0, -1.0, 1.0, -1.0, 1.0, -1.0, 1.0, 1.0, -1.0, 1.0, 1.0],
+    [1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, -1.0, 1.0],
+    [1.0, 1.0, -1.0, 1.0, -1.0, 1.0, 1.0, -1.0, 1.0, -1.0, -1.0, 1.0, -1.0, 1.0, 1.0, 1.0],
+    [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 0.0, -1.0, -1.0, -1.0, 1.0],
+    [-1.0, 1.0, -1.0, -1.0, 1.0, 1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0],
+    [-1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0, 1.0],
+    [-1.0, -1.0, 1.0, -1.0, 1.0, -1.0, 1.0, 1.0, -1.0, 1.0, -1.0, 1.0, -1.0, 1.0, -1.0, 1.0],
+    [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, -1.0, 0.0, -1.0, 1.0, -1.0, 1.0],
+    [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0, 1.0, 1.0, 0.0, -1.0, -1.0, 1.0, 1.0],
+    [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, -1.0, 1.0, 0.0, 1.0, -1.0, -1.0, 1.0],
+    [-1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, -1.0],
+    [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, -1.0, 1.0, 0.0, 1.0, 1.0, -1.0, -1.0],
+    [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, -1.0, 0.0, -1.0, 1.0, 1.0, -1.0],
+    [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0, 1.0, 1.0, 0.0, 1.0, -1.0, 1.0, -1.0],
+    [0.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, -1.0, -1.0, -1.0, 0.0, -1.0, -1.0, -1.0, 1.0],
+];
+
+// The outer and inner products keep only the terms of the geometric product whose grade is
+// the sum or the absolute difference of the grades of their factors, respectively
+pub(crate) const OUTER_SIGNS: [[f32; 16]; 16] = grade_filtered_signs(true);
+const INNER_SIGNS: [[f32; 16]; 16] = grade_filtered_signs(false);
+
+pub(crate) const DUAL_INDICES: [usize; 16] = [15, 12, 13, 14, 11, 10, 9, 8, 7, 6, 5, 4, 1, 2, 3, 0];
+#[rustfmt::skip]
+pub(crate) const DUAL_SIGNS: [f32; 16] = [
+    1.0, -1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
+];
+#[rustfmt::skip]
+pub(crate) const UNDUAL_SIGNS: [f32; 16] = [
+    1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0, 1.0,
+];
+#[rustfmt::skip]
+pub(crate) const REVERSE_SIGNS: [f32; 16] = [
+    1.0, 1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, 1.0,
+];
+#[rustfmt::skip]
+const INVOLUTION_SIGNS: [f32; 16] = [
+    1.0, -1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0, 1.0,
+];
+
+const fn grade_filtered_signs(outer: bool) -> [[f32; 16]; 16] {
+    let mut signs = GEO_SIGNS;
+    let mut i = 0;
+    while i < 16 {
+        let mut k = 0;
+        while k < 16 {
+            let (gi, gj, gk) = (GRADES[i], GRADES[GEO_INDICES[i][k]], GRADES[k]);
+            let keep = if outer {
+                gk == gi + gj
+            } else {
+                gk == gi.abs_diff(gj)
+            };
+            if !keep {
+                signs[i][k] = 0.0;
+            }
+            k += 1;
+        }
+        i += 1;
+    }
+    signs
+}
+
+// Sums, over every lane of the left-hand side, that coefficient times the correspondingly
+// permuted and sign-corrected right-hand side
+macro_rules! cayley_product {
+    ($lhs:expr, $rhs:expr, $signs:expr) => {
+        cayley_product!($lhs, $rhs, $signs; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)
+    };
+    ($lhs:expr, $rhs:expr, $signs:expr; $($i:literal),*) => {{
+        let mut result = Lanes::splat(F::ZERO);
+        $(
+            result += Lanes::splat($lhs[$i])
+                * Lanes::from_array($signs[$i].map(F::from))
+                * swizzle!($rhs, GEO_INDICES[$i]);
+        )*
+        result
+    }};
+}
+
+impl<F: Float> From<[F; 16]> for Multivector16<F> {
+    #[inline]
+    fn from(cs: [F; 16]) -> Self {
+        Multivector16 {
+            components: Lanes::from(cs),
+        }
+    }
+}
+
+impl<F: Float> From<Lanes<F, 16>> for Multivector16<F> {
+    #[inline]
+    fn from(cs: Lanes<F, 16>) -> Self {
+        Multivector16 { components: cs }
+    }
+}
+
+impl<F: Float> From<F> for Multivector16<F> {
+    #[inline]
+    fn from(s: F) -> Self {
+        let mut res = Self::from([F::ZERO; 16]);
+        res[0] = s;
+        res
+    }
+}
+
+impl<F: Float> From<Pseudoscalar<F>> for Multivector16<F> {
+    #[inline]
+    fn from(ps: Pseudoscalar<F>) -> Self {
+        let mut res = Self::from([F::ZERO; 16]);
+        res[15] = ps.0;
+        res
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> From<KVector<K, N, F>> for Multivector16<F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(kv: KVector<K, N, F>) -> Self {
+        AnyKVector::from(kv).into()
+    }
+}
+
+impl<F: Float> From<AnyKVector<F>> for Multivector16<F> {
+    #[inline]
+    fn from(kv: AnyKVector<F>) -> Self {
+        use AnyKVector::*;
+        let mut res = Self::from([F::ZERO; 16]);
+        match kv {
+            Zero(s) => res[0] = s,
+            One(v) => res[1..5].copy_from_slice(&v[0..4]),
+            Two(bv) => res[5..11].copy_from_slice(&bv[0..6]),
+            Three(tv) => res[11..15].copy_from_slice(&tv[0..4]),
+            Four(ps) => res[15] = ps.0,
+        }
+        res
+    }
+}
+
+impl<F: Float> From<Motor<F>> for Multivector16<F> {
+    #[inline]
+    fn from(m: Motor<F>) -> Self {
+        Self::from([
+            m[0],
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            m[1],
+            m[2],
+            m[3],
+            m[4],
+            m[5],
+            m[6],
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            m[7],
+        ])
+    }
+}
+
+impl<F: Float> From<OddVersor<F>> for Multivector16<F> {
+    #[inline]
+    fn from(ov: OddVersor<F>) -> Self {
+        Self::from([
+            F::ZERO,
+            ov[0],
+            ov[1],
+            ov[2],
+            ov[3],
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            ov[4],
+            ov[5],
+            ov[6],
+            ov[7],
+            F::ZERO,
+        ])
+    }
+}
+
+impl<F: Float> From<Versor<F>> for Multivector16<F> {
+    #[inline]
+    fn from(v: Versor<F>) -> Self {
+        use Versor::*;
+        match v {
+            Even(m) => m.into(),
+            Odd(ov) => ov.into(),
+            KVec(kv) => kv.into(),
+            Mixed(mv) => mv,
+        }
+    }
+}
+
+impl<F: Float> TryFrom<Multivector16<F>> for Motor<F> {
+    type Error = Multivector16<F>;
+
+    fn try_from(mv: Multivector16<F>) -> Result<Self, Self::Error> {
+        if !(mv.grade_is_zero(1) && mv.grade_is_zero(3)) {
+            return Err(mv);
+        }
+        Ok(Motor::from([
+            mv[0], mv[5], mv[6], mv[7], mv[8], mv[9], mv[10], mv[15],
+        ]))
+    }
+}
+
+impl<F: Float> TryFrom<Multivector16<F>> for OddVersor<F> {
+    type Error = Multivector16<F>;
+
+    fn try_from(mv: Multivector16<F>) -> Result<Self, Self::Error> {
+        if !(mv.grade_is_zero(0) && mv.grade_is_zero(2) && mv.grade_is_zero(4)) {
+            return Err(mv);
+        }
+        Ok(OddVersor::from([
+            mv[1], mv[2], mv[3], mv[4], mv[11], mv[12], mv[13], mv[14],
+        ]))
+    }
+}
+
+impl<F: Float> TryFrom<Multivector16<F>> for AnyKVector<F> {
+    type Error = Multivector16<F>;
+
+    fn try_from(mv: Multivector16<F>) -> Result<Self, Self::Error> {
+        let mut grades = (0..=4).filter(|g| !mv.grade_is_zero(*g));
+        match (grades.next(), grades.next()) {
+            (None, _) => Ok(AnyKVector::Zero(F::ZERO)),
+            (Some(g), None) => Ok(mv.grade(g)),
+            _ => Err(mv),
+        }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> TryFrom<Multivector16<F>> for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Error = Multivector16<F>;
+
+    fn try_from(mv: Multivector16<F>) -> Result<Self, Self::Error> {
+        if (0..=4).any(|g| g != K && !mv.grade_is_zero(g)) {
+            return Err(mv);
+        }
+        Ok(mv.grade(K).assert::<Self>())
+    }
+}
+
+// Generic over the float type, this would break the orphan rule
+macro_rules! scalar_try_from {
+    ($($f:ty),*) => {$(
+        impl TryFrom<Multivector16<$f>> for $f {
+            type Error = Multivector16<$f>;
+
+            fn try_from(mv: Multivector16<$f>) -> Result<Self, Self::Error> {
+                if (1..=3).any(|g| !mv.grade_is_zero(g)) {
+                    return Err(mv);
+                }
+                Ok(mv[0])
+            }
+        }
+    )*};
+}
+
+scalar_try_from!(f32, f64);
+
+impl<F: Float> TryFrom<Multivector16<F>> for Pseudoscalar<F> {
+    type Error = Multivector16<F>;
+
+    fn try_from(mv: Multivector16<F>) -> Result<Self, Self::Error> {
+        if (0..4).any(|g| !mv.grade_is_zero(g)) {
+            return Err(mv);
+        }
+        Ok(Pseudoscalar(mv[15]))
+    }
+}
+
+impl<F: Float, Idx: SliceIndex<[F]>> Index<Idx> for Multivector16<F> {
+    type Output = Idx::Output;
+    fn index(&self, index: Idx) -> &Self::Output {
+        &self.components[index]
+    }
+}
+
+impl<F: Float, Idx: SliceIndex<[F]>> IndexMut<Idx> for Multivector16<F> {
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        &mut self.components[index]
+    }
+}
+
+impl<F: Float> PartialEq for Multivector16<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.components
+            .as_array()
+            .iter()
+            .enumerate()
+            .fold(true, |acc, (i, e)| acc && float_eq(*e, other[i]))
+    }
+}
+
+impl<F: Float> Neg for Multivector16<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Multivector16 {
+            components: -self.components,
+        }
+    }
+}
+
+impl<F: Float, T: Multivector<Pga3<F>>> Add<T> for Multivector16<F> {
+    type Output = Self;
+    fn add(self, rhs: T) -> Self::Output {
+        Multivector16 {
+            components: self.components + Multivector16::from(rhs.into()).components,
+        }
+    }
+}
+
+impl<F: Float, T: Multivector<Pga3<F>>> Sub<T> for Multivector16<F> {
+    type Output = Self;
+    fn sub(self, rhs: T) -> Self::Output {
+        Multivector16 {
+            components: self.components - Multivector16::from(rhs.into()).components,
+        }
+    }
+}
+
+impl<F: Float> Mul<F> for Multivector16<F> {
+    type Output = Self;
+    fn mul(self, rhs: F) -> Self::Output {
+        Multivector16 {
+            components: self.components * Lanes::splat(rhs),
+        }
+    }
+}
+
+impl<F: Float, const K: u8, const N: usize> Mul<KVector<K, N, F>> for Multivector16<F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Multivector16<F>;
+    fn mul(self, rhs: KVector<K, N, F>) -> Self::Output {
+        self.geo_full(Multivector16::from(rhs))
+    }
+}
+
+// Spelled out per type, as `Mul<T: NonScalar>` would overlap `Mul<F>`
+macro_rules! mul_via_geo_full {
+    ($($rhs:ty),*) => {$(
+        impl<F: Float> Mul<$rhs> for Multivector16<F> {
+            type Output = Multivector16<F>;
+            fn mul(self, rhs: $rhs) -> Self::Output {
+                self.geo_full(Multivector16::from(rhs))
+            }
+        }
+    )*};
+}
+
+mul_via_geo_full!(
+    Pseudoscalar<F>,
+    AnyKVector<F>,
+    Motor<F>,
+    OddVersor<F>,
+    Versor<F>,
+    Multivector16<F>
+);
+
+impl<F: Float> Div<F> for Multivector16<F> {
+    type Output = Self;
+    fn div(self, rhs: F) -> Self::Output {
+        Multivector16 {
+            components: self.components / Lanes::splat(rhs),
+        }
+    }
+}
+
+impl<F: Float, T: Multivector<Pga3<F>>> BitXor<T> for Multivector16<F> {
+    type Output = Multivector16<F>;
+    #[inline]
+    fn bitxor(self, rhs: T) -> Self::Output {
+        self.outer_full(Multivector16::from(rhs.into()))
+    }
+}
+
+impl<F: Float, T: Multivector<Pga3<F>>> BitAnd<T> for Multivector16<F> {
+    type Output = Multivector16<F>;
+    #[inline]
+    fn bitand(self, rhs: T) -> Self::Output {
+        self.regressive_full(Multivector16::from(rhs.into()))
+    }
+}
+
+impl<F: Float, T: Multivector<Pga3<F>>> BitOr<T> for Multivector16<F> {
+    type Output = Multivector16<F>;
+    #[inline]
+    fn bitor(self, rhs: T) -> Self::Output {
+        self.inner_full(Multivector16::from(rhs.into()))
+    }
+}
+
+impl<F: Float> Multivector<Pga3<F>> for Multivector16<F> {
+    #[inline]
+    fn e(&self, basis: u8) -> F {
+        match basis {
+            0b0000 => self[0],
+            0b0100 => self[1],
+            0b0010 => self[2],
+            0b0001 => self[3],
+            0b1000 => self[4],
+            0b0110 => self[5],
+            0b0101 => self[6],
+            0b0011 => self[7],
+            0b1100 => self[8],
+            0b1010 => self[9],
+            0b1001 => self[10],
+            0b0111 => self[11],
+            0b1011 => self[12],
+            0b1101 => self[13],
+            0b1110 => self[14],
+            0b1111 => self[15],
+            _ => F::ZERO,
+        }
+    }
+
+    #[inline]
+    fn grade(&self, g: u8) -> AnyKVector<F> {
+        match g {
+            0 => self[0].into(),
+            1 => Vector::from(self.components.extract::<1, 4>()).into(),
+            2 => Bivector::from(self.components.extract::<5, 6>()).into(),
+            3 => Trivector::from(self.components.extract::<11, 4>()).into(),
+            4 => Pseudoscalar(self[15]).into(),
+            _ => F::ZERO.into(),
+        }
+    }
+
+    #[inline]
+    fn highest_grade(&self) -> u8 {
+        (0..=4).rev().find(|g| !self.grade_is_zero(*g)).unwrap_or(0)
+    }
+
+    #[inline]
+    fn reverse(&self) -> Self {
+        Self::from(self.components * Lanes::from_array(REVERSE_SIGNS.map(F::from)))
+    }
+
+    #[inline]
+    fn grade_involution(&self) -> Self {
+        Self::from(self.components * Lanes::from_array(INVOLUTION_SIGNS.map(F::from)))
+    }
+
+    #[inline]
+    fn zero(&self) -> bool {
+        self.components == Lanes::splat(F::ZERO)
+    }
+
+    #[inline]
+    fn is_ideal(&self) -> bool {
+        [0, 1, 2, 3, 5, 6, 7, 11]
+            .iter()
+            .all(|i| float_eq(self[*i], F::ZERO))
+    }
+
+    #[inline]
+    fn dual(self) -> Versor<F> {
+        Versor::from(self.dual_full())
+    }
+
+    #[inline]
+    fn undual(self) -> Versor<F> {
+        Versor::from(self.undual_full())
+    }
+
+    #[inline]
+    fn geo<T: Multivector<Pga3<F>>>(self, rhs: T) -> Versor<F> {
+        Versor::from(self.geo_full(Multivector16::from(rhs.into())))
+    }
+}
+
+impl<F: Float> Multivector16<F> {
+    #[inline]
+    fn grade_is_zero(&self, g: u8) -> bool {
+        GRADES
+            .iter()
+            .enumerate()
+            .all(|(i, grade)| *grade != g || self[i] == F::ZERO)
+    }
+
+    #[inline]
+    pub fn dual_full(self) -> Self {
+        Self::from(
+            swizzle!(self.components, DUAL_INDICES) * Lanes::from_array(DUAL_SIGNS.map(F::from)),
+        )
+    }
+
+    #[inline]
+    pub fn undual_full(self) -> Self {
+        Self::from(
+            swizzle!(self.components, DUAL_INDICES) * Lanes::from_array(UNDUAL_SIGNS.map(F::from)),
+        )
+    }
+
+    #[inline]
+    pub fn geo_full(self, rhs: Self) -> Self {
+        Self::from(cayley_product!(self, rhs.components, GEO_SIGNS))
+    }
+
+    #[inline]
+    pub fn outer_full(self, rhs: Self) -> Self {
+        Self::from(cayley_product!(self, rhs.components, OUTER_SIGNS))
+    }
+
+    #[inline]
+    pub fn inner_full(self, rhs: Self) -> Self {
+        Self::from(cayley_product!(self, rhs.components, INNER_SIGNS))
+    }
+
+    #[inline]
+    pub fn regressive_full(self, rhs: Self) -> Self {
+        self.dual_full().outer_full(rhs.dual_full()).undual_full()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample_1() -> Multivector16 {
+        Multivector16::from([
+            1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 0.25, -1.0, 2.0, 4.0, 1.0, -3.0, 0.5, 2.0, -1.5,
+        ])
+    }
+
+    fn sample_2() -> Multivector16 {
+        Multivector16::from([
+            -0.5, 1.0, 3.0, -2.0, 0.5, 1.0, -1.0, 2.0, 0.5, -3.0, 1.0, 2.0, 1.0, -1.0, 0.5, 2.0,
+        ])
+    }
+
+    #[test]
+    fn geo_matches_kvector_products() {
+        let v1 = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let v2 = Vector::from([4.0, 3.0, 2.0, 1.0]);
+        let bv = Bivector::from([-5.0, 10.0, -5.0, 15.0, 10.0, 5.0]);
+        let tv = Trivector::from([4.0, 3.0, 2.0, 1.0]);
+        let mv = |x: Versor| Multivector16::from(x);
+        assert_eq!(Multivector16::from(v1) * v2, mv(v1 * v2));
+        assert_eq!(Multivector16::from(bv) * tv, mv(bv * tv));
+        assert_eq!(Multivector16::from(v1) * bv, mv(v1 * bv));
+        assert_eq!(Multivector16::from(tv) * tv, mv(tv * tv));
+    }
+
+    #[test]
+    fn geo_matches_motor_products() {
+        let m: Motor = Motor::from([-1.0, 0.0, 0.0, 1.0, -2.0, 0.0, 0.0, 2.0]);
+        let bv = Bivector::from([-5.0, 10.0, -5.0, 15.0, 10.0, 5.0]);
+        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(Multivector16::from(m) * bv, Multivector16::from(m * bv));
+        assert_eq!(Multivector16::from(v) * m, Multivector16::from(v * m));
+    }
+
+    #[test]
+    fn geo_associative() {
+        let (a, b) = (sample_1(), sample_2());
+        let c = a + b * 0.5;
+        assert_eq!((a * b) * c, a * (b * c));
+    }
+
+    #[test]
+    fn geo_distributes_over_mixed_grades() {
+        let v: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let bv = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+        let tv = Trivector::from([1.0, -2.0, 3.0, -4.0]);
+        let mixed = Multivector16::from(v) + Multivector16::from(bv);
+        assert_eq!(
+            mixed * tv,
+            Multivector16::from(v * tv) + Multivector16::from(bv * tv)
+        );
+    }
+
+    #[test]
+    fn outer_inner_regressive_match_kvectors() {
+        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let bv = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+        let tv = Trivector::from([1.0, 2.0, 3.0, 4.0]);
+        let mv = |x: AnyKVector| Multivector16::from(x);
+        assert_eq!(Multivector16::from(v) ^ bv, mv(v ^ bv));
+        assert_eq!(Multivector16::from(bv) | v, mv(bv | v));
+        assert_eq!(Multivector16::from(v) | tv, mv(v | tv));
+        assert_eq!(Multivector16::from(tv) & bv, mv(tv & bv));
+        assert_eq!(Multivector16::from(tv) & (tv * 2.0), mv(tv & (tv * 2.0)));
+    }
+
+    #[test]
+    fn dual_undual_match_kvectors() {
+        let bv: Bivector = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+        let tv: Trivector = Trivector::from([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(Multivector16::from(tv).dual_full(), tv.dual().into());
+        assert_eq!(Multivector16::from(bv).undual_full(), bv.undual().into());
+        assert_eq!(sample_1().dual_full().undual_full(), sample_1());
+    }
+
+    #[test]
+    fn lossless_conversions() {
+        let m: Motor = Motor::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let ov: OddVersor = OddVersor::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let bv: Bivector = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(Motor::try_from(Multivector16::from(m)), Ok(m));
+        assert_eq!(OddVersor::try_from(Multivector16::from(ov)), Ok(ov));
+        assert_eq!(Bivector::try_from(Multivector16::from(bv)), Ok(bv));
+        assert_eq!(
+            AnyKVector::try_from(Multivector16::from(bv)),
+            Ok(AnyKVector::Two(bv))
+        );
+        assert_eq!(Scalar::try_from(Multivector16::from(2.0)), Ok(2.0));
+        assert!(Motor::try_from(sample_1()).is_err());
+        assert!(Trivector::try_from(Multivector16::from(ov)).is_err());
+    }
+
+    #[test]
+    fn versor_from_mixed_parity() {
+        let mixed = Multivector16::from(e1) + Multivector16::from(e12);
+        assert_eq!(Versor::from(mixed), Versor::Mixed(mixed));
+        assert_eq!(Versor::from(Multivector16::from(e12)), Versor::from(e12));
+        assert_eq!(Multivector16::from(Versor::from(mixed)), mixed);
+    }
+
+    #[test]
+    fn kvector_times_mixed_is_mixed() {
+        let mixed = Multivector16::from(e1) + Multivector16::from(2.0);
+        let Versor::Mixed(product) = e2 * mixed else {
+            panic!("Vector * (vector + scalar) should be mixed");
+        };
+        assert_eq!(
+            product,
+            Multivector16::from(e2 * e1) + Multivector16::from(e2 * 2.0)
+        );
+    }
+}
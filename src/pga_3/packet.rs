@@ -0,0 +1,470 @@
+use crate::pga_3::*;
+use std::array;
+use std::ops::{Add, BitAnd, BitXor, Div, Index, IndexMut, Mul, Neg, Shl, Shr, Sub};
+use std::simd::Simd;
+use std::simd::StdFloat;
+
+// Structure-of-arrays packets of eight elements. Where a `Trivector` keeps its four coefficients
+// in the lanes of one register, a `TrivectorX8` keeps one register per coefficient with one element
+// in each lane, so every lane does useful work and eight rays or points go through a product in
+// the time one would. Indexing a packet gives the register of one coefficient, and `lane` gives
+// back a single element. Packets are f32 only, like the ray tracer they serve, as twice as many f32
+// as f64 lanes fit in a register.
+
+pub const LANES: usize = 8;
+
+pub type FloatX8 = Simd<f32, LANES>;
+
+macro_rules! packet {
+    ($packet:ident, $single:ty, $n:literal) => {
+        #[derive(Debug, Copy, Clone)]
+        pub struct $packet {
+            pub components: [FloatX8; $n],
+        }
+
+        impl $packet {
+            /// A packet holding `x` in every lane
+            #[inline]
+            pub fn splat(x: $single) -> Self {
+                Self {
+                    components: array::from_fn(|i| FloatX8::splat(x[i])),
+                }
+            }
+
+            /// The element in lane `i`
+            #[inline]
+            pub fn lane(&self, i: usize) -> $single {
+                <$single>::from(array::from_fn(|j| self.components[j][i]))
+            }
+
+            #[inline]
+            pub fn to_array(&self) -> [$single; LANES] {
+                array::from_fn(|i| self.lane(i))
+            }
+        }
+
+        impl From<[$single; LANES]> for $packet {
+            #[inline]
+            fn from(xs: [$single; LANES]) -> Self {
+                Self {
+                    components: array::from_fn(|j| FloatX8::from_array(xs.map(|x| x[j]))),
+                }
+            }
+        }
+
+        impl From<[FloatX8; $n]> for $packet {
+            #[inline]
+            fn from(components: [FloatX8; $n]) -> Self {
+                Self { components }
+            }
+        }
+
+        impl Index<usize> for $packet {
+            type Output = FloatX8;
+
+            #[inline]
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.components[index]
+            }
+        }
+
+        impl IndexMut<usize> for $packet {
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                &mut self.components[index]
+            }
+        }
+
+        impl Add for $packet {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self::from(array::from_fn(|i| self[i] + rhs[i]))
+            }
+        }
+
+        impl Sub for $packet {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self::from(array::from_fn(|i| self[i] - rhs[i]))
+            }
+        }
+
+        impl Neg for $packet {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self::from(self.components.map(|c| -c))
+            }
+        }
+
+        impl Mul<FloatX8> for $packet {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: FloatX8) -> Self::Output {
+                Self::from(self.components.map(|c| c * rhs))
+            }
+        }
+
+        impl Mul<f32> for $packet {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: f32) -> Self::Output {
+                self * FloatX8::splat(rhs)
+            }
+        }
+
+        impl Div<FloatX8> for $packet {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: FloatX8) -> Self::Output {
+                Self::from(self.components.map(|c| c / rhs))
+            }
+        }
+    };
+}
+
+packet!(VectorX8, Vector, 4);
+packet!(BivectorX8, Bivector, 6);
+packet!(TrivectorX8, Trivector, 4);
+packet!(MotorX8, Motor, 8);
+
+impl TrivectorX8 {
+    #[inline]
+    pub fn point(x: FloatX8, y: FloatX8, z: FloatX8) -> Self {
+        Self::from([FloatX8::splat(1.0), -x, -y, -z])
+    }
+
+    #[inline]
+    pub fn direction(x: FloatX8, y: FloatX8, z: FloatX8) -> Self {
+        Self::from([FloatX8::splat(0.0), -x, -y, -z])
+    }
+
+    /// Divides each point by its weight. Ideal points are left with infinite coordinates.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self / self[0]
+    }
+
+    #[inline]
+    pub fn x(&self) -> FloatX8 {
+        -self[1] / self[0]
+    }
+
+    #[inline]
+    pub fn y(&self) -> FloatX8 {
+        -self[2] / self[0]
+    }
+
+    #[inline]
+    pub fn z(&self) -> FloatX8 {
+        -self[3] / self[0]
+    }
+
+    /// The dot product of the coordinates of normalized points or directions
+    #[inline]
+    pub fn dot(self, rhs: Self) -> FloatX8 {
+        self[1] * rhs[1] + self[2] * rhs[2] + self[3] * rhs[3]
+    }
+}
+
+impl BivectorX8 {
+    /// Normalizes the Euclidean lines so their direction has unit length
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self / (self[0] * self[0] + self[1] * self[1] + self[2] * self[2]).sqrt()
+    }
+
+    /// The direction each line runs along, as an ideal point, as `Bivector::direction`
+    #[inline]
+    pub fn direction(&self) -> TrivectorX8 {
+        TrivectorX8::direction(self[2], self[1], self[0])
+    }
+}
+
+impl MotorX8 {
+    #[inline]
+    pub fn reverse(self) -> Self {
+        let [s, a, b, c, d, e, f, g] = self.components;
+        Self::from([s, -a, -b, -c, -d, -e, -f, g])
+    }
+
+    // The rotation part of the sandwich `m~ x m`, as a matrix acting on (x, y, z) coordinates
+    // and scaled by the squared norm of the motor. Every grade transforms its Euclidean
+    // coefficients with it, and only the translation terms differ between them.
+    #[inline]
+    fn rotation_matrix(&self) -> [[FloatX8; 3]; 3] {
+        let [a0, a1, a2, a3, ..] = self.components;
+        let two = FloatX8::splat(2.0);
+        let (s0, s1, s2, s3) = (a0 * a0, a1 * a1, a2 * a2, a3 * a3);
+        [
+            [
+                s0 - s1 - s2 + s3,
+                -two * (a0 * a1 - a2 * a3),
+                two * (a0 * a2 + a1 * a3),
+            ],
+            [
+                two * (a0 * a1 + a2 * a3),
+                s0 - s1 + s2 - s3,
+                -two * (a0 * a3 - a1 * a2),
+            ],
+            [
+                -two * (a0 * a2 - a1 * a3),
+                two * (a0 * a3 + a1 * a2),
+                s0 + s1 - s2 - s3,
+            ],
+        ]
+    }
+
+    #[inline]
+    fn norm_squared(&self) -> FloatX8 {
+        let [a0, a1, a2, a3, ..] = self.components;
+        a0 * a0 + a1 * a1 + a2 * a2 + a3 * a3
+    }
+}
+
+#[inline]
+fn apply(r: [[FloatX8; 3]; 3], v: [FloatX8; 3]) -> [FloatX8; 3] {
+    r.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+impl Mul for MotorX8 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let [a0, a1, a2, a3, a4, a5, a6, a7] = self.components;
+        let [b0, b1, b2, b3, b4, b5, b6, b7] = rhs.components;
+        Self::from([
+            a0 * b0 - a1 * b1 - a2 * b2 - a3 * b3,
+            a0 * b1 + a1 * b0 + a2 * b3 - a3 * b2,
+            a0 * b2 - a1 * b3 + a2 * b0 + a3 * b1,
+            a0 * b3 + a1 * b2 - a2 * b1 + a3 * b0,
+            a0 * b4 + a1 * b5 - a2 * b6 - a3 * b7 + a4 * b0 - a5 * b1 + a6 * b2 - a7 * b3,
+            a0 * b5 - a1 * b4 - a2 * b7 + a3 * b6 + a4 * b1 + a5 * b0 - a6 * b3 - a7 * b2,
+            a0 * b6 - a1 * b7 + a2 * b4 - a3 * b5 - a4 * b2 + a5 * b3 + a6 * b0 - a7 * b1,
+            a0 * b7 + a1 * b6 + a2 * b5 + a3 * b4 + a4 * b3 + a5 * b2 + a6 * b1 + a7 * b0,
+        ])
+    }
+}
+
+impl Shr<TrivectorX8> for MotorX8 {
+    type Output = TrivectorX8;
+
+    #[inline]
+    fn shr(self, rhs: TrivectorX8) -> Self::Output {
+        let [a0, a1, a2, a3, a4, a5, a6, a7] = self.components;
+        let [t0, t1, t2, t3] = rhs.components;
+        let two = FloatX8::splat(2.0);
+        let [x, y, z] = apply(self.rotation_matrix(), [t1, t2, t3]);
+        TrivectorX8::from([
+            t0 * self.norm_squared(),
+            x + t0 * two * (a0 * a4 - a1 * a5 + a2 * a6 + a3 * a7),
+            y + t0 * two * (a0 * a5 + a1 * a4 + a2 * a7 - a3 * a6),
+            z + t0 * two * (a0 * a6 + a1 * a7 - a2 * a4 + a3 * a5),
+        ])
+    }
+}
+
+impl Shr<BivectorX8> for MotorX8 {
+    type Output = BivectorX8;
+
+    #[inline]
+    fn shr(self, rhs: BivectorX8) -> Self::Output {
+        let [a0, a1, a2, a3, a4, a5, a6, a7] = self.components;
+        let [l0, l1, l2, l3, l4, l5] = rhs.components;
+        let two = FloatX8::splat(2.0);
+        let r = self.rotation_matrix();
+        // The Euclidean part is stored as (z, y, x)
+        let [x, y, z] = apply(r, [l2, l1, l0]);
+        let [dx, dy, dz] = apply(r, [l3, l4, l5]);
+        BivectorX8::from([
+            z,
+            y,
+            x,
+            dx + two
+                * (l0 * (a0 * a5 + a1 * a4 - a2 * a7 + a3 * a6)
+                    - l1 * (a0 * a6 - a1 * a7 - a2 * a4 - a3 * a5)
+                    - l2 * (a0 * a7 + a1 * a6 + a2 * a5 - a3 * a4)),
+            dy + two
+                * (-l0 * (a0 * a4 - a1 * a5 - a2 * a6 - a3 * a7)
+                    - l1 * (a0 * a7 + a1 * a6 - a2 * a5 + a3 * a4)
+                    + l2 * (a0 * a6 - a1 * a7 + a2 * a4 + a3 * a5)),
+            dz + two
+                * (-l0 * (a0 * a7 - a1 * a6 + a2 * a5 + a3 * a4)
+                    + l1 * (a0 * a4 + a1 * a5 + a2 * a6 - a3 * a7)
+                    - l2 * (a0 * a5 - a1 * a4 - a2 * a7 - a3 * a6)),
+        ])
+    }
+}
+
+impl Shr<VectorX8> for MotorX8 {
+    type Output = VectorX8;
+
+    #[inline]
+    fn shr(self, rhs: VectorX8) -> Self::Output {
+        let [a0, a1, a2, a3, a4, a5, a6, a7] = self.components;
+        let [v0, v1, v2, v3] = rhs.components;
+        let two = FloatX8::splat(2.0);
+        let [x, y, z] = apply(self.rotation_matrix(), [v0, v1, v2]);
+        VectorX8::from([
+            x,
+            y,
+            z,
+            v3 * self.norm_squared()
+                - two
+                    * (v0 * (a0 * a4 + a1 * a5 - a2 * a6 + a3 * a7)
+                        + v1 * (a0 * a5 - a1 * a4 + a2 * a7 + a3 * a6)
+                        + v2 * (a0 * a6 + a1 * a7 + a2 * a4 - a3 * a5)),
+        ])
+    }
+}
+
+macro_rules! reverse_sandwich {
+    ($($packet:ident),*) => {
+        $(
+            impl Shl<$packet> for MotorX8 {
+                type Output = $packet;
+
+                #[inline]
+                fn shl(self, rhs: $packet) -> Self::Output {
+                    Shr::shr(self.reverse(), rhs)
+                }
+            }
+        )*
+    };
+}
+
+reverse_sandwich!(VectorX8, BivectorX8, TrivectorX8);
+
+// Where each line meets each plane
+impl BitXor<VectorX8> for BivectorX8 {
+    type Output = TrivectorX8;
+
+    #[inline]
+    fn bitxor(self, rhs: VectorX8) -> Self::Output {
+        let [l0, l1, l2, l3, l4, l5] = self.components;
+        let [v0, v1, v2, v3] = rhs.components;
+        TrivectorX8::from([
+            l0 * v2 + l1 * v1 + l2 * v0,
+            -l2 * v3 - l4 * v2 + l5 * v1,
+            -l1 * v3 + l3 * v2 - l5 * v0,
+            -l0 * v3 - l3 * v1 + l4 * v0,
+        ])
+    }
+}
+
+// The line through each pair of points
+impl BitAnd for TrivectorX8 {
+    type Output = BivectorX8;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        let [p0, p1, p2, p3] = self.components;
+        let [q0, q1, q2, q3] = rhs.components;
+        BivectorX8::from([
+            p0 * q3 - p3 * q0,
+            p0 * q2 - p2 * q0,
+            p0 * q1 - p1 * q0,
+            p2 * q3 - p3 * q2,
+            p3 * q1 - p1 * q3,
+            p1 * q2 - p2 * q1,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn motors() -> [Motor; LANES] {
+        array::from_fn(|i| {
+            let i = i as f32;
+            Motor::rotation(
+                Blade::new(Bivector::through(
+                    Trivector::point(i, 1.0, -i),
+                    Trivector::direction(1.0, i - 3.0, 2.0),
+                ))
+                .unwrap(),
+                PI / (i + 2.0),
+            ) * Motor::trans_coords(i, -2.0, 0.5 * i)
+                * (1.0 + i)
+        })
+    }
+
+    fn points() -> [Trivector; LANES] {
+        array::from_fn(|i| {
+            let i = i as f32;
+            Trivector::point(i - 4.0, 2.0 * i, 1.0) * (i + 1.0)
+        })
+    }
+
+    fn lines() -> [Bivector; LANES] {
+        let ps = points();
+        array::from_fn(|i| (ps[i] & ps[(i + 3) % LANES]).assert::<Bivector>())
+    }
+
+    fn planes() -> [Vector; LANES] {
+        array::from_fn(|i| Vector::from([1.0, i as f32, -2.0, 3.0 - i as f32]))
+    }
+
+    #[test]
+    fn lanes_round_trip() {
+        let packet = TrivectorX8::from(points());
+        assert_eq!(packet.to_array(), points());
+        assert_eq!(packet.lane(3), points()[3]);
+        assert_eq!(TrivectorX8::splat(e123).lane(7), e123);
+        assert_eq!(packet.normalize().lane(2), points()[2].normalize());
+        assert_eq!(packet.x()[5], points()[5].x());
+    }
+
+    #[test]
+    fn motor_products() {
+        let (ms, ps) = (motors(), points());
+        let packet = MotorX8::from(ms);
+        let composed = (packet * MotorX8::from(ms.map(|m| m.reverse()))).to_array();
+        let moved = (packet >> TrivectorX8::from(ps)).to_array();
+        let returned = (packet << TrivectorX8::from(ps)).to_array();
+        for i in 0..LANES {
+            assert_eq!(composed[i], ms[i] * ms[i].reverse());
+            assert_eq!(moved[i].normalize(), (ms[i] >> ps[i]).normalize());
+            assert_eq!(returned[i].normalize(), (ms[i] << ps[i]).normalize());
+        }
+    }
+
+    #[test]
+    fn sandwich_lines_and_planes() {
+        let ms = motors().map(|m| m.normalize());
+        let packet = MotorX8::from(ms);
+        let lines = lines().map(|l| l.normalize());
+        let moved_lines = (packet >> BivectorX8::from(lines)).to_array();
+        let moved_planes = (packet >> VectorX8::from(planes())).to_array();
+        for i in 0..LANES {
+            assert_eq!(moved_lines[i], ms[i] >> lines[i]);
+            assert_eq!(moved_planes[i], ms[i] >> planes()[i]);
+        }
+    }
+
+    #[test]
+    fn join_and_meet() {
+        let ps = points();
+        let shifted = array::from_fn(|i| ps[(i + 3) % LANES]);
+        let joined = (TrivectorX8::from(ps) & TrivectorX8::from(shifted)).to_array();
+        let met = (BivectorX8::from(lines()) ^ VectorX8::from(planes())).to_array();
+        for i in 0..LANES {
+            assert_eq!(joined[i], lines()[i]);
+            assert_eq!(met[i], (lines()[i] ^ planes()[i]).assert::<Trivector>());
+        }
+        let line = BivectorX8::from(lines());
+        assert_eq!(line.normalize().lane(4), lines()[4].normalize());
+        assert_eq!(line.direction().lane(1), lines()[1].direction());
+    }
+}
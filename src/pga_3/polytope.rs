@@ -0,0 +1,399 @@
+use crate::pga_3::*;
+
+// A convex polytope is the intersection of the half-spaces behind its face planes, which are
+// normalized with their normals pointing out of it. Its vertices are where three faces meet, and
+// its faces are the planes joining three vertices that have every other vertex behind them, so
+// both constructions find the other half by trying every triple. That's cubic in the number of
+// planes or points, which is fine for the handful that make up a solid. Distances are compared
+// against a tolerance scaled by the size of the solid, so tiny and huge solids work alike.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexPolytope<F: Float = f32> {
+    planes: Vec<Vector<F>>,
+    vertices: Vec<Trivector<F>>,
+    tolerance: F,
+}
+
+impl<F: Float> ConvexPolytope<F> {
+    /// The polytope behind every plane, ie on the opposite side to its normal, or `None` if that's
+    /// empty, flat or unbounded. Planes that don't form a face are dropped.
+    pub fn from_planes(planes: &[Vector<F>]) -> Option<Self> {
+        let planes: Vec<Vector<F>> = planes.iter().map(|p| p.normalize()).collect();
+        let mut corners: Vec<Trivector<F>> = vec![];
+        for (i, a) in planes.iter().enumerate() {
+            for (j, b) in planes.iter().enumerate().skip(i + 1) {
+                for c in planes.iter().skip(j + 1) {
+                    let Ok(vertex) =
+                        (Bivector::from_planes(*a, *b) ^ *c).try_assert::<Trivector<F>>()
+                    else {
+                        continue;
+                    };
+                    // The triple product of the unit normals, which doesn't depend on scale
+                    if vertex[0].abs() <= F::EPSILON {
+                        continue;
+                    }
+                    corners.push(vertex.normalize());
+                }
+            }
+        }
+        let tolerance = F::EPSILON * extent(&corners);
+        let mut vertices: Vec<Trivector<F>> = vec![];
+        for vertex in corners {
+            if planes
+                .iter()
+                .all(|p| vertex.distance_to_plane(*p) <= tolerance)
+            {
+                push_unique(&mut vertices, vertex, tolerance);
+            }
+        }
+        let hull = Self::from_points(&vertices)?;
+        // An unbounded region still has vertices, but their hull has faces the region doesn't
+        hull.planes
+            .iter()
+            .all(|face| planes.iter().any(|p| same_plane(*p, *face, hull.tolerance)))
+            .then_some(hull)
+    }
+
+    /// The convex hull of the points, or `None` if they're all on one plane
+    pub fn from_points(points: &[Trivector<F>]) -> Option<Self> {
+        let points: Vec<Trivector<F>> = points.iter().map(|p| p.normalize()).collect();
+        let size = extent(&points);
+        let tolerance = F::EPSILON * size;
+        let mut planes: Vec<Vector<F>> = vec![];
+        for (i, a) in points.iter().enumerate() {
+            for (j, b) in points.iter().enumerate().skip(i + 1) {
+                for c in points.iter().skip(j + 1) {
+                    // Collinear points join to zero, and otherwise to twice the triangle's area
+                    let Ok(plane) = (*a & *b & *c).try_assert::<Vector<F>>() else {
+                        continue;
+                    };
+                    let norm = plane.eucl_norm();
+                    if norm <= tolerance * size {
+                        continue;
+                    }
+                    // Rather than `normalize`, which takes small enough planes to be ideal
+                    let plane = plane / norm;
+                    let distances: Vec<F> =
+                        points.iter().map(|p| p.distance_to_plane(plane)).collect();
+                    let face = if distances.iter().all(|d| *d <= tolerance) {
+                        plane
+                    } else if distances.iter().all(|d| *d >= -tolerance) {
+                        -plane
+                    } else {
+                        continue;
+                    };
+                    if distances.iter().all(|d| d.abs() <= tolerance) {
+                        return None;
+                    }
+                    if !planes.iter().any(|p| same_plane(*p, face, tolerance)) {
+                        planes.push(face);
+                    }
+                }
+            }
+        }
+        // Vertices are the points on at least three faces, rather than inside a face or an edge
+        let mut vertices = vec![];
+        for p in points {
+            let faces = planes
+                .iter()
+                .filter(|f| p.distance_to_plane(**f).abs() <= tolerance)
+                .count();
+            if faces >= 3 {
+                push_unique(&mut vertices, p, tolerance);
+            }
+        }
+        (planes.len() >= 4).then_some(Self {
+            planes,
+            vertices,
+            tolerance,
+        })
+    }
+
+    /// The face planes, normalized and facing outwards
+    #[inline]
+    pub fn planes(&self) -> &[Vector<F>] {
+        &self.planes
+    }
+
+    /// The vertices, normalized
+    #[inline]
+    pub fn vertices(&self) -> &[Trivector<F>] {
+        &self.vertices
+    }
+
+    /// Whether the point is inside or on the surface
+    #[inline]
+    pub fn contains(&self, point: Trivector<F>) -> bool {
+        self.planes
+            .iter()
+            .all(|p| point.distance_to_plane(*p) <= self.tolerance)
+    }
+
+    /// The part of the segment from `a` to `b` inside the polytope, or `None` if it misses
+    pub fn clip(&self, a: Trivector<F>, b: Trivector<F>) -> Option<(Trivector<F>, Trivector<F>)> {
+        let (a, b) = (a.normalize(), b.normalize());
+        let (mut t0, mut t1): (F, F) = (F::ZERO, F::ONE);
+        for plane in &self.planes {
+            // The signed distance changes linearly along the segment
+            let (da, db) = (a.distance_to_plane(*plane), b.distance_to_plane(*plane));
+            match (da > F::ZERO, db > F::ZERO) {
+                (true, true) => return None,
+                (true, false) => t0 = t0.max(da / (da - db)),
+                (false, true) => t1 = t1.min(da / (da - db)),
+                (false, false) => {}
+            }
+        }
+        (t0 <= t1).then(|| (a * (F::ONE - t0) + b * t0, a * (F::ONE - t1) + b * t1))
+    }
+
+    #[inline]
+    pub fn volume(&self) -> F {
+        self.tetrahedra().map(|(volume, _)| volume).sum()
+    }
+
+    /// The centre of mass of the solid, as opposed to the mean of its vertices
+    pub fn centroid(&self) -> Trivector<F> {
+        let (volume, moment) = self.tetrahedra().fold(
+            (F::ZERO, Trivector::from([F::ZERO; 4])),
+            |(v, m), (volume, centroid)| (v + volume, m + centroid * volume),
+        );
+        moment / volume
+    }
+
+    /// The vertices on a face, in order around it
+    pub fn face(&self, plane: Vector<F>) -> Vec<Trivector<F>> {
+        let mut vertices: Vec<Trivector<F>> = self
+            .vertices
+            .iter()
+            .filter(|v| v.distance_to_plane(plane).abs() <= self.tolerance)
+            .copied()
+            .collect();
+        let Some(centre) = Trivector::centroid(&vertices) else {
+            return vertices;
+        };
+        // Sort by the angle about the face normal, from the cosine and sine against the first
+        let n = [plane[0], plane[1], plane[2]];
+        let first = offset(vertices[0], centre);
+        let angle = |v: &Trivector<F>| {
+            let u = offset(*v, centre);
+            let cross = [
+                first[1] * u[2] - first[2] * u[1],
+                first[2] * u[0] - first[0] * u[2],
+                first[0] * u[1] - first[1] * u[0],
+            ];
+            let sin = cross[0] * n[0] + cross[1] * n[1] + cross[2] * n[2];
+            let cos = first[0] * u[0] + first[1] * u[1] + first[2] * u[2];
+            sin.atan2(cos)
+        };
+        vertices.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+        vertices
+    }
+
+    // The volumes and centroids of the tetrahedra from the mean of the vertices to a fan of
+    // triangles over each face. The volume of a tetrahedron is the triple product of its edges
+    // from one corner over 6, as the join of its corners rounds small volumes to zero.
+    fn tetrahedra(&self) -> impl Iterator<Item = (F, Trivector<F>)> + '_ {
+        let apex = Trivector::centroid(&self.vertices).unwrap_or(*Blade::e123);
+        self.planes.iter().flat_map(move |plane| {
+            let face = self.face(*plane);
+            (1..face.len().saturating_sub(1))
+                .map(|i| {
+                    let (a, b, c) = (face[0], face[i], face[i + 1]);
+                    let (u, v, w) = (offset(a, apex), offset(b, apex), offset(c, apex));
+                    let volume = (u[0] * (v[1] * w[2] - v[2] * w[1])
+                        + u[1] * (v[2] * w[0] - v[0] * w[2])
+                        + u[2] * (v[0] * w[1] - v[1] * w[0]))
+                        .abs()
+                        / F::from(6.0);
+                    (volume, (apex + a + b + c) / F::from(4.0))
+                })
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+// The coordinates of `p - from` for normalized points
+#[inline]
+fn offset<F: Float>(p: Trivector<F>, from: Trivector<F>) -> [F; 3] {
+    [p.x() - from.x(), p.y() - from.y(), p.z() - from.z()]
+}
+
+// The length of the diagonal of the bounding box of normalized points
+fn extent<F: Float>(points: &[Trivector<F>]) -> F {
+    let (mut min, mut max) = ([F::INFINITY; 3], [F::NEG_INFINITY; 3]);
+    for p in points {
+        for (i, x) in [p.x(), p.y(), p.z()].into_iter().enumerate() {
+            min[i] = min[i].min(x);
+            max[i] = max[i].max(x);
+        }
+    }
+    (0..3)
+        .map(|i| (max[i] - min[i]).max(F::ZERO).powi(2))
+        .sum::<F>()
+        .sqrt()
+}
+
+// Normalized planes with the same normal, and offsets within `tolerance`
+#[inline]
+fn same_plane<F: Float>(a: Vector<F>, b: Vector<F>, tolerance: F) -> bool {
+    (0..3).all(|i| (a[i] - b[i]).abs() <= F::EPSILON) && (a[3] - b[3]).abs() <= tolerance
+}
+
+#[inline]
+fn push_unique<F: Float>(points: &mut Vec<Trivector<F>>, p: Trivector<F>, tolerance: F) {
+    if !points.iter().any(|q| q.distance_to_point(p) <= tolerance) {
+        points.push(p);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::float_eq;
+
+    // The cube from -1 to 1 on every axis
+    fn cube_planes() -> Vec<Vector> {
+        vec![
+            Vector::from([1.0, 0.0, 0.0, 1.0]),
+            Vector::from([-1.0, 0.0, 0.0, 1.0]),
+            Vector::from([0.0, 1.0, 0.0, 1.0]),
+            Vector::from([0.0, -1.0, 0.0, 1.0]),
+            Vector::from([0.0, 0.0, 1.0, 1.0]),
+            Vector::from([0.0, 0.0, -1.0, 1.0]),
+        ]
+    }
+
+    fn tetrahedron() -> Vec<Trivector> {
+        vec![
+            Trivector::point(0.0, 0.0, 0.0),
+            Trivector::point(3.0, 0.0, 0.0),
+            Trivector::point(0.0, 3.0, 0.0),
+            Trivector::point(0.0, 0.0, 3.0),
+        ]
+    }
+
+    #[test]
+    fn cube_from_planes() {
+        let cube = ConvexPolytope::from_planes(&cube_planes()).unwrap();
+        assert_eq!(cube.vertices().len(), 8);
+        assert_eq!(cube.planes().len(), 6);
+        assert!(float_eq(cube.volume(), 8.0));
+        assert_eq!(cube.centroid(), e123);
+        assert!(cube.contains(Trivector::point(0.5, -1.0, 0.9)));
+        assert!(!cube.contains(Trivector::point(0.5, -1.1, 0.9)));
+        assert_eq!(cube.face(cube.planes()[0]).len(), 4);
+    }
+
+    #[test]
+    fn planes_that_dont_bound_a_solid() {
+        let mut planes = cube_planes();
+        planes.pop();
+        assert_eq!(ConvexPolytope::from_planes(&planes), None);
+        // Disjoint half-spaces
+        planes.push(Vector::from([0.0, 0.0, 1.0, -2.0]));
+        assert_eq!(ConvexPolytope::from_planes(&planes), None);
+        // A redundant plane is dropped
+        let mut planes = cube_planes();
+        planes.push(Vector::from([1.0, 1.0, 0.0, 5.0]));
+        let cube = ConvexPolytope::from_planes(&planes).unwrap();
+        assert_eq!(cube.planes().len(), 6);
+    }
+
+    #[test]
+    fn hull_of_points() {
+        let mut points = tetrahedron();
+        // Points inside, on a face and on an edge aren't vertices
+        points.push(Trivector::point(0.5, 0.5, 0.5));
+        points.push(Trivector::point(1.0, 1.0, 0.0));
+        points.push(Trivector::point(1.5, 0.0, 1.5));
+        let hull = ConvexPolytope::from_points(&points).unwrap();
+        assert_eq!(hull.vertices().len(), 4);
+        assert_eq!(hull.planes().len(), 4);
+        assert!(float_eq(hull.volume(), 4.5));
+        assert_eq!(hull.centroid(), Trivector::point(0.75, 0.75, 0.75));
+        for p in points {
+            assert!(hull.contains(p));
+        }
+        let flat: [Trivector; 4] = [
+            Trivector::point(0.0, 0.0, 0.0),
+            Trivector::point(1.0, 0.0, 0.0),
+            Trivector::point(0.0, 1.0, 0.0),
+            Trivector::point(1.0, 1.0, 0.0),
+        ];
+        assert_eq!(ConvexPolytope::from_points(&flat), None);
+    }
+
+    #[test]
+    fn hull_and_planes_agree() {
+        let cube = ConvexPolytope::from_planes(&cube_planes()).unwrap();
+        let hull = ConvexPolytope::from_points(cube.vertices()).unwrap();
+        assert!(float_eq(hull.volume(), cube.volume()));
+        for plane in hull.planes() {
+            assert!(
+                cube.planes()
+                    .iter()
+                    .any(|p| same_plane(*p, *plane, cube.tolerance))
+            );
+        }
+    }
+
+    #[test]
+    fn small_solids() {
+        let side = 0.002;
+        let planes: Vec<Vector> = cube_planes()
+            .into_iter()
+            .map(|p| Vector::from([p[0], p[1], p[2], side / 2.0]))
+            .collect();
+        let cube = ConvexPolytope::from_planes(&planes).unwrap();
+        assert_eq!(cube.vertices().len(), 8);
+        assert_eq!(cube.planes().len(), 6);
+        assert!(float_eq(cube.volume() / side.powi(3), 1.0));
+        let hull = ConvexPolytope::from_points(cube.vertices()).unwrap();
+        assert_eq!(hull.planes().len(), 6);
+        assert!(float_eq(hull.volume() / side.powi(3), 1.0));
+        let points: [Trivector; 4] = [
+            Trivector::point(0.0, 0.0, 0.0),
+            Trivector::point(3e-3, 0.0, 0.0),
+            Trivector::point(0.0, 3e-3, 0.0),
+            Trivector::point(0.0, 0.0, 3e-3),
+        ];
+        let hull = ConvexPolytope::from_points(&points).unwrap();
+        assert_eq!(hull.vertices().len(), 4);
+        assert!(hull.contains(Trivector::point(1e-4, 1e-4, 1e-4)));
+        assert!(!hull.contains(Trivector::point(-1e-4, 1e-4, 1e-4)));
+    }
+
+    #[test]
+    fn clip_segments() {
+        let cube = ConvexPolytope::from_planes(&cube_planes()).unwrap();
+        let (a, b) = cube
+            .clip(
+                Trivector::point(-3.0, 0.0, 0.0),
+                Trivector::point(0.0, 0.5, 0.0),
+            )
+            .unwrap();
+        assert_eq!(a, Trivector::point(-1.0, 1.0 / 3.0, 0.0));
+        assert_eq!(b, Trivector::point(0.0, 0.5, 0.0));
+        // Crossing right through
+        let (a, b) = cube
+            .clip(
+                Trivector::point(0.0, 0.0, 5.0),
+                Trivector::point(0.0, 0.0, -5.0),
+            )
+            .unwrap();
+        assert_eq!(
+            (a, b),
+            (
+                Trivector::point(0.0, 0.0, 1.0),
+                Trivector::point(0.0, 0.0, -1.0)
+            )
+        );
+        assert_eq!(
+            cube.clip(
+                Trivector::point(2.0, 0.0, 0.0),
+                Trivector::point(0.0, 3.0, 0.0)
+            ),
+            None
+        );
+    }
+}
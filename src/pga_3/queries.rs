@@ -0,0 +1,347 @@
+use crate::pga_3::*;
+use crate::util::float_eq;
+
+// Distances are between normalized elements, so every query normalizes its inputs first. Angles
+// are computed from a cosine and a sine with atan2, which only depends on the ratio of the two
+// and so doesn't need normalized inputs.
+
+/// How two lines lie relative to each other. Parallel lines are coplanar too, but are told apart
+/// from lines that cross as they don't meet at a finite point.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LineRelation {
+    Coplanar,
+    Parallel,
+    Skew,
+}
+
+impl<F: Float> Trivector<F> {
+    #[inline]
+    pub fn distance_to_point(self, other: Trivector<F>) -> F {
+        (self.normalize() & other.normalize()).magnitude()
+    }
+
+    /// Signed distance to a plane, positive on the side its normal points towards
+    #[inline]
+    pub fn distance_to_plane(self, plane: Vector<F>) -> F {
+        (self.normalize() & plane.normalize()).assert::<F>()
+    }
+
+    #[inline]
+    pub fn distance_to_line(self, line: Bivector<F>) -> F {
+        (self.normalize() & line.normalize()).magnitude()
+    }
+
+    /// Foot of the perpendicular dropped from this point onto a line
+    #[inline]
+    pub fn foot_on_line(self, line: Bivector<F>) -> Trivector<F> {
+        let perpendicular = (self | line).assert::<Vector<F>>();
+        (perpendicular ^ line).assert::<Trivector<F>>().normalize()
+    }
+
+    /// Foot of the perpendicular dropped from this point onto a plane
+    #[inline]
+    pub fn foot_on_plane(self, plane: Vector<F>) -> Trivector<F> {
+        let perpendicular = (self | plane).assert::<Bivector<F>>();
+        (perpendicular ^ plane).assert::<Trivector<F>>().normalize()
+    }
+}
+
+impl<F: Float> Bivector<F> {
+    /// Shortest distance between two lines. For intersecting lines this is zero, and for
+    /// parallel lines it's the distance from any point of one line to the other.
+    #[inline]
+    pub fn distance_to_line(self, other: Bivector<F>) -> F {
+        match self.closest_points(other) {
+            Some((p1, p2)) => p1.distance_to_point(p2),
+            None => Blade::<Trivector<F>>::e123
+                .foot_on_line(self)
+                .distance_to_line(other),
+        }
+    }
+
+    /// The points on each line closest to the other one, or `None` if the lines are parallel
+    /// and no unique pair exists. For intersecting lines both points are the intersection.
+    pub fn closest_points(self, other: Bivector<F>) -> Option<(Trivector<F>, Trivector<F>)> {
+        // The common normal's direction is the Euclidean part of the commutator of the lines
+        let normal = (self * other).grade(2).assert::<Bivector<F>>();
+        let direction = Trivector::from([F::ZERO, -normal[2], -normal[1], -normal[0]]);
+        if float_eq(direction.ideal_norm(), F::ZERO) {
+            return None;
+        }
+        let through_other = (other & direction).assert::<Vector<F>>();
+        let through_self = (self & direction).assert::<Vector<F>>();
+        Some((
+            (self ^ through_other).assert::<Trivector<F>>().normalize(),
+            (other ^ through_self).assert::<Trivector<F>>().normalize(),
+        ))
+    }
+
+    /// Where two lines cross, or `None` if they're skew or parallel
+    pub fn intersection(self, other: Bivector<F>) -> Option<Trivector<F>> {
+        match self.relation_to(other) {
+            LineRelation::Coplanar => self.closest_points(other).map(|(p, _)| p),
+            LineRelation::Parallel | LineRelation::Skew => None,
+        }
+    }
+
+    /// Whether two lines cross, are parallel or are skew
+    pub fn relation_to(self, other: Bivector<F>) -> LineRelation {
+        let (a, b) = (self.normalize(), other.normalize());
+        // The outer product is the distance between the lines times the sine of the angle between
+        // them, so it only tells crossing lines from skew ones once parallel lines are ruled out
+        let (da, db) = (a.direction(), b.direction());
+        let cross = [
+            da[2] * db[3] - da[3] * db[2],
+            da[3] * db[1] - da[1] * db[3],
+            da[1] * db[2] - da[2] * db[1],
+        ];
+        // The e0123 coefficient of the outer product, compared relative to the size of the lines
+        // as their ideal parts grow with the distance from the origin
+        let meet =
+            a[0] * b[5] + a[1] * b[4] + a[2] * b[3] + a[3] * b[2] + a[4] * b[1] + a[5] * b[0];
+        let scale = (a.coefficient_norm_sq() * b.coefficient_norm_sq()).sqrt();
+        if cross.iter().all(|c| float_eq(*c, F::ZERO)) {
+            LineRelation::Parallel
+        } else if float_eq(meet / scale, F::ZERO) {
+            LineRelation::Coplanar
+        } else {
+            LineRelation::Skew
+        }
+    }
+
+    /// Angle between two oriented lines, in `[0, PI]`
+    #[inline]
+    pub fn angle_to_line(self, other: Bivector<F>) -> F {
+        let cos = -(self | other).assert::<F>();
+        let sin = (self * other).grade(2).assert::<Bivector<F>>().eucl_norm();
+        sin.atan2(cos)
+    }
+
+    /// Angle between a line and a plane, in `[0, PI / 2]`
+    #[inline]
+    pub fn angle_to_plane(self, plane: Vector<F>) -> F {
+        let sin = (self ^ plane).eucl_norm();
+        let cos = (self | plane).eucl_norm();
+        sin.atan2(cos)
+    }
+}
+
+impl<F: Float> Vector<F> {
+    /// Angle between two oriented planes, in `[0, PI]`
+    #[inline]
+    pub fn angle_to_plane(self, other: Vector<F>) -> F {
+        let cos = (self | other).assert::<F>();
+        let sin = (self ^ other).eucl_norm();
+        sin.atan2(cos)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn line(p: Trivector, d: Trivector) -> Bivector {
+        Bivector::from((p, d))
+    }
+
+    #[test]
+    fn point_distances() {
+        let p = Trivector::point(1.0, 2.0, 3.0);
+        assert!(float_eq(
+            p.distance_to_point(Trivector::point(4.0, 6.0, 3.0)),
+            5.0
+        ));
+        assert!(float_eq((p * 2.0).distance_to_point(e123), f32::sqrt(14.0)));
+        let z_axis = line(e123, Trivector::direction(0.0, 0.0, 1.0));
+        assert!(float_eq(p.distance_to_line(z_axis), f32::sqrt(5.0)));
+    }
+
+    #[test]
+    fn distances_far_from_the_origin_in_f64() {
+        // Floats near a million are 1/16 apart in f32, which would round the offsets away
+        let p = Trivector::<f64>::point(1e6, 1e6, 1e6);
+        let q = Trivector::point(1e6 + 0.01, 1e6, 1e6);
+        assert!(float_eq(p.distance_to_point(q), 0.01));
+        let x_axis = Bivector::from((
+            Trivector::point(0.0, 1e6 + 0.02, 1e6),
+            Trivector::direction(1.0, 0.0, 0.0),
+        ));
+        assert!(float_eq(p.distance_to_line(x_axis), 0.02));
+    }
+
+    #[test]
+    fn signed_distance_to_plane() {
+        let plane: Vector = Vector::from([0.0, 2.0, 0.0, 2.0]);
+        assert!(float_eq(
+            Trivector::point(5.0, 3.0, 0.0).distance_to_plane(plane),
+            2.0
+        ));
+        assert!(float_eq(
+            Trivector::point(5.0, -1.0, 0.0).distance_to_plane(plane),
+            -2.0
+        ));
+    }
+
+    #[test]
+    fn feet_of_perpendiculars() {
+        let p = Trivector::point(1.0, 2.0, 3.0);
+        let z_axis = line(
+            Trivector::point(0.0, 0.0, -4.0),
+            Trivector::direction(0.0, 0.0, 2.0),
+        );
+        assert_eq!(p.foot_on_line(z_axis), Trivector::point(0.0, 0.0, 3.0));
+        let plane = Vector::from([0.0, 0.0, 1.0, -1.0]);
+        assert_eq!(p.foot_on_plane(plane), Trivector::point(1.0, 2.0, -1.0));
+    }
+
+    #[test]
+    fn projections() {
+        let p = Trivector::point(1.0, 2.0, 3.0);
+        let plane = Vector::from([0.0, 0.0, 2.0, 2.0]);
+        let line = line(e123, Trivector::direction(1.0, 1.0, 0.0));
+        assert_eq!(
+            p.project_onto(plane)
+                .unwrap()
+                .assert::<Trivector>()
+                .normalize(),
+            Trivector::point(1.0, 2.0, 1.0)
+        );
+        assert_eq!(
+            p.project_onto(line)
+                .unwrap()
+                .assert::<Trivector>()
+                .normalize(),
+            Trivector::point(1.5, 1.5, 0.0)
+        );
+        assert_eq!(
+            plane
+                .project_onto(p)
+                .unwrap()
+                .assert::<Vector>()
+                .normalize(),
+            Vector::from([0.0, 0.0, 1.0, 3.0])
+        );
+        let slope = line.project_onto(e3 + e2).unwrap();
+        assert_eq!(
+            slope.assert::<Bivector>().normalize(),
+            Bivector::from((e123, Trivector::direction(2.0, 1.0, -1.0))).normalize()
+        );
+    }
+
+    #[test]
+    fn rejections() {
+        let p = Trivector::point(1.0, 2.0, 3.0);
+        let plane = Vector::from([0.0, 0.0, 1.0, 1.0]);
+        let offset = p.reject_from(plane).unwrap().assert::<Trivector>();
+        assert!(offset.is_ideal());
+        assert_eq!(
+            p - offset,
+            p.project_onto(plane).unwrap().assert::<Trivector>()
+        );
+        assert_eq!(p - offset, Trivector::point(1.0, 2.0, 1.0));
+        let v = Vector::from([1.0, 1.0, 0.0, 0.0]);
+        let (rejection, projection) = (v.reject_from(e1), v.project_onto(e1));
+        assert_eq!(rejection.unwrap().assert::<Vector>(), e2);
+        assert_eq!(projection.unwrap().assert::<Vector>(), e1);
+    }
+
+    #[test]
+    fn ideal_targets() {
+        let p = Trivector::point(1.0, 2.0, 3.0);
+        assert_eq!(p.project_onto(e0), Err(PgaError::NonInvertible));
+        assert_eq!(p.reject_from(e01), Err(PgaError::NonInvertible));
+        assert_eq!(
+            e1.project_onto(Trivector::direction(1.0, 0.0, 0.0)),
+            Err(PgaError::NonInvertible)
+        );
+    }
+
+    #[test]
+    fn skew_lines() {
+        let l1 = line(e123, Trivector::direction(1.0, 0.0, 0.0));
+        let l2 = line(
+            Trivector::point(0.0, 3.0, 2.0),
+            Trivector::direction(0.0, 0.0, 1.0),
+        );
+        let (p1, p2) = l1.closest_points(l2).unwrap();
+        assert_eq!(p1, e123);
+        assert_eq!(p2, Trivector::point(0.0, 3.0, 0.0));
+        assert!(float_eq(l1.distance_to_line(l2), 3.0));
+        assert!(float_eq(l1.angle_to_line(l2), FRAC_PI_2));
+    }
+
+    #[test]
+    fn intersecting_and_parallel_lines() {
+        let p = Trivector::point(1.0, 1.0, 1.0);
+        let l1 = line(p, Trivector::direction(1.0, 0.0, 0.0));
+        let l2 = line(p, Trivector::direction(1.0, 1.0, 0.0));
+        assert_eq!(l1.closest_points(l2), Some((p, p)));
+        assert!(float_eq(l1.distance_to_line(l2), 0.0));
+        assert!(float_eq(l1.angle_to_line(l2), FRAC_PI_4));
+
+        let l3 = line(
+            Trivector::point(1.0, 1.0, 5.0),
+            Trivector::direction(-2.0, 0.0, 0.0),
+        );
+        assert_eq!(l1.closest_points(l3), None);
+        assert!(float_eq(l1.distance_to_line(l3), 4.0));
+        assert!(float_eq(l1.angle_to_line(l3), PI));
+    }
+
+    #[test]
+    fn plane_angles() {
+        let x_axis = line(e123, Trivector::direction(1.0, 0.0, 0.0));
+        let slope = line(e123, Trivector::direction(1.0, 1.0, 0.0));
+        assert!(float_eq(x_axis.angle_to_plane(e1), FRAC_PI_2));
+        assert!(float_eq(x_axis.angle_to_plane(e2), 0.0));
+        assert!(float_eq(slope.angle_to_plane(e2 * 3.0), FRAC_PI_4));
+        assert!(float_eq(e1.angle_to_plane(e2), FRAC_PI_2));
+        assert!(float_eq(e1.angle_to_plane(e1 + e2), FRAC_PI_4));
+        assert!(float_eq(e1.angle_to_plane(-e1), PI));
+    }
+
+    #[test]
+    fn line_intersections() {
+        let p = Trivector::point(1.0, -2.0, 3.0);
+        let l1 = Bivector::through(p, Trivector::direction(1.0, 0.0, 0.0));
+        let l2 = Bivector::through(p, Trivector::direction(0.0, 1.0, 1.0));
+        assert_eq!(l1.relation_to(l2), LineRelation::Coplanar);
+        assert_eq!(l1.intersection(l2), Some(p));
+
+        let l3 = Bivector::through(
+            Trivector::point(0.0, 0.0, 3.0),
+            Trivector::direction(0.0, 1.0, 0.0),
+        );
+        assert_eq!(l1.relation_to(l3), LineRelation::Coplanar);
+        assert_eq!(l1.intersection(l3), Some(Trivector::point(0.0, -2.0, 3.0)));
+
+        let l4 = Bivector::through(e123, Trivector::direction(-3.0, 0.0, 0.0));
+        assert_eq!(l1.relation_to(l4), LineRelation::Parallel);
+        assert_eq!(l1.intersection(l4), None);
+        assert_eq!(l1.relation_to(-l1), LineRelation::Parallel);
+
+        let l5 = Bivector::through(
+            Trivector::point(0.0, 0.0, 4.0),
+            Trivector::direction(0.0, 1.0, 0.0),
+        );
+        assert_eq!(l1.relation_to(l5), LineRelation::Skew);
+        assert_eq!(l1.intersection(l5), None);
+    }
+
+    #[test]
+    fn oblique_line_intersection_far_from_the_origin() {
+        // Rounding leaves the outer product of these lines well above the epsilon in f32
+        let p = Trivector::point(1234.5, -2567.8, 3891.2);
+        let l1 = Bivector::through(p, Trivector::direction(0.3, 0.7, -0.2));
+        let l2 = Bivector::through(p, Trivector::direction(-0.5, 0.1, 0.9));
+        assert_eq!(l1.relation_to(l2), LineRelation::Coplanar);
+        assert!(l1.intersection(l2).unwrap().distance_to_point(p) < 1e-3);
+
+        let l3 = Bivector::through(
+            Trivector::point(1234.5, -2567.8, 4891.2),
+            Trivector::direction(-0.5, 0.1, 0.9),
+        );
+        assert_eq!(l1.relation_to(l3), LineRelation::Skew);
+    }
+}
@@ -0,0 +1,421 @@
+// A slow but straightforward PGA(3,0,1) for checking the optimized products against. Each basis
+// blade is written as its generators in the order the rest of the crate stores it, and the Cayley
+// table is found at runtime by sorting the generators of every product of two blades, with each
+// swap of two different generators flipping the sign, then squaring away repeats with e0^2 = 0
+// and e1^2 = e2^2 = e3^2 = 1. Multivectors are plain arrays in the `Multivector16` layout.
+//
+// The crate's tests check every product against it, and it's public behind the `reference`
+// feature so that code outside the crate can do the same, eg to convert with
+// `Multivector16::from` and compare coefficients.
+
+const BLADES: [&[u8]; 16] = [
+    &[],
+    &[1],
+    &[2],
+    &[3],
+    &[0],
+    &[1, 2],
+    &[3, 1],
+    &[2, 3],
+    &[0, 1],
+    &[0, 2],
+    &[0, 3],
+    &[1, 2, 3],
+    &[0, 3, 2],
+    &[0, 1, 3],
+    &[0, 2, 1],
+    &[0, 1, 2, 3],
+];
+
+const PSEUDOSCALAR: usize = 15;
+
+/// The product of every pair of basis blades in the `Multivector16` layout
+pub struct CayleyTable {
+    // Lane of the product of blades i and j, and the sign it's scaled by (zero if it vanishes)
+    products: [[(usize, f32); 16]; 16],
+    grades: [usize; 16],
+}
+
+impl CayleyTable {
+    pub fn new() -> CayleyTable {
+        // Each stored blade is `sign` times its generators sorted into ascending order
+        let canonical: Vec<(u8, f32)> = BLADES.iter().map(|b| sorted_product(b, &[])).collect();
+        let lane_of = |mask: u8| canonical.iter().position(|(m, _)| *m == mask).unwrap();
+        let mut products = [[(0, 0.0); 16]; 16];
+        for i in 0..16 {
+            for j in 0..16 {
+                let (mask, sign) = sorted_product(BLADES[i], BLADES[j]);
+                let k = lane_of(mask);
+                products[i][j] = (k, sign * canonical[k].1);
+            }
+        }
+        CayleyTable {
+            products,
+            grades: BLADES.map(|b| b.len()),
+        }
+    }
+
+    /// Lane and sign of the product of the basis blades in lanes `i` and `j`
+    pub fn product(&self, i: usize, j: usize) -> (usize, f32) {
+        self.products[i][j]
+    }
+
+    /// The geometric product, keeping every term
+    pub fn geo(&self, a: [f32; 16], b: [f32; 16]) -> [f32; 16] {
+        self.filtered(a, b, |_, _, _| true)
+    }
+
+    /// The outer product, keeping the terms of grade `ga + gb`
+    pub fn outer(&self, a: [f32; 16], b: [f32; 16]) -> [f32; 16] {
+        self.filtered(a, b, |ga, gb, gk| gk == ga + gb)
+    }
+
+    /// The symmetric inner product, keeping the terms of grade `|ga - gb|`
+    pub fn inner(&self, a: [f32; 16], b: [f32; 16]) -> [f32; 16] {
+        self.filtered(a, b, |ga, gb, gk| gk == ga.abs_diff(gb))
+    }
+
+    /// The regressive product, the undual of the outer product of the duals
+    pub fn regressive(&self, a: [f32; 16], b: [f32; 16]) -> [f32; 16] {
+        self.undual(self.outer(self.dual(a), self.dual(b)))
+    }
+
+    /// The right complement, so that `x ^ dual(x) = e0123` for every basis blade `x`
+    pub fn dual(&self, a: [f32; 16]) -> [f32; 16] {
+        self.complement(a, |i, j| self.products[i][j])
+    }
+
+    /// The left complement, so that `undual(x) ^ x = e0123` for every basis blade `x`
+    pub fn undual(&self, a: [f32; 16]) -> [f32; 16] {
+        self.complement(a, |i, j| self.products[j][i])
+    }
+
+    /// Negates the grades that reversing the order of their generators flips
+    pub fn reverse(&self, a: [f32; 16]) -> [f32; 16] {
+        let mut result = a;
+        for (i, c) in result.iter_mut().enumerate() {
+            if self.grades[i] % 4 >= 2 {
+                *c = -*c;
+            }
+        }
+        result
+    }
+
+    fn filtered<F>(&self, a: [f32; 16], b: [f32; 16], keep: F) -> [f32; 16]
+    where
+        F: Fn(usize, usize, usize) -> bool,
+    {
+        let mut result = [0.0; 16];
+        for (i, ai) in a.iter().enumerate() {
+            for (j, bj) in b.iter().enumerate() {
+                let (k, sign) = self.products[i][j];
+                if keep(self.grades[i], self.grades[j], self.grades[k]) {
+                    result[k] += sign * ai * bj;
+                }
+            }
+        }
+        result
+    }
+
+    fn complement<F>(&self, a: [f32; 16], product: F) -> [f32; 16]
+    where
+        F: Fn(usize, usize) -> (usize, f32),
+    {
+        let mut result = [0.0; 16];
+        for (i, ai) in a.iter().enumerate() {
+            let (j, sign) = (0..16)
+                .map(|j| (j, product(i, j)))
+                .find(|(_, (k, sign))| *k == PSEUDOSCALAR && *sign != 0.0)
+                .map(|(j, (_, sign))| (j, sign))
+                .unwrap();
+            result[j] += sign * ai;
+        }
+        result
+    }
+}
+
+impl Default for CayleyTable {
+    fn default() -> Self {
+        CayleyTable::new()
+    }
+}
+
+// Sorts the generators of `a` then `b` into ascending order and squares away repeats, giving the
+// bitmask of the remaining generators and the sign picked up
+fn sorted_product(a: &[u8], b: &[u8]) -> (u8, f32) {
+    let mut generators: Vec<u8> = a.iter().chain(b).copied().collect();
+    let mut sign = 1.0;
+    for pass in 0..generators.len() {
+        for i in 0..generators.len().saturating_sub(pass + 1) {
+            if generators[i] > generators[i + 1] {
+                generators.swap(i, i + 1);
+                sign = -sign;
+            }
+        }
+    }
+    let mut mask = 0;
+    let mut i = 0;
+    while i < generators.len() {
+        if generators.get(i + 1) == Some(&generators[i]) {
+            if generators[i] == 0 {
+                return (0, 0.0);
+            }
+            i += 2;
+        } else {
+            mask |= 1 << generators[i];
+            i += 1;
+        }
+    }
+    (mask, sign)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pga_3::multivector16::{GEO_INDICES, GEO_SIGNS};
+    use crate::pga_3::*;
+    use crate::util::float_eq;
+
+    const SAMPLES: usize = 50;
+
+    // xorshift, to keep failures reproducible without pulling in a crate
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> f32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            ((self.0 >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0) as f32
+        }
+
+        // Random coefficients in the lanes of the given grades
+        fn multivector(&mut self, grades: &[usize]) -> Multivector16 {
+            let mut mv = Multivector16::from([0.0; 16]);
+            for lane in 0..16 {
+                if grades.contains(&BLADES[lane].len()) {
+                    mv[lane] = self.next();
+                }
+            }
+            mv
+        }
+
+        fn kvector(&mut self, grade: usize) -> AnyKVector {
+            AnyKVector::try_from(self.multivector(&[grade])).unwrap()
+        }
+    }
+
+    fn coefficients<T: Into<Versor>>(x: T) -> [f32; 16] {
+        let mv = Multivector16::from(x.into());
+        let mut cs = [0.0; 16];
+        cs.copy_from_slice(&mv[..]);
+        cs
+    }
+
+    #[track_caller]
+    fn assert_matches(actual: [f32; 16], expected: [f32; 16], what: &str) {
+        assert!(
+            actual.iter().zip(expected).all(|(a, e)| float_eq(*a, e)),
+            "{what}\n  specialized: {actual:?}\n  reference:   {expected:?}"
+        );
+    }
+
+    #[test]
+    fn known_products() {
+        let table = CayleyTable::new();
+        // e1 * e2 = e12, e3 * e1 = e31, e0 * e0 = 0, e12 * e12 = -1, e0 * e123 = e0123
+        assert_eq!(table.product(1, 2), (5, 1.0));
+        assert_eq!(table.product(3, 1), (6, 1.0));
+        assert_eq!(table.product(4, 4).1, 0.0);
+        assert_eq!(table.product(5, 5), (0, -1.0));
+        assert_eq!(table.product(4, 11), (15, 1.0));
+        // e032 = e0 ^ e3 ^ e2
+        let tv = table.outer(
+            table.outer(coefficients(e0), coefficients(e3)),
+            coefficients(e2),
+        );
+        assert_matches(
+            tv,
+            coefficients(Trivector::from([0.0, 1.0, 0.0, 0.0])),
+            "e032",
+        );
+    }
+
+    #[test]
+    fn matches_precomputed_table() {
+        let table = CayleyTable::new();
+        for i in 0..16 {
+            for k in 0..16 {
+                let j = GEO_INDICES[i][k];
+                let (lane, sign) = table.product(i, j);
+                assert_eq!(sign, GEO_SIGNS[i][k], "lanes {i} * {j}");
+                assert!(sign == 0.0 || lane == k, "lanes {i} * {j}");
+            }
+        }
+    }
+
+    macro_rules! check_kvector_pairs {
+        ($rng:expr, $table:expr; $(($lhs:ty, $gl:literal, $rhs:ty, $gr:literal)),*) => {
+            $(
+                for _ in 0..SAMPLES {
+                    let a = <$lhs>::try_from($rng.multivector(&[$gl])).unwrap();
+                    let b = <$rhs>::try_from($rng.multivector(&[$gr])).unwrap();
+                    let (ca, cb) = (coefficients(a), coefficients(b));
+                    let pair = concat!(stringify!($lhs), ", ", stringify!($rhs));
+                    assert_matches(coefficients(a.geo_kvector(b)), $table.geo(ca, cb), pair);
+                    // reverse(reverse(a) * reverse(b)) = b * a
+                    assert_matches(
+                        coefficients(a.reverse_geo_kvector(b)),
+                        $table.geo(cb, ca),
+                        pair,
+                    );
+                    assert_matches(coefficients(a.inner_kvector(b)), $table.inner(ca, cb), pair);
+                    assert_matches(coefficients(a.outer_kvector(b)), $table.outer(ca, cb), pair);
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn kvector_products() {
+        let (mut rng, table) = (Rng(0x9e3779b97f4a7c15), CayleyTable::new());
+        check_kvector_pairs!(rng, table;
+            (Vector, 1, Vector, 1), (Vector, 1, Bivector, 2), (Vector, 1, Trivector, 3),
+            (Bivector, 2, Vector, 1), (Bivector, 2, Bivector, 2), (Bivector, 2, Trivector, 3),
+            (Trivector, 3, Vector, 1), (Trivector, 3, Bivector, 2), (Trivector, 3, Trivector, 3)
+        );
+    }
+
+    #[test]
+    fn single_grade_products() {
+        let (mut rng, table) = (Rng(0x2545f4914f6cdd1d), CayleyTable::new());
+        for _ in 0..SAMPLES {
+            for ga in 0..=4 {
+                for gb in 0..=4 {
+                    let (a, b) = (rng.kvector(ga), rng.kvector(gb));
+                    let (ca, cb) = (coefficients(a), coefficients(b));
+                    let pair = format!("grades {ga} and {gb}");
+                    assert_matches(coefficients(a.geo(b)), table.geo(ca, cb), &pair);
+                    assert_matches(coefficients(a ^ b), table.outer(ca, cb), &pair);
+                    assert_matches(coefficients(a | b), table.inner(ca, cb), &pair);
+                    assert_matches(coefficients(a & b), table.regressive(ca, cb), &pair);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn versor_products() {
+        let (mut rng, table) = (Rng(0xd1b54a32d192ed03), CayleyTable::new());
+        let motor = |rng: &mut Rng| Motor::try_from(rng.multivector(&[0, 2, 4])).unwrap();
+        let odd = |rng: &mut Rng| OddVersor::try_from(rng.multivector(&[1, 3])).unwrap();
+        for _ in 0..SAMPLES {
+            let (m1, m2, o1, o2) = (
+                motor(&mut rng),
+                motor(&mut rng),
+                odd(&mut rng),
+                odd(&mut rng),
+            );
+            let (cm1, cm2) = (coefficients(m1), coefficients(m2));
+            let (co1, co2) = (coefficients(o1), coefficients(o2));
+            assert_matches(coefficients(m1 * m2), table.geo(cm1, cm2), "motor * motor");
+            assert_matches(coefficients(m1 * o1), table.geo(cm1, co1), "motor * odd");
+            assert_matches(coefficients(o1 * m1), table.geo(co1, cm1), "odd * motor");
+            assert_matches(coefficients(o1 * o2), table.geo(co1, co2), "odd * odd");
+            for g in 0..=4 {
+                let kv = rng.kvector(g);
+                let ckv = coefficients(kv);
+                let what = format!("with grade {g}");
+                assert_matches(coefficients(m1.geo(kv)), table.geo(cm1, ckv), &what);
+                assert_matches(coefficients(kv.geo(m1)), table.geo(ckv, cm1), &what);
+                assert_matches(coefficients(o1.geo(kv)), table.geo(co1, ckv), &what);
+                assert_matches(coefficients(kv.geo(o1)), table.geo(ckv, co1), &what);
+            }
+            let mixed = rng.multivector(&[0, 1, 2, 3, 4]);
+            let cmixed = coefficients(mixed);
+            assert_matches(
+                coefficients(m1 ^ mixed),
+                table.outer(cm1, cmixed),
+                "motor ^",
+            );
+            assert_matches(coefficients(o1 | mixed), table.inner(co1, cmixed), "odd |");
+            assert_matches(coefficients(m1 & o1), table.regressive(cm1, co1), "motor &");
+        }
+    }
+
+    #[test]
+    fn reverse() {
+        let (mut rng, table) = (Rng(0x94d049bb133111eb), CayleyTable::new());
+        for _ in 0..SAMPLES {
+            for g in 0..=4 {
+                let kv = rng.kvector(g);
+                let what = format!("grade {g}");
+                assert_matches(
+                    coefficients(kv.reverse()),
+                    table.reverse(coefficients(kv)),
+                    &what,
+                );
+            }
+            let m = Motor::try_from(rng.multivector(&[0, 2, 4])).unwrap();
+            let ov = OddVersor::try_from(rng.multivector(&[1, 3])).unwrap();
+            let mv = rng.multivector(&[0, 1, 2, 3, 4]);
+            assert_matches(
+                coefficients(m.reverse()),
+                table.reverse(coefficients(m)),
+                "motor",
+            );
+            assert_matches(
+                coefficients(ov.reverse()),
+                table.reverse(coefficients(ov)),
+                "odd",
+            );
+            assert_matches(
+                coefficients(mv.reverse()),
+                table.reverse(coefficients(mv)),
+                "full",
+            );
+        }
+    }
+
+    #[test]
+    fn dual_undual() {
+        let (mut rng, table) = (Rng(0xbf58476d1ce4e5b9), CayleyTable::new());
+        for _ in 0..SAMPLES {
+            for g in 0..=4 {
+                let kv = rng.kvector(g);
+                let what = format!("grade {g}");
+                assert_matches(coefficients(kv.dual()), table.dual(coefficients(kv)), &what);
+                assert_matches(
+                    coefficients(kv.undual()),
+                    table.undual(coefficients(kv)),
+                    &what,
+                );
+            }
+            let m = Motor::try_from(rng.multivector(&[0, 2, 4])).unwrap();
+            let ov = OddVersor::try_from(rng.multivector(&[1, 3])).unwrap();
+            let mv = rng.multivector(&[0, 1, 2, 3, 4]);
+            assert_matches(coefficients(m.dual()), table.dual(coefficients(m)), "motor");
+            assert_matches(
+                coefficients(m.undual()),
+                table.undual(coefficients(m)),
+                "motor",
+            );
+            assert_matches(coefficients(ov.dual()), table.dual(coefficients(ov)), "odd");
+            assert_matches(
+                coefficients(ov.undual()),
+                table.undual(coefficients(ov)),
+                "odd",
+            );
+            assert_matches(
+                coefficients(mv.dual_full()),
+                table.dual(coefficients(mv)),
+                "full",
+            );
+            assert_matches(
+                coefficients(mv.undual_full()),
+                table.undual(coefficients(mv)),
+                "full",
+            );
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use crate::pga_3::constant::embed;
+use crate::pga_3::multivector16::GRADES;
+use crate::pga_3::*;
+use std::fmt::{Display, Formatter};
+use std::simd::{LaneCount, SupportedLaneCount};
+use std::str::FromStr;
+
+// Multivectors are written as sums of scaled basis blades in the `Multivector16` layout, eg
+// `2e1 - 0.5e0 + e123`, with the scalar part as a bare number. A coefficient of one is left off,
+// and zero is written as `0`. Parsing accepts the same, with an optional `*` between a coefficient
+// and its blade and repeated blades summed. Numbers can't use exponents, as `2e1` is a blade.
+
+// Each basis blade is named after its constant, in the lane that constant's one lands in
+macro_rules! basis_names {
+    ($($blade:ident),*) => {{
+        let mut names = [""; 16];
+        $(names[lane_of(embed($blade))] = stringify!($blade);)*
+        names[15] = stringify!(e0123);
+        names
+    }};
+}
+
+const BASIS_NAMES: [&str; 16] = basis_names!(
+    e1, e2, e3, e0, e12, e31, e23, e01, e02, e03, e123, e032, e013, e021
+);
+
+const fn lane_of(blade: [f32; 16]) -> usize {
+    let mut lane = 0;
+    while blade[lane] != 1.0 {
+        lane += 1;
+    }
+    lane
+}
+
+fn write_terms<F: Float>(f: &mut Formatter<'_>, mv: Multivector16<F>) -> std::fmt::Result {
+    let mut first = true;
+    for (lane, name) in BASIS_NAMES.iter().enumerate() {
+        let c = mv[lane];
+        if c == F::ZERO {
+            continue;
+        }
+        match (first, c < F::ZERO) {
+            (true, true) => write!(f, "-")?,
+            (false, true) => write!(f, " - ")?,
+            (false, false) => write!(f, " + ")?,
+            (true, false) => {}
+        }
+        first = false;
+        let magnitude = c.abs();
+        if magnitude != F::ONE || name.is_empty() {
+            match f.precision() {
+                Some(p) => write!(f, "{magnitude:.p$}")?,
+                None => write!(f, "{magnitude}")?,
+            }
+        }
+        write!(f, "{name}")?;
+    }
+    if first {
+        write!(f, "0")?;
+    }
+    Ok(())
+}
+
+impl<F: Float, const K: u8, const N: usize> Display for KVector<K, N, F>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_terms(f, Multivector16::from(*self))
+    }
+}
+
+macro_rules! display_via_full {
+    ($($t:ident),*) => {
+        $(
+            impl<F: Float> Display for $t<F> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                    write_terms(f, Multivector16::from(*self))
+                }
+            }
+        )*
+    };
+}
+
+display_via_full!(
+    Pseudoscalar,
+    AnyKVector,
+    Motor,
+    OddVersor,
+    Versor,
+    Multivector16
+);
+
+impl<F: Float> FromStr for Multivector16<F> {
+    type Err = PgaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(sum(&terms(s)?))
+    }
+}
+
+impl<F: Float> FromStr for Versor<F> {
+    type Err = PgaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Versor::from(s.parse::<Multivector16<F>>()?))
+    }
+}
+
+impl<F: Float> FromStr for AnyKVector<F> {
+    type Err = PgaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let terms = terms(s)?;
+        let mv = sum(&terms);
+        AnyKVector::try_from(mv).map_err(|_| {
+            // Point at the first term of another grade than the first that doesn't cancel out
+            let mut kept = terms.iter().filter(|(_, lane, _)| mv[*lane] != F::ZERO);
+            let grade = kept.next().map(|(_, lane, _)| GRADES[*lane]);
+            let at = kept
+                .find(|(_, lane, _)| Some(GRADES[*lane]) != grade)
+                .map_or(0, |(at, _, _)| *at);
+            PgaError::Parse {
+                at,
+                expected: "a term of the same grade as the first",
+            }
+        })
+    }
+}
+
+// The signed terms of the text, as the byte each starts at, its lane and its coefficient
+fn terms<F: Float>(s: &str) -> Result<Vec<(usize, usize, F)>, PgaError> {
+    let mut parser = Parser { text: s, at: 0 };
+    let mut terms = vec![];
+    loop {
+        parser.skip_whitespace();
+        // The first term's sign is optional
+        let sign = match parser.peek() {
+            Some('+') => F::ONE,
+            Some('-') => -F::ONE,
+            None if !terms.is_empty() => return Ok(terms),
+            _ if terms.is_empty() => F::ONE,
+            _ => return Err(parser.error("`+` or `-`")),
+        };
+        if matches!(parser.peek(), Some('+' | '-')) {
+            parser.at += 1;
+            parser.skip_whitespace();
+        }
+        let start = parser.at;
+        let (lane, c) = parser.term()?;
+        terms.push((start, lane, sign * c));
+    }
+}
+
+fn sum<F: Float>(terms: &[(usize, usize, F)]) -> Multivector16<F> {
+    let mut mv = Multivector16::from([F::ZERO; 16]);
+    for (_, lane, c) in terms {
+        mv[*lane] += *c;
+    }
+    mv
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    at: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.at..].chars().next()
+    }
+
+    fn error(&self, expected: &'static str) -> PgaError {
+        PgaError::Parse {
+            at: self.at,
+            expected,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.at += c.len_utf8();
+        }
+    }
+
+    fn take_while(&mut self, pred: fn(char) -> bool) -> &str {
+        let start = self.at;
+        while self.peek().is_some_and(pred) {
+            self.at += 1;
+        }
+        &self.text[start..self.at]
+    }
+
+    // An unsigned `coefficient`, `coefficient blade`, `coefficient * blade` or `blade`, as its
+    // lane and coefficient
+    fn term<F: Float>(&mut self) -> Result<(usize, F), PgaError> {
+        let start = self.at;
+        let number = self.take_while(|c| c.is_ascii_digit() || c == '.');
+        let coefficient = match number {
+            "" => None,
+            n => Some(
+                n.parse::<f64>()
+                    .map(F::from_f64)
+                    .map_err(|_| PgaError::Parse {
+                        at: start,
+                        expected: "a number",
+                    })?,
+            ),
+        };
+        self.skip_whitespace();
+        let starred = coefficient.is_some() && self.peek() == Some('*');
+        if starred {
+            self.at += 1;
+            self.skip_whitespace();
+        }
+        if self.peek() != Some('e') {
+            return match coefficient {
+                Some(c) if !starred => Ok((0, c)),
+                Some(_) => Err(self.error("a basis blade")),
+                None => Err(self.error("a number or basis blade")),
+            };
+        }
+        let blade_start = self.at;
+        self.at += 1;
+        let digits = self.take_while(|c| c.is_ascii_digit()).len();
+        let name = &self.text[blade_start..blade_start + 1 + digits];
+        match BASIS_NAMES[1..].iter().position(|n| *n == name) {
+            Some(lane) => Ok((lane + 1, coefficient.unwrap_or(F::ONE))),
+            None => Err(PgaError::Parse {
+                at: blade_start,
+                expected: "a basis blade",
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display_kvectors() {
+        let v = Vector::from([2.0, 0.0, 0.0, -0.5]);
+        assert_eq!(v.to_string(), "2e1 - 0.5e0");
+        assert_eq!((-e31).to_string(), "-e31");
+        assert_eq!(
+            Trivector::point(1.0, 0.0, -2.0).to_string(),
+            "e123 - e032 + 2e021"
+        );
+        assert_eq!(Bivector::from([0.0; 6]).to_string(), "0");
+        assert_eq!(e0123.to_string(), "e0123");
+    }
+
+    #[test]
+    fn display_versors() {
+        let m = Motor::from([0.5, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 2.0]);
+        assert_eq!(m.to_string(), "0.5 - e01 + 2e0123");
+        let mixed = Motor::from(2.0) + e1;
+        assert_eq!(mixed.to_string(), "2 + e1");
+        let ov = OddVersor::from((e1 * 2.0, e123));
+        assert_eq!(ov.to_string(), "2e1 + e123");
+    }
+
+    #[test]
+    fn display_precision() {
+        let v = Vector::from([1.0 / 3.0, 0.0, 2.0, 0.0]);
+        assert_eq!(format!("{v:.2}"), "0.33e1 + 2.00e3");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "2e1 - 0.5e0 + e123".parse::<Versor>(),
+            Ok(Versor::from(Vector::from([2.0, 0.0, 0.0, -0.5])) + e123)
+        );
+        assert_eq!(
+            " -e12+3 * e0123 + 0.5".parse::<Versor>(),
+            Ok(Versor::Even(Motor::from([
+                0.5, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 3.0
+            ])))
+        );
+        assert_eq!("e1 + e1".parse::<AnyKVector>(), Ok((e1 * 2.0).into()));
+        assert_eq!("0".parse::<AnyKVector>(), Ok(AnyKVector::Zero(0.0)));
+        assert_eq!("-1.5".parse::<AnyKVector>(), Ok(AnyKVector::Zero(-1.5)));
+    }
+
+    #[test]
+    fn parse_f64() {
+        let v = "0.1e1 - 0.2e0".parse::<AnyKVector<f64>>().unwrap();
+        assert_eq!(v, Vector::from([0.1, 0.0, 0.0, -0.2]).into());
+        let tv = Trivector::<f64>::point(1.0 / 3.0, -2.0 / 7.0, 0.1);
+        assert_eq!(tv.to_string().parse::<AnyKVector<f64>>(), Ok(tv.into()));
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        let m = Bivector::from([0.1, 0.2, 0.3, 0.4, 0.5, 0.6]).exp();
+        assert_eq!(m.to_string().parse::<Versor>(), Ok(Versor::from(m)));
+        let tv = Trivector::point(-1.25, 3.0, 0.5);
+        assert_eq!(tv.to_string().parse::<AnyKVector>(), Ok(tv.into()));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |at, expected| PgaError::Parse { at, expected };
+        assert_eq!("2e4".parse::<Versor>(), Err(error(1, "a basis blade")));
+        assert_eq!("e21".parse::<Versor>(), Err(error(0, "a basis blade")));
+        assert_eq!("e1 e2".parse::<Versor>(), Err(error(3, "`+` or `-`")));
+        assert_eq!(
+            "e1 +".parse::<Versor>(),
+            Err(error(4, "a number or basis blade"))
+        );
+        assert_eq!("2 *".parse::<Versor>(), Err(error(3, "a basis blade")));
+        assert_eq!(
+            "".parse::<Versor>(),
+            Err(error(0, "a number or basis blade"))
+        );
+        assert_eq!("1.2.3".parse::<Versor>(), Err(error(0, "a number")));
+        let mixed = "a term of the same grade as the first";
+        assert_eq!("e1 + e12".parse::<AnyKVector>(), Err(error(5, mixed)));
+        assert_eq!(
+            "e12 - e12 + e1 + 2e3".parse::<AnyKVector>(),
+            Ok((e1 + e3 * 2.0).into())
+        );
+        assert_eq!(
+            "e12 + e1 - e12 + e0123".parse::<AnyKVector>(),
+            Err(error(17, mixed))
+        );
+    }
+
+    #[test]
+    fn basis_names_follow_constants() {
+        assert_eq!(BASIS_NAMES[1..5], ["e1", "e2", "e3", "e0"]);
+        assert_eq!(BASIS_NAMES[11..], ["e123", "e032", "e013", "e021", "e0123"]);
+        assert!(BASIS_NAMES[1..].iter().all(|n| !n.is_empty()));
+    }
+}
@@ -0,0 +1,511 @@
+use crate::pga_3::*;
+use std::ops::{Mul, Shl, Shr};
+
+// Affine maps as a linear part followed by a motor. The linear part scales and shears about the
+// origin, acting on (x, y, z) coordinates, and the motor then places the result rigidly, so a
+// point goes to `motor >> (linear * point)`. Each grade takes the linear part its own way:
+//
+//     points and directions    coordinates by L
+//     lines                    direction by L and moment by the cofactor matrix det(L) L^-T
+//     planes and normals       normal by L^-T, keeping the distance from the origin
+//
+// which keeps incidence, so the image of a join or meet is the join or meet of the images.
+
+type Matrix<F> = [[F; 3]; 3];
+
+const IDENTITY: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+#[derive(Debug, Copy, Clone)]
+pub struct Transform<F: Float = f32> {
+    map: Affine<F>,
+    // Kept alongside the map, since objects move every ray back into their own coordinates
+    inverse: Affine<F>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Affine<F: Float> {
+    motor: Motor<F>,
+    linear: Matrix<F>,
+    /// The inverse transpose of `linear`, which moves normals
+    normal: Matrix<F>,
+}
+
+impl<F: Float> Transform<F> {
+    /// Applies `linear`, a row-major matrix acting on coordinates, and then `motor`. Fails with
+    /// `PgaError::NonInvertible` if `linear` is singular.
+    #[inline]
+    pub fn new(motor: Motor<F>, linear: [[F; 3]; 3]) -> Result<Self, PgaError> {
+        let inverse = invert(linear).ok_or(PgaError::NonInvertible)?;
+        Ok(Self::with_inverse(motor, linear, inverse))
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::from(Motor::from(F::ONE))
+    }
+
+    /// Scaling by `x`, `y` and `z` along the axes, which must all be nonzero
+    #[inline]
+    pub fn scale(x: F, y: F, z: F) -> Result<Self, PgaError> {
+        Self::new(
+            Motor::from(F::ONE),
+            [
+                [x, F::ZERO, F::ZERO],
+                [F::ZERO, y, F::ZERO],
+                [F::ZERO, F::ZERO, z],
+            ],
+        )
+    }
+
+    /// Shearing each coordinate in proportion to the other two, eg `xy` moves x by `xy * y`.
+    /// Fails if the shear flattens space onto a plane.
+    #[inline]
+    pub fn shear(xy: F, xz: F, yx: F, yz: F, zx: F, zy: F) -> Result<Self, PgaError> {
+        Self::new(
+            Motor::from(F::ONE),
+            [[F::ONE, xy, xz], [yx, F::ONE, yz], [zx, zy, F::ONE]],
+        )
+    }
+
+    #[inline]
+    pub fn motor(&self) -> Motor<F> {
+        self.map.motor
+    }
+
+    /// Row-major matrix applied to coordinates before the motor
+    #[inline]
+    pub fn linear(&self) -> [[F; 3]; 3] {
+        self.map.linear
+    }
+
+    /// The transform undoing this one
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Self {
+            map: self.inverse,
+            inverse: self.map,
+        }
+    }
+
+    /// The same transform with its motor normalized
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let mut t = self;
+        t.map.motor = t.map.motor.normalize();
+        t.inverse.motor = t.inverse.motor.normalize();
+        t
+    }
+
+    /// The normal of a surface at a point, given the normal `n` at the matching point before the
+    /// transform, as a normalized plane through the origin
+    #[inline]
+    pub fn normal(&self, n: Vector<F>) -> Vector<F> {
+        let n = apply(self.map.normal, [n[0], n[1], n[2]]);
+        let mut n = self.map.motor >> Vector::from([n[0], n[1], n[2], F::ZERO]);
+        n[3] = F::ZERO;
+        n.normalize()
+    }
+
+    fn with_inverse(motor: Motor<F>, linear: Matrix<F>, inverse: Matrix<F>) -> Self {
+        // L^-1 M^-1 is the translation by L^-1 t' after the linear map L^-1 R' for the rotation
+        // R' and translation t' of M^-1, and (L^-1 R')^-T is L^T R'
+        let (rotation, translation) = split(motor.reverse());
+        Self {
+            map: Affine {
+                motor,
+                linear,
+                normal: transpose(inverse),
+            },
+            inverse: Affine {
+                motor: translate(apply(inverse, translation)),
+                linear: multiply(inverse, rotation),
+                normal: multiply(transpose(linear), rotation),
+            },
+        }
+    }
+}
+
+impl<F: Float> Affine<F> {
+    /// The map applying `self` and then `rhs`
+    #[inline]
+    fn then(self, rhs: Self) -> Self {
+        // rhs.linear moves the translation t of self.motor to L2 t, which joins rhs.motor, and
+        // the rotation R between the linear parts is its own inverse transpose
+        let (rotation, translation) = split(self.motor);
+        Self {
+            motor: translate(apply(rhs.linear, translation)) * rhs.motor,
+            linear: multiply(multiply(rhs.linear, rotation), self.linear),
+            normal: multiply(multiply(rhs.normal, rotation), self.normal),
+        }
+    }
+}
+
+/// Transforms are equal when they have the same motor and linear part
+impl<F: Float> PartialEq for Transform<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.map.motor == other.map.motor && self.map.linear == other.map.linear
+    }
+}
+
+impl<F: Float> Default for Transform<F> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<F: Float> From<Motor<F>> for Transform<F> {
+    #[inline]
+    fn from(motor: Motor<F>) -> Self {
+        let identity = IDENTITY.map(|row| row.map(F::from));
+        Self::with_inverse(motor, identity, identity)
+    }
+}
+
+/// A reflection as a mirroring linear part followed by the translation to where it takes the
+/// origin
+impl<F: Float> From<OddVersor<F>> for Transform<F> {
+    #[inline]
+    fn from(v: OddVersor<F>) -> Self {
+        let (linear, translation) = split(v);
+        Self::with_inverse(translate(translation), linear, transpose(linear))
+    }
+}
+
+impl<F: Float> From<Transformation<F>> for Transform<F> {
+    #[inline]
+    fn from(t: Transformation<F>) -> Self {
+        use Transformation::*;
+        match t {
+            Rotation { axis, angle } => Self::from(Motor::rotation(axis, angle)),
+            Translation { direction } => Self::from(Motor::translation(direction)),
+            Screw {
+                axis,
+                angle,
+                distance,
+            } => Self::from(Motor::screw(axis, angle, distance)),
+            Reflection { plane } => Self::from(OddVersor::reflection(plane)),
+            RotoReflection { axis, angle, plane } => {
+                Self::from(OddVersor::roto_reflection(axis, angle, plane))
+            }
+            Glide { plane, direction } => Self::from(OddVersor::glide(plane, direction)),
+        }
+    }
+}
+
+impl<F: Float> Mul for Transform<F> {
+    type Output = Self;
+
+    /// The transform applying `self` and then `rhs`, as motors compose
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            map: self.map.then(rhs.map),
+            inverse: rhs.inverse.then(self.inverse),
+        }
+    }
+}
+
+impl<F: Float> Shr<Trivector<F>> for Transform<F> {
+    type Output = Trivector<F>;
+
+    #[inline]
+    fn shr(self, rhs: Trivector<F>) -> Self::Output {
+        let p = apply(self.map.linear, [rhs[1], rhs[2], rhs[3]]);
+        self.map.motor >> Trivector::from([rhs[0], p[0], p[1], p[2]])
+    }
+}
+
+impl<F: Float> Shr<Bivector<F>> for Transform<F> {
+    type Output = Bivector<F>;
+
+    #[inline]
+    fn shr(self, rhs: Bivector<F>) -> Self::Output {
+        let (d, m) = (rhs.direction(), rhs.moment());
+        let d = apply(self.map.linear, [d[1], d[2], d[3]]);
+        let m = apply(cofactor(self.map.linear), [m[1], m[2], m[3]]);
+        self.map.motor
+            >> Bivector::from_plucker(
+                Trivector::from([F::ZERO, d[0], d[1], d[2]]),
+                Trivector::from([F::ZERO, m[0], m[1], m[2]]),
+            )
+    }
+}
+
+impl<F: Float> Shr<Vector<F>> for Transform<F> {
+    type Output = Vector<F>;
+
+    #[inline]
+    fn shr(self, rhs: Vector<F>) -> Self::Output {
+        let n = apply(self.map.normal, [rhs[0], rhs[1], rhs[2]]);
+        self.map.motor >> Vector::from([n[0], n[1], n[2], rhs[3]])
+    }
+}
+
+impl Shr<TrivectorX8> for Transform {
+    type Output = TrivectorX8;
+
+    #[inline]
+    fn shr(self, rhs: TrivectorX8) -> Self::Output {
+        let p = apply_packet(self.map.linear, [rhs[1], rhs[2], rhs[3]]);
+        MotorX8::splat(self.map.motor) >> TrivectorX8::from([rhs[0], p[0], p[1], p[2]])
+    }
+}
+
+macro_rules! transform_inverse {
+    (@one [$($generics:tt)*] $f:ty; $t:ty) => {
+        impl<$($generics)*> Shl<$t> for Transform<$f> {
+            type Output = $t;
+
+            /// Applies the inverse transform
+            #[inline]
+            fn shl(self, rhs: $t) -> Self::Output {
+                Shr::shr(self.inverse(), rhs)
+            }
+        }
+    };
+    ($generics:tt $f:ty; $($t:ty),*) => {
+        $(transform_inverse!(@one $generics $f; $t);)*
+    };
+}
+
+transform_inverse!([F: Float] F; Trivector<F>, Bivector<F>, Vector<F>);
+transform_inverse!([] f32; TrivectorX8);
+
+// The rotation matrix and translation of a motor, or the mirroring matrix and translation of an
+// odd versor, from where it takes the origin and the axes
+fn split<F: Float, V: Shr<Trivector<F>, Output = Trivector<F>> + Copy>(
+    m: V,
+) -> (Matrix<F>, [F; 3]) {
+    let origin = (m >> *Blade::<Trivector<F>>::e123).normalize();
+    let axes = [
+        Trivector::direction(F::ONE, F::ZERO, F::ZERO),
+        Trivector::direction(F::ZERO, F::ONE, F::ZERO),
+        Trivector::direction(F::ZERO, F::ZERO, F::ONE),
+    ]
+    .map(|d| m >> d);
+    (
+        std::array::from_fn(|i| axes.map(|axis| -axis[i + 1])),
+        [origin.x(), origin.y(), origin.z()],
+    )
+}
+
+#[inline]
+fn translate<F: Float>(t: [F; 3]) -> Motor<F> {
+    Motor::trans_coords(t[0], t[1], t[2])
+}
+
+#[inline]
+fn apply<F: Float>(m: Matrix<F>, v: [F; 3]) -> [F; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+#[inline]
+fn apply_packet(m: Matrix<f32>, v: [FloatX8; 3]) -> [FloatX8; 3] {
+    m.map(|row| {
+        FloatX8::splat(row[0]) * v[0]
+            + FloatX8::splat(row[1]) * v[1]
+            + FloatX8::splat(row[2]) * v[2]
+    })
+}
+
+#[inline]
+fn multiply<F: Float>(a: Matrix<F>, b: Matrix<F>) -> Matrix<F> {
+    a.map(|row| std::array::from_fn(|j| row[0] * b[0][j] + row[1] * b[1][j] + row[2] * b[2][j]))
+}
+
+#[inline]
+fn transpose<F: Float>(m: Matrix<F>) -> Matrix<F> {
+    std::array::from_fn(|i| m.map(|row| row[i]))
+}
+
+// det(m) m^-T, which exists even when m is singular
+#[inline]
+fn cofactor<F: Float>(m: Matrix<F>) -> Matrix<F> {
+    std::array::from_fn(|i| {
+        std::array::from_fn(|j| {
+            let (i1, i2, j1, j2) = ((i + 1) % 3, (i + 2) % 3, (j + 1) % 3, (j + 2) % 3);
+            m[i1][j1] * m[i2][j2] - m[i1][j2] * m[i2][j1]
+        })
+    })
+}
+
+fn invert<F: Float>(m: Matrix<F>) -> Option<Matrix<F>> {
+    let c = cofactor(m);
+    let det = m[0][0] * c[0][0] + m[0][1] * c[0][1] + m[0][2] * c[0][2];
+    if det.abs() <= F::MIN_POSITIVE {
+        return None;
+    }
+    Some(transpose(c).map(|row| row.map(|f| f / det)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::float_eq;
+    use std::f32::consts::PI;
+
+    fn skewed() -> Transform {
+        Transform::scale(2.0, 0.5, 3.0).unwrap()
+            * Transform::shear(0.5, 0.0, 0.0, 1.0, -0.5, 0.0).unwrap()
+            * Transform::from(Transformation::rotation(Blade::e31, PI / 3.0))
+            * Transform::from(Transformation::trans_coords(1.0, -2.0, 0.5))
+    }
+
+    #[test]
+    fn scale_and_shear_points() {
+        let p: Trivector = Trivector::point(1.0, 2.0, 3.0);
+        assert_eq!(
+            (Transform::scale(2.0, 3.0, 4.0).unwrap() >> p).normalize(),
+            Trivector::point(2.0, 6.0, 12.0)
+        );
+        assert_eq!(
+            Transform::shear(1.0, 0.0, 0.0, 0.0, 0.0, 2.0).unwrap() >> p,
+            Trivector::point(3.0, 2.0, 7.0)
+        );
+        // Directions aren't moved by the translation
+        let t = Transform::scale(2.0, 3.0, 4.0).unwrap()
+            * Transform::from(Transformation::trans_coords(5.0, 0.0, 0.0));
+        assert_eq!(
+            t >> Trivector::direction(1.0, 1.0, 1.0),
+            Trivector::direction(2.0, 3.0, 4.0)
+        );
+        assert_eq!((t >> p).normalize(), Trivector::point(7.0, 6.0, 12.0));
+    }
+
+    #[test]
+    fn composition_applies_left_to_right() {
+        let (a, b) = (
+            Transform::scale(1.0, 2.0, 1.0).unwrap(),
+            Transform::from(Transformation::rotation(Blade::e12, PI / 2.0)),
+        );
+        let p = Trivector::point(1.0, 1.0, -1.0);
+        assert_eq!((a * b) >> p, b >> (a >> p));
+        assert_eq!((b * a) >> p, a >> (b >> p));
+        let t = skewed();
+        assert_eq!((t * skewed()) >> p, t >> (t >> p));
+        // Motors compose as before
+        let (m1, m2) = (
+            Motor::rotation(Blade::e23, 0.4),
+            Motor::trans_coords(1.0, 2.0, 3.0),
+        );
+        assert_eq!(
+            (Transform::from(m1) * Transform::from(m2)) >> p,
+            (m1 * m2) >> p
+        );
+    }
+
+    #[test]
+    fn inverse_in_f64() {
+        let t = Transform::<f64>::shear(0.5, 0.0, 0.0, 1.0, -0.5, 0.0).unwrap()
+            * Transform::from(Transformation::trans_coords(1e4, -2e4, 0.5));
+        let p = Trivector::point(0.1, -0.2, 0.3);
+        assert_eq!((t << (t >> p)).normalize(), p);
+        assert_eq!(
+            Transform::<f64>::scale(1.0, 0.0, 1.0),
+            Err(PgaError::NonInvertible)
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let t = skewed();
+        let p = Trivector::point(0.5, -1.0, 2.0);
+        assert_eq!((t << (t >> p)).normalize(), p);
+        assert_eq!(((t.inverse() * t) >> p).normalize(), p);
+        assert_eq!(
+            Transform::scale(1.0, 0.0, 1.0),
+            Err(PgaError::NonInvertible)
+        );
+        assert_eq!(
+            Transform::shear(1.0, 0.0, 1.0, 0.0, 0.0, 0.0),
+            Err(PgaError::NonInvertible)
+        );
+        // The cached inverse follows composition
+        let u = Transform::shear(0.0, 0.5, 0.0, 0.0, 0.0, 0.0).unwrap()
+            * Transform::from(Transformation::rotation(Blade::e12, 0.5));
+        assert_eq!(((t * u).inverse() >> (u >> (t >> p))).normalize(), p);
+        let n = Vector::from([1.0, -2.0, 0.5, 3.0]);
+        assert_eq!((t << (t >> n)).normalize(), n.normalize());
+    }
+
+    #[test]
+    fn lines_and_planes_keep_incidence() {
+        let t = skewed();
+        let (a, b, c) = (
+            Trivector::point(1.0, 0.0, 2.0),
+            Trivector::point(-1.0, 3.0, 0.5),
+            Trivector::point(0.0, 1.0, -1.0),
+        );
+        let line = (a & b).assert::<Bivector>();
+        let expected = ((t >> a) & (t >> b)).assert::<Bivector>();
+        let moved = t >> line;
+        assert!(float_eq(
+            (moved.normalize() - expected.normalize()).magnitude(),
+            0.0
+        ));
+
+        let plane = (line & c).assert::<Vector>();
+        let moved = (t >> plane).normalize();
+        for p in [a, b, c] {
+            assert!(float_eq((t >> p).distance_to_plane(moved), 0.0));
+        }
+        // The ideal line of a plane family follows the planes
+        let ideal = (plane ^ e0).assert::<Bivector>();
+        let expected = ((t >> plane) ^ e0).assert::<Bivector>();
+        let moved = t >> ideal;
+        assert_eq!(moved * (expected[3] / moved[3]), expected);
+    }
+
+    #[test]
+    fn normals() {
+        // Squashing a sphere along y tilts the normals towards y
+        let t: Transform = Transform::scale(1.0, 0.5, 1.0).unwrap();
+        let n = Vector::from([1.0, 1.0, 0.0, 0.0]).normalize();
+        let expected = Vector::from([1.0, 2.0, 0.0, 0.0]).normalize();
+        assert_eq!(t.normal(n), expected);
+        let t = t * Transform::from(Transformation::trans_coords(3.0, 0.0, 0.0));
+        assert_eq!(t.normal(n), expected);
+    }
+
+    #[test]
+    fn reflections() {
+        let mirror = Transformation::glide(
+            Vector::from([1.0, 1.0, 0.0, -1.0]),
+            Trivector::direction(0.0, 0.0, 2.0),
+        );
+        let (t, v) = (
+            Transform::from(mirror),
+            OddVersor::try_from(mirror).unwrap(),
+        );
+        let (a, b) = (
+            Trivector::point(1.0, 2.0, 3.0),
+            Trivector::point(-1.0, 0.5, 0.0),
+        );
+        assert_eq!((t >> a).normalize(), (v >> a).normalize());
+        assert_eq!((t << (t >> a)).normalize(), a);
+        let line = (a & b).assert::<Bivector>();
+        assert_eq!((t >> line).normalize(), (v >> line).normalize());
+        let plane = Vector::from([0.0, 2.0, -1.0, 0.5]);
+        assert_eq!((t >> plane).normalize(), (v >> plane).normalize());
+        // Mirroring twice is rigid again, and composes with the rest
+        let twice = t * t;
+        assert_eq!((twice >> a).normalize(), (v >> (v >> a)).normalize());
+        let s = skewed() * t;
+        assert_eq!((s >> a).normalize(), (t >> (skewed() >> a)).normalize());
+    }
+
+    #[test]
+    fn packets_match_points() {
+        let t = skewed();
+        let points: [Trivector; LANES] =
+            std::array::from_fn(|i| Trivector::point(i as f32, 1.0 - i as f32, 0.5));
+        let moved = t >> TrivectorX8::from(points);
+        let back = t << moved;
+        for (i, p) in points.iter().enumerate() {
+            assert_eq!(moved.lane(i).normalize(), (t >> *p).normalize());
+            assert_eq!(back.lane(i).normalize(), *p);
+        }
+    }
+}
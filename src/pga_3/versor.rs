@@ -4,72 +4,108 @@ pub use odd_versor::*;
 use std::ops::{Div, Mul, Neg};
 use std::simd::{LaneCount, SupportedLaneCount};
 
+mod mixed;
 mod motor;
 mod odd_versor;
+mod products;
 
-const VERSOR_ZERO_EPSILON: f32 = crate::util::EPSILON * 10.0;
+#[inline]
+fn versor_zero_epsilon<F: Float>() -> F {
+    F::EPSILON * F::from(10.0)
+}
 
 #[derive(Debug, Copy, Clone)]
-pub enum Versor {
-    Even(Motor),
-    Odd(OddVersor),
-    KVec(AnyKVector),
+pub enum Versor<F: Float = f32> {
+    Even(Motor<F>),
+    Odd(OddVersor<F>),
+    KVec(AnyKVector<F>),
+    Mixed(Multivector16<F>),
+}
+
+// Sandwiching a single-grade element in a rigid motion or reflection keeps its grade, but motors
+// and odd versors that aren't products of planes can add other grades, eg the sum of a scalar and
+// a pseudoscalar maps a plane to a plane plus an ideal point
+fn sandwiched<F: Float, T: SingleGrade<Pga3<F>> + TryFrom<Multivector16<F>>>(
+    product: Versor<F>,
+) -> Result<T, PgaError> {
+    // The other grades cancel up to a rounding error relative to the size of the product
+    let mut mv = Multivector16::from(product);
+    let scale = (0..16).fold(F::ZERO, |acc, i| acc.max(mv[i].abs()));
+    for i in 0..16 {
+        if mv[i].abs() <= scale * F::EPSILON {
+            mv[i] = F::ZERO;
+        }
+    }
+    T::try_from(mv).map_err(|_| PgaError::GradeMismatch {
+        expected: T::GRADE,
+        found: AnyKVector::try_from(mv).ok().map(|kv| kv.highest_grade()),
+    })
+}
+
+// The part of a sandwich product with the grade of the sandwiched element, for the operators,
+// which can't fail
+fn projected<F: Float, T: TryFrom<Multivector16<F>>>(product: Versor<F>, grade: u8) -> T {
+    let part = Multivector16::from(Multivector16::from(product).grade(grade));
+    match T::try_from(part) {
+        Ok(res) => res,
+        Err(_) => unreachable!("A single grade converts to the type of that grade"),
+    }
 }
 
-fn is_zero(slice: &[f32]) -> bool {
-    slice.iter().fold(true, |acc, f| acc && *f == 0.0)
+fn is_zero<F: Float>(slice: &[F]) -> bool {
+    slice.iter().fold(true, |acc, f| acc && *f == F::ZERO)
 }
 
-fn is_zero_rounding(slice: &[f32]) -> bool {
+fn is_zero_rounding<F: Float>(slice: &[F]) -> bool {
     slice.iter().fold(true, |acc, f| {
-        acc && f.abs() < VERSOR_ZERO_EPSILON || f.is_nan() || f.is_infinite()
+        acc && f.abs() < versor_zero_epsilon() || f.is_nan() || f.is_infinite()
     })
 }
 
-impl From<AnyKVector> for Versor {
+impl<F: Float> From<AnyKVector<F>> for Versor<F> {
     #[inline]
-    fn from(kv: AnyKVector) -> Self {
+    fn from(kv: AnyKVector<F>) -> Self {
         Self::KVec(kv)
     }
 }
 
-impl<const K: u8, const N: usize> From<KVector<K, N>> for Versor
+impl<F: Float, const K: u8, const N: usize> From<KVector<K, N, F>> for Versor<F>
 where
     LaneCount<N>: SupportedLaneCount,
 {
     #[inline]
-    fn from(kv: KVector<K, N>) -> Self {
+    fn from(kv: KVector<K, N, F>) -> Self {
         /*
         if !is_zero(&kv[0..N]) {
         */
         Self::KVec(kv.into())
         /*
         } else {
-            Self::KVec(AnyKVector::Zero(0.0))
+            Self::KVec(AnyKVector::Zero(F::ZERO))
         }
         */
     }
 }
 
-impl From<Scalar> for Versor {
+impl<F: Float> From<F> for Versor<F> {
     #[inline]
-    fn from(s: Scalar) -> Self {
+    fn from(s: F) -> Self {
         Versor::KVec(s.into())
     }
 }
 
-impl From<Pseudoscalar> for Versor {
+impl<F: Float> From<Pseudoscalar<F>> for Versor<F> {
     #[inline]
-    fn from(ps: Pseudoscalar) -> Self {
+    fn from(ps: Pseudoscalar<F>) -> Self {
         Versor::KVec(ps.into())
     }
 }
 
-impl From<OddVersor> for Versor {
+impl<F: Float> From<OddVersor<F>> for Versor<F> {
     #[inline]
-    fn from(ov: OddVersor) -> Self {
+    fn from(ov: OddVersor<F>) -> Self {
         if ov.zero() {
-            Versor::KVec(0.0.into())
+            Versor::KVec(F::ZERO.into())
         } else if is_zero(&ov[0..4]) {
             Versor::KVec(Trivector::from([ov[4], ov[5], ov[6], ov[7]]).into())
         } else if is_zero(&ov[4..8]) {
@@ -80,15 +116,36 @@ impl From<OddVersor> for Versor {
     }
 }
 
-impl From<Motor> for Versor {
+impl<F: Float> From<Transformation<F>> for Versor<F> {
+    #[inline]
+    fn from(t: Transformation<F>) -> Self {
+        use Transformation::*;
+        match t {
+            Rotation { axis, angle } => Versor::from(Motor::rotation(axis, angle)),
+            Translation { direction } => Versor::from(Motor::translation(direction)),
+            Screw {
+                axis,
+                angle,
+                distance,
+            } => Versor::from(Motor::screw(axis, angle, distance)),
+            Reflection { plane } => Versor::from(OddVersor::reflection(plane)),
+            RotoReflection { axis, angle, plane } => {
+                Versor::from(OddVersor::roto_reflection(axis, angle, plane))
+            }
+            Glide { plane, direction } => Versor::from(OddVersor::glide(plane, direction)),
+        }
+    }
+}
+
+impl<F: Float> From<Motor<F>> for Versor<F> {
     #[inline]
-    fn from(m: Motor) -> Self {
+    fn from(m: Motor<F>) -> Self {
         use crate::util::float_eq;
         if is_zero(&m[1..8]) {
             Versor::KVec(m[0].into())
         } else if is_zero(&m[0..7]) {
             Versor::KVec(Pseudoscalar(m[7]).into())
-        } else if float_eq(m[0], 0.0) && float_eq(m[7], 0.0) {
+        } else if float_eq(m[0], F::ZERO) && float_eq(m[7], F::ZERO) {
             Versor::KVec(Bivector::from([m[1], m[2], m[3], m[4], m[5], m[6]]).into())
         } else {
             Versor::Even(m)
@@ -96,7 +153,18 @@ impl From<Motor> for Versor {
     }
 }
 
-impl PartialEq for Versor {
+impl<F: Float> From<Multivector16<F>> for Versor<F> {
+    #[inline]
+    fn from(mv: Multivector16<F>) -> Self {
+        match (Motor::try_from(mv), OddVersor::try_from(mv)) {
+            (Ok(m), _) => Versor::from(m),
+            (_, Ok(ov)) => Versor::from(ov),
+            _ => Versor::Mixed(mv),
+        }
+    }
+}
+
+impl<F: Float> PartialEq for Versor<F> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         use Versor::*;
@@ -104,12 +172,13 @@ impl PartialEq for Versor {
             (Odd(ov1), Odd(ov2)) => ov1 == ov2,
             (Even(m1), Even(m2)) => m1 == m2,
             (KVec(kv1), KVec(kv2)) => kv1 == kv2,
+            (Mixed(mv1), Mixed(mv2)) => mv1 == mv2,
             _ => false,
         }
     }
 }
 
-impl Neg for Versor {
+impl<F: Float> Neg for Versor<F> {
     type Output = Self;
     #[inline]
     fn neg(self) -> Self::Output {
@@ -118,62 +187,108 @@ impl Neg for Versor {
             Odd(ov) => Odd(-ov),
             Even(m) => Even(-m),
             KVec(kv) => KVec(-kv),
+            Mixed(mv) => Mixed(-mv),
         }
     }
 }
 
-impl Mul<Scalar> for Versor {
+impl<F: Float> Mul<F> for Versor<F> {
     type Output = Self;
     #[inline]
-    fn mul(self, rhs: Scalar) -> Self::Output {
+    fn mul(self, rhs: F) -> Self::Output {
         use Versor::*;
         match self {
             Odd(ov) => Odd(ov * rhs),
             Even(m) => Even(m * rhs),
             KVec(kv) => KVec(kv * rhs),
+            Mixed(mv) => Mixed(mv * rhs),
         }
     }
 }
 
-impl<T: Multivector + NonScalar> Mul<T> for Versor {
-    type Output = Versor;
-    #[inline]
-    fn mul(self, rhs: T) -> Versor {
-        self.geo(rhs)
-    }
+// `Mul<T: NonScalar>` would overlap `Mul<F>` for a generic float, so the geometric product is
+// implemented for each pair of types. Motors and odd versors multiply each other into a known
+// type, but anything else goes through the general geometric product
+macro_rules! mul_via_geo {
+    (@each $lhs:ty, $generics:tt, $bounds:tt; $($rhs:ty),*) => {
+        $(mul_via_geo!(@one $lhs, $generics, $bounds, $rhs);)*
+    };
+    (@one $lhs:ty, [$($generics:tt)*], [$($bounds:tt)*], $rhs:ty) => {
+        impl<F: Float, $($generics)*> Mul<$rhs> for $lhs
+        where
+            $($bounds)*
+        {
+            type Output = Versor<F>;
+            #[inline]
+            fn mul(self, rhs: $rhs) -> Versor<F> {
+                self.geo(rhs)
+            }
+        }
+    };
+    ($lhs:ty $(, $k:ident, $n:ident)?; $($rhs:ty),*) => {
+        impl<F: Float, $(const $k: u8, const $n: usize,)? const K: u8, const N: usize>
+            Mul<KVector<K, N, F>> for $lhs
+        where
+            LaneCount<N>: SupportedLaneCount,
+            $(LaneCount<$n>: SupportedLaneCount,)?
+        {
+            type Output = Versor<F>;
+            #[inline]
+            fn mul(self, rhs: KVector<K, N, F>) -> Versor<F> {
+                self.geo(rhs)
+            }
+        }
+
+        mul_via_geo!(
+            @each $lhs,
+            [$(const $k: u8, const $n: usize)?],
+            [$(LaneCount<$n>: SupportedLaneCount)?];
+            $($rhs),*
+        );
+    };
 }
 
-impl Div<Scalar> for Versor {
+mul_via_geo!(KVector<J, M, F>, J, M; Pseudoscalar<F>, AnyKVector<F>, Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+mul_via_geo!(Pseudoscalar<F>; Pseudoscalar<F>, AnyKVector<F>, Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+mul_via_geo!(AnyKVector<F>; Pseudoscalar<F>, AnyKVector<F>, Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+mul_via_geo!(Versor<F>; Pseudoscalar<F>, AnyKVector<F>, Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+mul_via_geo!(Motor<F>; Pseudoscalar<F>, AnyKVector<F>, Versor<F>, Multivector16<F>);
+mul_via_geo!(OddVersor<F>; Pseudoscalar<F>, AnyKVector<F>, Versor<F>, Multivector16<F>);
+
+impl<F: Float> Div<F> for Versor<F> {
     type Output = Self;
     #[inline]
-    fn div(self, rhs: Scalar) -> Self::Output {
+    fn div(self, rhs: F) -> Self::Output {
         use Versor::*;
         match self {
             Odd(ov) => Odd(ov / rhs),
             Even(m) => Even(m / rhs),
             KVec(kv) => KVec(kv / rhs),
+            Mixed(mv) => Mixed(mv / rhs),
         }
     }
 }
 
-impl Multivector for Versor {
+impl<F: Float> Multivector<Pga3<F>> for Versor<F> {
     #[inline]
-    fn e(&self, basis: u8) -> f32 {
+    fn e(&self, basis: u8) -> F {
         use Versor::*;
         match self {
             Odd(ov) => ov.e(basis),
             Even(m) => m.e(basis),
             KVec(kv) => kv.e(basis),
+            Mixed(mv) => mv.e(basis),
         }
     }
 
     #[inline]
-    fn grade(&self, g: u8) -> AnyKVector {
+    fn grade(&self, g: u8) -> AnyKVector<F> {
         use Versor::*;
         match self {
             Odd(ov) => ov.grade(g),
             Even(m) => m.grade(g),
             KVec(kv) => kv.grade(g),
+            Mixed(mv) => mv.grade(g),
         }
     }
 
@@ -184,6 +299,7 @@ impl Multivector for Versor {
             Odd(ov) => ov.highest_grade(),
             Even(m) => m.highest_grade(),
             KVec(kv) => kv.highest_grade(),
+            Mixed(mv) => mv.highest_grade(),
         }
     }
 
@@ -194,6 +310,7 @@ impl Multivector for Versor {
             Odd(ov) => ov.zero(),
             Even(m) => m.zero(),
             KVec(kv) => kv.zero(),
+            Mixed(mv) => mv.zero(),
         }
     }
 
@@ -204,6 +321,7 @@ impl Multivector for Versor {
             Odd(ov) => ov.is_ideal(),
             Even(m) => m.is_ideal(),
             KVec(kv) => kv.is_ideal(),
+            Mixed(mv) => mv.is_ideal(),
         }
     }
 
@@ -214,6 +332,7 @@ impl Multivector for Versor {
             Odd(ov) => Odd(ov.reverse()),
             Even(m) => Even(m.reverse()),
             KVec(kv) => KVec(kv.reverse()),
+            Mixed(mv) => Mixed(mv.reverse()),
         }
     }
 
@@ -224,6 +343,7 @@ impl Multivector for Versor {
             Odd(ov) => Odd(ov.normalize()),
             Even(m) => Even(m.normalize()),
             KVec(kv) => KVec(kv.normalize()),
+            Mixed(mv) => Mixed(mv.normalize()),
         }
     }
 
@@ -234,88 +354,104 @@ impl Multivector for Versor {
             Odd(ov) => Odd(-*ov),
             Even(m) => Even(*m),
             KVec(kv) => KVec(kv.grade_involution()),
+            Mixed(mv) => Mixed(mv.grade_involution()),
         }
     }
 
     #[inline]
-    fn dual(self) -> Versor {
+    fn dual(self) -> Versor<F> {
         use Versor::*;
         match self {
             Odd(ov) => ov.dual(),
             Even(m) => m.dual(),
             KVec(kv) => kv.dual(),
+            Mixed(mv) => mv.dual(),
         }
     }
 
     #[inline]
-    fn undual(self) -> Versor {
+    fn undual(self) -> Versor<F> {
         use Versor::*;
         match self {
             Odd(ov) => ov.undual(),
             Even(m) => m.undual(),
             KVec(kv) => kv.undual(),
+            Mixed(mv) => mv.undual(),
         }
     }
 
     #[inline]
-    fn geo<T: Multivector>(self, rhs: T) -> Versor {
+    fn geo<T: Multivector<Pga3<F>>>(self, rhs: T) -> Versor<F> {
         use Versor::*;
         match self {
             Odd(ov) => ov.geo(rhs),
             Even(m) => m.geo(rhs),
             KVec(kv) => kv.geo(rhs),
+            Mixed(mv) => mv.geo(rhs),
         }
     }
 }
 
-impl Versor {
-    pub fn assert<T: SingleGrade + 'static>(&self) -> T {
+impl<F: Float> Versor<F> {
+    pub fn assert<T: SingleGrade<Pga3<F>> + 'static>(&self) -> T {
         use Versor::*;
         match self {
             Even(_) => panic!("Assert failed: motor not kvector"),
             Odd(_) => panic!("Assert failed: oddvector not kvector"),
+            Mixed(_) => panic!("Assert failed: mixed multivector not kvector"),
             KVec(kv) => kv.assert::<T>(),
         }
     }
 
+    pub fn try_assert<T: SingleGrade<Pga3<F>> + 'static>(&self) -> Result<T, PgaError> {
+        match self {
+            Versor::KVec(kv) => kv.try_assert::<T>(),
+            _ => Err(PgaError::GradeMismatch {
+                expected: T::GRADE,
+                found: None,
+            }),
+        }
+    }
+
     pub fn snap(self) -> Self {
         use Versor::*;
         match self {
             Even(m) => Self::from_motor_rounding(m),
             Odd(ov) => Self::from_oddversor_rounding(ov),
+            Mixed(mv) => Self::from_multivector_rounding(mv),
             KVec(_) => self, /*
                              match kv {
                              Zero(s) => {
-                                 if s.abs() < VERSOR_ZERO_EPSILON {
-                                     Self::from(0.0)
+                                 if s.abs() < versor_zero_epsilon() {
+                                     Self::from(F::ZERO)
                                  } else {
                                      self
                                  }
                              }
                              One(v) => {
                                  if is_zero_rounding(&v[0..4]) {
-                                     Self::from(Vector::from([0.0; 4]))
+                                     Self::from(Vector::from([F::ZERO; 4]))
                                  } else {
                                      self
                                  }
                              }
                              Two(bv) => {
                                  if is_zero_rounding(&bv[0..6]) {
-                                     Self::from(Bivector::from([0.0; 6]))
+                                     Self::from(Bivector::from([F::ZERO; 6]))
                                  } else {
                                      self
                                  }
                              }
                              Three(tv) => {
                                  if is_zero_rounding(&tv[0..4]) {
-                                     KVec(Three(Trivector::from([0.0; 4])))
+                                     KVec(Three(Trivector::from([F::ZERO; 4])))
                                  } else {
                                      self
                                  }
                              }
                              Four(ps) => {
-                                 if ps.0.abs() < VERSOR_ZERO_EPSILON {
-                                     Self::from(Pseudoscalar(0.0))
+                                 if ps.0.abs() < versor_zero_epsilon() {
+                                     Self::from(Pseudoscalar(F::ZERO))
                                  } else {
                                      self
                                  }
@@ -324,7 +460,7 @@ impl Versor {
         }
     }
 
-    pub fn from_motor_rounding(m: Motor) -> Self {
+    pub fn from_motor_rounding(m: Motor<F>) -> Self {
         use AnyKVector::*;
         use Versor::*;
         if is_zero_rounding(&m[1..8]) {
@@ -338,7 +474,7 @@ impl Versor {
         }
     }
 
-    pub fn from_oddversor_rounding(ov: OddVersor) -> Self {
+    pub fn from_oddversor_rounding(ov: OddVersor<F>) -> Self {
         use AnyKVector::*;
         use Versor::*;
         if is_zero_rounding(&ov[0..4]) {
@@ -349,4 +485,13 @@ impl Versor {
             Versor::Odd(ov)
         }
     }
+
+    pub fn from_multivector_rounding(mut mv: Multivector16<F>) -> Self {
+        for i in 0..16 {
+            if is_zero_rounding(&[mv[i]]) {
+                mv[i] = F::ZERO;
+            }
+        }
+        Self::from(mv)
+    }
 }
@@ -0,0 +1,356 @@
+use crate::pga_3::*;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Sub};
+use std::simd::{LaneCount, SupportedLaneCount};
+
+// Sums of different grades, and the outer, regressive and inner products of anything that isn't a
+// single k-vector, are found on the full multivector and narrowed back down to a versor
+
+macro_rules! via_full {
+    ($trait:ident, $method:ident, $op:tt; KVector; $($rhs:ty),*) => {
+        $(
+            impl<F: Float, const K: u8, const N: usize> $trait<$rhs> for KVector<K, N, F>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                type Output = Versor<F>;
+                #[inline]
+                fn $method(self, rhs: $rhs) -> Versor<F> {
+                    Versor::from(Multivector16::from(self) $op Multivector16::from(rhs))
+                }
+            }
+        )*
+    };
+    ($trait:ident, $method:ident, $op:tt; $lhs:ty; KVector) => {
+        impl<F: Float, const K: u8, const N: usize> $trait<KVector<K, N, F>> for $lhs
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = Versor<F>;
+            #[inline]
+            fn $method(self, rhs: KVector<K, N, F>) -> Versor<F> {
+                Versor::from(Multivector16::from(self) $op Multivector16::from(rhs))
+            }
+        }
+    };
+    ($trait:ident, $method:ident, $op:tt; $lhs:ty; $($rhs:ty),*) => {
+        $(
+            impl<F: Float> $trait<$rhs> for $lhs {
+                type Output = Versor<F>;
+                #[inline]
+                fn $method(self, rhs: $rhs) -> Versor<F> {
+                    Versor::from(Multivector16::from(self) $op Multivector16::from(rhs))
+                }
+            }
+        )*
+    };
+}
+
+// Motors, odd versors and versors take any right-hand side in the three products
+macro_rules! products_via_full {
+    ($($lhs:ty),*) => {
+        $(
+            impl<F: Float, T: Multivector<Pga3<F>>> BitXor<T> for $lhs {
+                type Output = Versor<F>;
+                #[inline]
+                fn bitxor(self, rhs: T) -> Versor<F> {
+                    Versor::from(Multivector16::from(self) ^ rhs)
+                }
+            }
+
+            impl<F: Float, T: Multivector<Pga3<F>>> BitAnd<T> for $lhs {
+                type Output = Versor<F>;
+                #[inline]
+                fn bitand(self, rhs: T) -> Versor<F> {
+                    Versor::from(Multivector16::from(self) & rhs)
+                }
+            }
+
+            impl<F: Float, T: Multivector<Pga3<F>>> BitOr<T> for $lhs {
+                type Output = Versor<F>;
+                #[inline]
+                fn bitor(self, rhs: T) -> Versor<F> {
+                    Versor::from(Multivector16::from(self) | rhs)
+                }
+            }
+        )*
+    };
+}
+
+products_via_full!(Motor<F>, OddVersor<F>, Versor<F>);
+
+via_full!(BitXor, bitxor, ^; KVector; Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+via_full!(BitAnd, bitand, &; KVector; Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+via_full!(BitOr, bitor, |; KVector; Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+via_full!(BitXor, bitxor, ^; AnyKVector<F>; Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+via_full!(BitAnd, bitand, &; AnyKVector<F>; Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+via_full!(BitOr, bitor, |; AnyKVector<F>; Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+via_full!(BitXor, bitxor, ^; Pseudoscalar<F>; Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+via_full!(BitAnd, bitand, &; Pseudoscalar<F>; Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+via_full!(BitOr, bitor, |; Pseudoscalar<F>; Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+
+impl<F: Float, T: Multivector<Pga3<F>>> Add<T> for Versor<F> {
+    type Output = Versor<F>;
+    #[inline]
+    fn add(self, rhs: T) -> Versor<F> {
+        Versor::from(Multivector16::from(self) + rhs)
+    }
+}
+
+impl<F: Float, T: Multivector<Pga3<F>>> Sub<T> for Versor<F> {
+    type Output = Versor<F>;
+    #[inline]
+    fn sub(self, rhs: T) -> Versor<F> {
+        Versor::from(Multivector16::from(self) - rhs)
+    }
+}
+
+impl<F: Float, T: Multivector<Pga3<F>>> Add<T> for AnyKVector<F> {
+    type Output = Versor<F>;
+    #[inline]
+    fn add(self, rhs: T) -> Versor<F> {
+        Versor::from(Multivector16::from(self) + rhs)
+    }
+}
+
+impl<F: Float, T: Multivector<Pga3<F>>> Sub<T> for AnyKVector<F> {
+    type Output = Versor<F>;
+    #[inline]
+    fn sub(self, rhs: T) -> Versor<F> {
+        Versor::from(Multivector16::from(self) - rhs)
+    }
+}
+
+// Every other type already adds to itself, so sums with each of the others are listed out
+macro_rules! sums_via_full {
+    (KVector; $($rhs:ty),*) => {
+        via_full!(Add, add, +; KVector; $($rhs),*);
+        via_full!(Sub, sub, -; KVector; $($rhs),*);
+    };
+    ($lhs:ty; KVector) => {
+        via_full!(Add, add, +; $lhs; KVector);
+        via_full!(Sub, sub, -; $lhs; KVector);
+    };
+    ($lhs:ty; $($rhs:ty),*) => {
+        via_full!(Add, add, +; $lhs; $($rhs),*);
+        via_full!(Sub, sub, -; $lhs; $($rhs),*);
+    };
+}
+
+// A scalar on the left is a foreign type, so its sums are listed out for each float type
+macro_rules! scalar_sums_via_full {
+    ($($f:ty),*) => {
+        $(
+            scalar_sums_via_full!(@kvector $f, Add, add, +);
+            scalar_sums_via_full!(@kvector $f, Sub, sub, -);
+            scalar_sums_via_full!(@each $f; Pseudoscalar, AnyKVector, Motor, OddVersor, Versor, Multivector16);
+        )*
+    };
+    (@kvector $f:ty, $trait:ident, $method:ident, $op:tt) => {
+        impl<const K: u8, const N: usize> $trait<KVector<K, N, $f>> for $f
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = Versor<$f>;
+            #[inline]
+            fn $method(self, rhs: KVector<K, N, $f>) -> Versor<$f> {
+                Versor::from(Multivector16::from(self) $op Multivector16::from(rhs))
+            }
+        }
+    };
+    (@each $f:ty; $($rhs:ident),*) => {
+        $(
+            scalar_sums_via_full!(@one $f, $rhs, Add, add, +);
+            scalar_sums_via_full!(@one $f, $rhs, Sub, sub, -);
+        )*
+    };
+    (@one $f:ty, $rhs:ident, $trait:ident, $method:ident, $op:tt) => {
+        impl $trait<$rhs<$f>> for $f {
+            type Output = Versor<$f>;
+            #[inline]
+            fn $method(self, rhs: $rhs<$f>) -> Versor<$f> {
+                Versor::from(Multivector16::from(self) $op Multivector16::from(rhs))
+            }
+        }
+    };
+}
+
+sums_via_full!(Motor<F>; KVector);
+sums_via_full!(Motor<F>; F, Pseudoscalar<F>, AnyKVector<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+sums_via_full!(OddVersor<F>; KVector);
+sums_via_full!(OddVersor<F>; F, Pseudoscalar<F>, AnyKVector<F>, Motor<F>, Versor<F>, Multivector16<F>);
+sums_via_full!(Pseudoscalar<F>; KVector);
+sums_via_full!(Pseudoscalar<F>; F, AnyKVector<F>, Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+sums_via_full!(KVector; F, Pseudoscalar<F>, AnyKVector<F>, Motor<F>, OddVersor<F>, Versor<F>, Multivector16<F>);
+sums_via_full!(Vector<F>; Bivector<F>, Trivector<F>);
+sums_via_full!(Bivector<F>; Vector<F>, Trivector<F>);
+sums_via_full!(Trivector<F>; Vector<F>, Bivector<F>);
+scalar_sums_via_full!(f32, f64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::pga_3::reference::CayleyTable;
+
+    fn basis(lane: usize) -> Multivector16 {
+        let mut mv = Multivector16::from([0.0; 16]);
+        mv[lane] = 1.0;
+        mv
+    }
+
+    // Each basis blade as the versor type that holds it
+    fn typed(lane: usize) -> Versor {
+        match Motor::try_from(basis(lane)) {
+            Ok(m) => Versor::Even(m),
+            Err(mv) => Versor::Odd(OddVersor::try_from(mv).unwrap()),
+        }
+    }
+
+    // The product of two basis blades from the reference table, which is built independently of
+    // the precomputed one behind `Multivector16`
+    fn expected(
+        table: &CayleyTable,
+        product: fn(&CayleyTable, [f32; 16], [f32; 16]) -> [f32; 16],
+        i: usize,
+        j: usize,
+    ) -> Multivector16 {
+        let mut a = [0.0; 16];
+        let mut b = [0.0; 16];
+        a[i] = 1.0;
+        b[j] = 1.0;
+        Multivector16::from(product(table, a, b))
+    }
+
+    #[test]
+    fn hand_computed_blades() {
+        let blade = |lane: usize, sign: f32| basis(lane) * sign;
+        // e12 ^ e3 = e123
+        assert_eq!(Multivector16::from(typed(5) ^ typed(3)), blade(11, 1.0));
+        // e23 ^ e0 = e023 = -e032
+        assert_eq!(Multivector16::from(typed(7) ^ typed(4)), blade(12, -1.0));
+        // e31 | e1 = e3 e1 e1 = e3
+        assert_eq!(Multivector16::from(typed(6) | typed(1)), blade(3, 1.0));
+        // e0123 | e3 = e012 = -e021
+        assert_eq!(Multivector16::from(typed(15) | typed(3)), blade(14, -1.0));
+        // e12 | e12 = -1, and e0 | e0 = 0
+        assert_eq!(Multivector16::from(typed(5) | typed(5)), blade(0, -1.0));
+        assert_eq!(Multivector16::from(typed(4) | typed(4)), blade(0, 0.0));
+        // The join of the origin and the point at infinity along x is the x axis, e123 & e032 = e23
+        assert_eq!(Multivector16::from(typed(11) & typed(12)), blade(7, 1.0));
+    }
+
+    #[test]
+    fn outer_matches_cayley_table() {
+        let table = CayleyTable::new();
+        for i in 0..16 {
+            for j in 0..16 {
+                let result = match (typed(i), typed(j)) {
+                    (Versor::Even(a), Versor::Even(b)) => a ^ b,
+                    (Versor::Even(a), Versor::Odd(b)) => a ^ b,
+                    (Versor::Odd(a), Versor::Even(b)) => a ^ b,
+                    (Versor::Odd(a), Versor::Odd(b)) => a ^ b,
+                    _ => unreachable!(),
+                };
+                assert_eq!(
+                    Multivector16::from(result),
+                    expected(&table, CayleyTable::outer, i, j),
+                    "{i} ^ {j}"
+                );
+                assert_eq!(
+                    Multivector16::from(typed(i) ^ typed(j)),
+                    expected(&table, CayleyTable::outer, i, j)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn inner_matches_cayley_table() {
+        let table = CayleyTable::new();
+        for i in 0..16 {
+            for j in 0..16 {
+                let result = match (typed(i), typed(j)) {
+                    (Versor::Even(a), Versor::Even(b)) => a | b,
+                    (Versor::Even(a), Versor::Odd(b)) => a | b,
+                    (Versor::Odd(a), Versor::Even(b)) => a | b,
+                    (Versor::Odd(a), Versor::Odd(b)) => a | b,
+                    _ => unreachable!(),
+                };
+                assert_eq!(
+                    Multivector16::from(result),
+                    expected(&table, CayleyTable::inner, i, j),
+                    "{i} | {j}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn regressive_matches_cayley_table() {
+        let table = CayleyTable::new();
+        for i in 0..16 {
+            for j in 0..16 {
+                let expected = expected(&table, CayleyTable::regressive, i, j);
+                let result = match (typed(i), typed(j)) {
+                    (Versor::Even(a), Versor::Even(b)) => a & b,
+                    (Versor::Even(a), Versor::Odd(b)) => a & b,
+                    (Versor::Odd(a), Versor::Even(b)) => a & b,
+                    (Versor::Odd(a), Versor::Odd(b)) => a & b,
+                    _ => unreachable!(),
+                };
+                assert_eq!(Multivector16::from(result), expected, "{i} & {j}");
+            }
+        }
+    }
+
+    #[test]
+    fn products_agree_with_kvectors() {
+        let p: Trivector = Trivector::point(1.0, 2.0, 3.0);
+        let q = Trivector::point(-1.0, 0.5, 2.0);
+        let plane = Vector::from([1.0, -2.0, 0.5, 3.0]);
+        let line = Bivector::from((p, q));
+        assert_eq!(Versor::from(p) & Versor::from(q), Versor::from(p & q));
+        assert_eq!(Versor::from(line) ^ plane, Versor::from(line ^ plane));
+        assert_eq!(plane | Versor::from(line), Versor::from(plane | line));
+        assert_eq!(e0123 ^ 2.0, Pseudoscalar(2.0).into());
+    }
+
+    #[test]
+    fn motor_products_split_by_grade() {
+        // A motor is 1 + bivector + pseudoscalar, so its products distribute over the three
+        let m: Motor = Motor::from([0.5, 1.0, -2.0, 0.5, 3.0, -1.0, 2.0, 0.25]);
+        let v = Vector::from([1.0, 2.0, -1.0, 0.5]);
+        let AnyKVector::Two(bv) = m.grade(2) else {
+            panic!("Grade 2 part of motor should be bivector");
+        };
+        assert_eq!(
+            m ^ v,
+            Versor::from(v * 0.5) + (bv ^ v) + (Pseudoscalar(0.25) ^ v)
+        );
+        assert_eq!(
+            m | v,
+            Versor::from(v * 0.5) + (bv | v) + (Pseudoscalar(0.25) | v)
+        );
+    }
+
+    #[test]
+    fn mixed_sums() {
+        let v: Vector = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let tv = Trivector::from([4.0, 3.0, 2.0, 1.0]);
+        let bv = Bivector::from([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(v + tv, Versor::Odd(OddVersor::from((v, tv))));
+        assert_eq!(
+            2.0 + bv + e0123,
+            Versor::Even(Motor::from((2.0, bv, e0123)))
+        );
+        let Versor::Mixed(mv) = Motor::from(1.0) + v else {
+            panic!("Motor + vector should be mixed");
+        };
+        assert_eq!(mv, Multivector16::from(1.0) + Multivector16::from(v));
+        assert_eq!((Motor::from(1.0) + v) - v, Versor::from(1.0));
+        assert_eq!(Versor::from(bv) - bv, Versor::from(0.0));
+        let ov = OddVersor::from((v, tv));
+        assert_eq!(ov - tv, Versor::from(v));
+        assert_eq!(AnyKVector::from(tv) + v, Versor::from(ov));
+    }
+}
@@ -1,149 +1,211 @@
+use super::products;
 use crate::pga_3::*;
 use crate::util::float_eq;
+pub use decomposition::*;
 use std::ops::{Add, Div, Mul, Neg, Shl, Shr, Sub};
 use std::ops::{Index, IndexMut};
-use std::simd::{Simd, simd_swizzle};
 use std::slice::SliceIndex;
 
-#[derive(Debug)]
-pub enum Transformation {
+mod decomposition;
+mod interchange;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Transformation<F: Float = f32> {
     Rotation {
-        axis: Bivector,
-        angle: f32,
+        axis: Blade<Bivector<F>>,
+        angle: F,
     },
     Translation {
-        direction: Trivector,
+        direction: Trivector<F>,
     },
     Screw {
-        axis: Bivector,
-        angle: f32,
-        distance: f32,
+        axis: Blade<Bivector<F>>,
+        angle: F,
+        distance: F,
+    },
+    Reflection {
+        plane: Vector<F>,
+    },
+    RotoReflection {
+        axis: Blade<Bivector<F>>,
+        angle: F,
+        plane: Vector<F>,
+    },
+    Glide {
+        plane: Vector<F>,
+        direction: Trivector<F>,
     },
 }
 
-impl Transformation {
+impl<F: Float> Transformation<F> {
     #[inline]
-    pub fn rotation(axis: Bivector, angle: f32) -> Self {
+    pub fn rotation(axis: Blade<Bivector<F>>, angle: F) -> Self {
         Transformation::Rotation {
             axis: axis.normalize(),
             angle,
         }
     }
     #[inline]
-    pub fn translation(direction: Trivector) -> Self {
+    pub const fn translation(direction: Trivector<F>) -> Self {
         Transformation::Translation { direction }
     }
     #[inline]
-    pub fn trans_coords(x: f32, y: f32, z: f32) -> Self {
+    pub fn trans_coords(x: F, y: F, z: F) -> Self {
         Transformation::Translation {
             direction: Trivector::direction(x, y, z),
         }
     }
     #[inline]
-    pub fn screw(axis: Bivector, angle: f32, distance: f32) -> Self {
+    pub fn screw(axis: Blade<Bivector<F>>, angle: F, distance: F) -> Self {
         Transformation::Screw {
             axis: axis.normalize(),
             angle,
             distance,
         }
     }
+    #[inline]
+    pub fn reflection(plane: Vector<F>) -> Self {
+        Transformation::Reflection {
+            plane: plane.normalize(),
+        }
+    }
+    /// Rotation about `axis` followed by reflection in `plane`, usually perpendicular to the axis
+    #[inline]
+    pub fn roto_reflection(axis: Blade<Bivector<F>>, angle: F, plane: Vector<F>) -> Self {
+        Transformation::RotoReflection {
+            axis: axis.normalize(),
+            angle,
+            plane: plane.normalize(),
+        }
+    }
+    /// Reflection in `plane` followed by translation along `direction`, usually parallel to it
+    #[inline]
+    pub fn glide(plane: Vector<F>, direction: Trivector<F>) -> Self {
+        Transformation::Glide {
+            plane: plane.normalize(),
+            direction,
+        }
+    }
+    /// Whether the transformation mirrors space, making it an `OddVersor` rather than a `Motor`
+    #[inline]
+    pub fn is_reflection(&self) -> bool {
+        matches!(
+            self,
+            Transformation::Reflection { .. }
+                | Transformation::RotoReflection { .. }
+                | Transformation::Glide { .. }
+        )
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
-pub struct Motor {
-    components: Simd<f32, 8>,
+pub struct Motor<F: Float = f32> {
+    pub(super) components: Lanes<F, 8>,
 }
 
-impl From<[f32; 8]> for Motor {
+impl<F: Float> From<[F; 8]> for Motor<F> {
     #[inline]
-    fn from(cs: [f32; 8]) -> Self {
+    fn from(cs: [F; 8]) -> Self {
         Motor {
-            components: Simd::from(cs),
+            components: Lanes::from(cs),
         }
     }
 }
 
-impl From<Simd<f32, 8>> for Motor {
+impl<F: Float> From<Lanes<F, 8>> for Motor<F> {
     #[inline]
-    fn from(cs: Simd<f32, 8>) -> Self {
+    fn from(cs: Lanes<F, 8>) -> Self {
         Motor { components: cs }
     }
 }
 
-impl From<Scalar> for Motor {
+impl<F: Float> From<F> for Motor<F> {
     #[inline]
-    fn from(s: Scalar) -> Self {
-        Self::from([s, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0])
+    fn from(s: F) -> Self {
+        Self::from([
+            s,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+        ])
     }
 }
 
-impl From<Bivector> for Motor {
+impl<F: Float> From<Bivector<F>> for Motor<F> {
     #[inline]
-    fn from(bv: Bivector) -> Self {
-        Self::from([0.0, bv[0], bv[1], bv[2], bv[3], bv[4], bv[5], 0.0])
+    fn from(bv: Bivector<F>) -> Self {
+        Self::from([F::ZERO, bv[0], bv[1], bv[2], bv[3], bv[4], bv[5], F::ZERO])
     }
 }
 
-impl From<Pseudoscalar> for Motor {
+impl<F: Float> From<Pseudoscalar<F>> for Motor<F> {
     #[inline]
-    fn from(ps: Pseudoscalar) -> Self {
-        Self::from([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, ps.0])
+    fn from(ps: Pseudoscalar<F>) -> Self {
+        Self::from([
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            ps.0,
+        ])
     }
 }
 
-impl From<(Scalar, Bivector, Pseudoscalar)> for Motor {
+impl<F: Float> From<(F, Bivector<F>, Pseudoscalar<F>)> for Motor<F> {
     #[inline]
-    fn from(cs: (Scalar, Bivector, Pseudoscalar)) -> Self {
+    fn from(cs: (F, Bivector<F>, Pseudoscalar<F>)) -> Self {
         Self::from([
             cs.0, cs.1[0], cs.1[1], cs.1[2], cs.1[3], cs.1[4], cs.1[5], cs.2.0,
         ])
     }
 }
 
-impl From<Transformation> for Motor {
+impl<F: Float> TryFrom<Transformation<F>> for Motor<F> {
+    type Error = PgaError;
+
+    /// The motor of a rigid motion. Reflections are odd versors, and fail with
+    /// `PgaError::ParityMismatch`.
     #[inline]
-    fn from(t: Transformation) -> Self {
-        use AnyKVector::*;
+    fn try_from(t: Transformation<F>) -> Result<Self, Self::Error> {
         use Transformation::*;
-        use Versor::*;
         match t {
-            Rotation { axis, angle } => axis.mul(-angle / 2.0).exp(),
-            Translation { direction } => {
-                let dir_dual = direction.dual().assert::<Vector>();
-                let bv = Bivector::from([0.0, 0.0, 0.0, dir_dual[0], dir_dual[1], dir_dual[2]]);
-                Self::from((1.0, -bv / 2.0, Pseudoscalar(0.0)))
-            }
+            Rotation { axis, angle } => Ok(Motor::rotation(axis, angle)),
+            Translation { direction } => Ok(Motor::translation(direction)),
             Screw {
                 axis,
                 angle,
                 distance,
-            } => {
-                let KVec(Two(bv_i)) = axis * e0123 * distance / 2.0 else {
-                    panic!("Line at infinity must be a bivector");
-                };
-                match bv_i.exp() * axis.mul(-angle / 2.0).exp() {
-                    Even(m) => m.normalize(),
-                    _ => panic!("Screw motion should be a motor"),
-                }
+            } => Ok(Motor::screw(axis, angle, distance)),
+            Reflection { .. } | RotoReflection { .. } | Glide { .. } => {
+                Err(PgaError::ParityMismatch {
+                    expected_odd: false,
+                })
             }
         }
     }
 }
 
-impl<Idx: SliceIndex<[f32]>> Index<Idx> for Motor {
+impl<F: Float, Idx: SliceIndex<[F]>> Index<Idx> for Motor<F> {
     type Output = Idx::Output;
     fn index(&self, index: Idx) -> &Self::Output {
         &self.components[index]
     }
 }
 
-impl<Idx: SliceIndex<[f32]>> IndexMut<Idx> for Motor {
+impl<F: Float, Idx: SliceIndex<[F]>> IndexMut<Idx> for Motor<F> {
     fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
         &mut self.components[index]
     }
 }
 
-impl PartialEq for Motor {
+impl<F: Float> PartialEq for Motor<F> {
     fn eq(&self, other: &Self) -> bool {
         self.components
             .as_array()
@@ -153,7 +215,7 @@ impl PartialEq for Motor {
     }
 }
 
-impl Neg for Motor {
+impl<F: Float> Neg for Motor<F> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -163,7 +225,7 @@ impl Neg for Motor {
     }
 }
 
-impl Add for Motor {
+impl<F: Float> Add for Motor<F> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
         Motor {
@@ -172,7 +234,7 @@ impl Add for Motor {
     }
 }
 
-impl Sub for Motor {
+impl<F: Float> Sub for Motor<F> {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
         Motor {
@@ -181,48 +243,63 @@ impl Sub for Motor {
     }
 }
 
-impl Mul<Scalar> for Motor {
+impl<F: Float> Mul<F> for Motor<F> {
     type Output = Self;
-    fn mul(self, rhs: Scalar) -> Self::Output {
+    fn mul(self, rhs: F) -> Self::Output {
         Motor {
-            components: self.components * Simd::splat(rhs),
+            components: self.components * Lanes::splat(rhs),
         }
     }
 }
 
-impl<T: Multivector + NonScalar> Mul<T> for Motor {
-    type Output = Versor;
-    fn mul(self, rhs: T) -> Self::Output {
-        self.geo(rhs)
+impl<F: Float> Mul for Motor<F> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Motor::from(products::even_even(self.components, rhs.components))
+    }
+}
+
+impl<F: Float> Mul<OddVersor<F>> for Motor<F> {
+    type Output = OddVersor<F>;
+    #[inline]
+    fn mul(self, rhs: OddVersor<F>) -> Self::Output {
+        OddVersor::from(products::even_odd(self.components, rhs.components))
     }
 }
 
-impl<T: SingleGrade + NonScalar + 'static> Shr<T> for Motor {
+impl<F: Float, T> Shr<T> for Motor<F>
+where
+    T: SingleGrade<Pga3<F>> + NonScalar + TryFrom<Multivector16<F>> + 'static,
+{
     type Output = T;
     fn shr(self, rhs: T) -> Self::Output {
-        self.sandwich(rhs)
+        super::projected(self.reverse().geo(rhs).geo(self), rhs.highest_grade())
     }
 }
 
-impl<T: SingleGrade + NonScalar + 'static> Shl<T> for Motor {
+impl<F: Float, T> Shl<T> for Motor<F>
+where
+    T: SingleGrade<Pga3<F>> + NonScalar + TryFrom<Multivector16<F>> + 'static,
+{
     type Output = T;
     fn shl(self, rhs: T) -> Self::Output {
-        self.reverse_sandwich(rhs)
+        super::projected(self.geo(rhs).geo(self.reverse()), rhs.highest_grade())
     }
 }
 
-impl Div<Scalar> for Motor {
+impl<F: Float> Div<F> for Motor<F> {
     type Output = Self;
-    fn div(self, rhs: Scalar) -> Self::Output {
+    fn div(self, rhs: F) -> Self::Output {
         Motor {
-            components: self.components / Simd::splat(rhs),
+            components: self.components / Lanes::splat(rhs),
         }
     }
 }
 
-impl Multivector for Motor {
+impl<F: Float> Multivector<Pga3<F>> for Motor<F> {
     #[inline]
-    fn e(&self, basis: u8) -> f32 {
+    fn e(&self, basis: u8) -> F {
         match basis {
             0b0000 => self[0],
             0b0110 => self[1],
@@ -232,26 +309,26 @@ impl Multivector for Motor {
             0b1010 => self[5],
             0b1001 => self[6],
             0b1111 => self[7],
-            _ => 0.0,
+            _ => F::ZERO,
         }
     }
 
     #[inline]
-    fn grade(&self, g: u8) -> AnyKVector {
+    fn grade(&self, g: u8) -> AnyKVector<F> {
         match g {
             0 => self[0].into(),
             2 => Bivector::from(self.components.extract::<1, 6>()).into(),
             4 => Pseudoscalar(self[7]).into(),
-            _ => 0.0.into(),
+            _ => F::ZERO.into(),
         }
     }
 
     #[inline]
     fn highest_grade(&self) -> u8 {
-        if self[7] != 0.0 {
+        if self[7] != F::ZERO {
             return 4;
         }
-        if self[1..7] != [0.0; 6] {
+        if self[1..7] != [F::ZERO; 6] {
             return 2;
         }
         0
@@ -271,129 +348,245 @@ impl Multivector for Motor {
 
     #[inline]
     fn zero(&self) -> bool {
-        self.components == Simd::splat(0.0)
+        self.components == Lanes::splat(F::ZERO)
     }
 
     #[inline]
     fn is_ideal(&self) -> bool {
-        self[0..4] == [0.0; 4]
+        self[0..4] == [F::ZERO; 4]
     }
 
     #[inline]
-    fn dual(self) -> Versor {
-        Versor::from(Motor::from(simd_swizzle!(
+    fn dual(self) -> Versor<F> {
+        Versor::from(Motor::from(swizzle!(
             self.components,
-            [0, 6, 5, 4, 3, 2, 1, 7]
+            [7, 6, 5, 4, 3, 2, 1, 0]
         )))
     }
 
     #[inline]
-    fn undual(self) -> Versor {
+    fn undual(self) -> Versor<F> {
         self.dual()
     }
 
     fn normalize(self) -> Self {
-        use AnyKVector::*;
-        use Versor::*;
-        let squared = match self * self.reverse() {
-            Even(m) => m,
-            KVec(Zero(s)) => Motor::from(s),
-            KVec(Four(ps)) => Motor::from(ps),
-            _ => panic!("Motor squared should be scalar + pseudoscalar"),
-        };
-        let s = 1.0 / squared[0].sqrt();
-        let ps = -squared[7] / (2.0 * squared[0].sqrt().powi(3));
-        match self * Motor::from([s, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, ps]) {
-            Even(m) => m,
-            KVec(Zero(s)) => Motor::from(s),
-            KVec(Four(ps)) => Motor::from(ps),
-            _ => panic!("Motor normalized should be a motor"),
-        }
+        // m * ~m is a scalar plus a pseudoscalar
+        let squared = self * self.reverse();
+        let s = F::ONE / squared[0].sqrt();
+        let ps = -squared[7] / (F::from(2.0) * squared[0].sqrt().powi(3));
+        self * Motor::from([s, F::ZERO, F::ZERO, F::ZERO, F::ZERO, F::ZERO, F::ZERO, ps])
     }
 
     #[inline]
-    fn geo<T: Multivector>(self, rhs: T) -> Versor {
+    fn geo<T: Multivector<Pga3<F>>>(self, rhs: T) -> Versor<F> {
         use Versor::*;
-        let self_g0 = self[0];
-        let AnyKVector::Two(self_g2) = self.grade(2) else {
-            panic!("Grade 2 part of motor should be bivector");
-        };
-        let self_g4 = Pseudoscalar(self[7]);
         match rhs.into() {
             KVec(kv) => kv.reverse().geo(self.reverse()).reverse(),
-            Even(m) => {
-                let t1 = m * self_g0;
-                let t2 = match self_g2.geo(m) {
-                    Even(m) => m,
-                    KVec(kv) => match kv {
-                        AnyKVector::Zero(s) => Motor::from(s),
-                        AnyKVector::Two(bv) => Motor::from(bv),
-                        AnyKVector::Four(ps) => Motor::from(ps),
-                        _ => panic!("Bivector * Motor should be motor"),
-                    },
-                    _ => panic!("Bivector * Motor should be motor"),
-                };
-                let t3 = match self_g4.geo(m) {
-                    Even(m) => m,
-                    KVec(kv) => match kv {
-                        AnyKVector::Zero(s) => Motor::from(s),
-                        AnyKVector::Two(bv) => Motor::from(bv),
-                        AnyKVector::Four(ps) => Motor::from(ps),
-                        _ => panic!("Pseudoscalar * Motor should be motor"),
-                    },
-                    _ => panic!("Pseudoscalar * Motor should be motor"),
-                };
-                Versor::from(t1 + t2 + t3)
-            }
-            Odd(ov) => {
-                let t1 = ov * self_g0;
-                let t2 = match self_g2.geo(ov) {
-                    Odd(ov) => ov,
-                    KVec(kv) => match kv {
-                        AnyKVector::One(v) => OddVersor::from(v),
-                        AnyKVector::Three(tv) => OddVersor::from(tv),
-                        _ => panic!("Bivector * odd versor should be odd versor"),
-                    },
-                    _ => panic!("Bivector * odd versor should be odd versor"),
-                };
-                let t3 = match self_g4.geo(ov) {
-                    Odd(ov) => ov,
-                    KVec(kv) => match kv {
-                        AnyKVector::One(v) => OddVersor::from(v),
-                        AnyKVector::Three(tv) => OddVersor::from(tv),
-                        _ => panic!("Pseudoscalar * odd versor should be odd versor"),
-                    },
-                    _ => panic!("Pseudoscalar * odd versor should be odd versor"),
-                };
-                Versor::from(t1 + t2 + t3)
-            }
+            Even(m) => Versor::from(self * m),
+            Odd(ov) => Versor::from(self * ov),
+            Mixed(mv) => Multivector16::from(self).geo(mv),
         }
     }
 }
 
-impl Motor {
-    pub fn sandwich<T: SingleGrade + NonScalar + 'static>(self, rhs: T) -> T {
-        match (self.reverse() * rhs * self).snap() {
-            Versor::KVec(kv) => kv.assert::<T>(),
-            _ => panic!("Sandwich of k-vector should be a k-vector"),
+impl<F: Float> Motor<F> {
+    /// The motor of `Transformation::rotation`
+    #[inline]
+    pub fn rotation(axis: Blade<Bivector<F>>, angle: F) -> Self {
+        axis.normalize().mul(-angle / F::from(2.0)).exp()
+    }
+
+    /// The motor of `Transformation::translation`
+    #[inline]
+    pub fn translation(direction: Trivector<F>) -> Self {
+        let dir_dual = direction.dual().assert::<Vector<F>>();
+        let bv = Bivector::from([
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            dir_dual[0],
+            dir_dual[1],
+            dir_dual[2],
+        ]);
+        Self::from((F::ONE, -bv / F::from(2.0), Pseudoscalar(F::ZERO)))
+    }
+
+    #[inline]
+    pub fn trans_coords(x: F, y: F, z: F) -> Self {
+        Self::translation(Trivector::direction(x, y, z))
+    }
+
+    /// The motor of `Transformation::screw`
+    #[inline]
+    pub fn screw(axis: Blade<Bivector<F>>, angle: F, distance: F) -> Self {
+        let axis = axis.normalize();
+        // The axis times e0123, the line at infinity the translation along the axis turns about
+        let bv_i = axis.eucl_dual() * (distance / F::from(2.0));
+        (axis.mul(-angle / F::from(2.0)) + bv_i).exp()
+    }
+
+    /// The motor with the given components, as a const alternative to `From<[Float; 8]>`
+    #[inline]
+    pub const fn from_array(cs: [F; 8]) -> Self {
+        Motor {
+            components: Lanes::from_array(cs),
         }
     }
 
-    pub fn reverse_sandwich<T: SingleGrade + NonScalar + 'static>(self, rhs: T) -> T {
-        match (self * rhs * self.reverse()).snap() {
-            Versor::KVec(kv) => kv.assert::<T>(),
-            _ => panic!("Reverse-sandwich of k-vector should be a k-vector"),
+    #[inline]
+    pub const fn to_array(self) -> [F; 8] {
+        self.components.to_array()
+    }
+
+    /// Applies the motor to `rhs`. The parts of other grades cancel for any motor, but only up to
+    /// rounding, so they're dropped relative to the size of the result; what's left fails with
+    /// `PgaError::GradeMismatch`, eg for motors with NaN components. `m >> rhs` keeps only the
+    /// part of the grade of `rhs` instead.
+    pub fn sandwich<T>(self, rhs: T) -> Result<T, PgaError>
+    where
+        T: SingleGrade<Pga3<F>> + NonScalar + TryFrom<Multivector16<F>> + 'static,
+    {
+        super::sandwiched(self.reverse().geo(rhs).geo(self))
+    }
+
+    /// Applies the inverse of the motor to `rhs`, failing like `sandwich`
+    pub fn reverse_sandwich<T>(self, rhs: T) -> Result<T, PgaError>
+    where
+        T: SingleGrade<Pga3<F>> + NonScalar + TryFrom<Multivector16<F>> + 'static,
+    {
+        super::sandwiched(self.geo(rhs).geo(self.reverse()))
+    }
+
+    /// Computes the bivector logarithm of the motor, such that `m.log().exp()` gives back the
+    /// normalized motor. A motor and its negation encode the same transformation, so this
+    /// uses whichever of the two has a non-negative scalar part, giving the shortest screw.
+    pub fn log(self) -> Bivector<F> {
+        let mut m = self.normalize();
+        // A half turn has no shorter way round, so rounding in its scalar part is ignored
+        if m[0] < F::ZERO && !float_eq(m[0], F::ZERO) {
+            m = -m;
+        }
+        let bv = Bivector::from(m.components.extract::<1, 6>());
+        let s = m[0].min(F::ONE);
+        if float_eq(s, F::ONE) {
+            // No rotational part, so the motor is 1 + (ideal bivector)
+            return bv;
         }
+        let inv_sin_sq = F::ONE / (F::ONE - s * s);
+        let angle_over_sin = s.acos() * inv_sin_sq.sqrt();
+        let ps_correction = -m[7] * inv_sin_sq * (F::ONE - s * angle_over_sin);
+        let eucl_dual = Bivector::from([F::ZERO, F::ZERO, F::ZERO, -bv[2], -bv[1], -bv[0]]);
+        bv * angle_over_sin + eucl_dual * ps_correction
     }
+
+    /// Screw-linear interpolation between two motors. `t = 0.0` gives `a`, `t = 1.0` gives `b`,
+    /// and values in between move along the single screw motion that takes `a` to `b`.
+    pub fn interpolate(a: Motor<F>, b: Motor<F>, t: F) -> Motor<F> {
+        let a = a.normalize();
+        let delta = a.reverse() * b.normalize();
+        a * (delta.log() * t).exp()
+    }
+
+    #[inline]
+    pub fn slerp(self, other: Motor<F>, t: F) -> Motor<F> {
+        Motor::interpolate(self, other, t)
+    }
+
+    /// Square root of the motor, the motor that applied twice gives this one, computed as the
+    /// normalized `1 + m`. Unlike `log` this keeps the sign of the motor, so `m` and `-m` give
+    /// the halfway points of the two ways round the same screw. A motor with scalar part `-1` is
+    /// a full turn, whose roots are all the half turns, so the root of `-m` is used instead.
+    pub fn sqrt(self) -> Motor<F> {
+        let m = self.normalize();
+        if float_eq(m[0], -F::ONE) {
+            return (-m).sqrt();
+        }
+        (Motor::from(F::ONE) + m).normalize()
+    }
+
+    /// The motor taking `a` to `b`, so that `Motor::between(a, b) >> a` is `b` up to scale, for
+    /// two points, lines or planes. This is the square root of their ratio, the motor that moves
+    /// `a` along the shortest screw to `b`. Points have no orientation, but lines and planes do,
+    /// and when `b` faces the opposite way to `a` the ratio is `-1` with no unique root; `a` is
+    /// then first turned over by half a turn about a perpendicular line through it. Fails with
+    /// `PgaError::NonInvertible` if either is ideal, as no motor moves them to or from infinity,
+    /// and with `PgaError::GradeMismatch` if they're `AnyKVector`s of different grades.
+    pub fn between<T>(a: T, b: T) -> Result<Motor<F>, PgaError>
+    where
+        T: SingleGrade<Pga3<F>> + NonScalar + TryFrom<Multivector16<F>> + 'static,
+    {
+        let a = a.normalize();
+        let inverse = a.try_inverse()?;
+        if b.is_ideal() {
+            return Err(PgaError::NonInvertible);
+        }
+        let mismatch = PgaError::GradeMismatch {
+            expected: Some(a.highest_grade()),
+            found: Some(b.highest_grade()),
+        };
+        if a.highest_grade() != b.highest_grade() {
+            return Err(mismatch);
+        }
+        // The ratio of two elements of the same grade is even
+        let ratio = Motor::try_from(Multivector16::from(inverse.geo(b.normalize())))
+            .map_err(|_| mismatch)?;
+        if !float_eq(ratio[0], -F::ONE) {
+            return Ok(ratio.sqrt());
+        }
+        // Without an orientation to turn over, the root of the negated ratio moves `a` to `b`
+        let Some(axis) = flip_axis(a.into()) else {
+            return Ok(ratio.sqrt());
+        };
+        let flip = Motor::rotation(axis, F::PI);
+        Ok(flip * Motor::between(flip >> a, b)?)
+    }
+}
+
+/// A line through `element` perpendicular to its direction (or normal, for a plane), about which
+/// half a turn reverses its orientation, or `None` for the grades without one
+fn flip_axis<F: Float>(element: AnyKVector<F>) -> Option<Blade<Bivector<F>>> {
+    let (foot, direction) = match element {
+        AnyKVector::One(plane) => (
+            Blade::<Trivector<F>>::e123.foot_on_plane(plane),
+            [plane[0], plane[1], plane[2]],
+        ),
+        AnyKVector::Two(line) => (
+            Blade::<Trivector<F>>::e123.foot_on_line(line),
+            [line[2], line[1], line[0]],
+        ),
+        _ => return None,
+    };
+    // Cross with whichever axis is least aligned with the direction
+    let [x, y, z] = direction.map(|d: F| d.abs());
+    let [dx, dy, dz] = direction;
+    let perpendicular = if x <= y && x <= z {
+        Trivector::direction(F::ZERO, dz, -dy)
+    } else if y <= z {
+        Trivector::direction(-dz, F::ZERO, dx)
+    } else {
+        Trivector::direction(dy, -dx, F::ZERO)
+    };
+    // The join of two points is always a blade
+    Some(Blade::unchecked(Bivector::from((foot, perpendicular))))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn dual_swaps_scalar_and_pseudoscalar() {
+        let m: Motor = Motor::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(
+            m.dual(),
+            Versor::from(Motor::from([8.0, 7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0]))
+        );
+        assert_eq!(m.dual().undual(), Versor::from(m));
+    }
+
     #[test]
     fn geo_motor_bivector() {
-        let m = Motor::from([-1.0, 0.0, 0.0, 1.0, -2.0, 0.0, 0.0, 2.0]);
+        let m: Motor = Motor::from([-1.0, 0.0, 0.0, 1.0, -2.0, 0.0, 0.0, 2.0]);
         let bv = Bivector::from([-5.0, 10.0, -5.0, 15.0, 10.0, 5.0]);
         let expected = Versor::Even(Motor::from([
             5.0, -5.0, -15.0, 5.0, -5.0, -15.0, 15.0, 25.0,
@@ -407,7 +600,7 @@ mod test {
 
     #[test]
     fn geo_motor_vector() {
-        let m = Motor::from([-1.0, 0.0, 0.0, 1.0, -2.0, 0.0, 0.0, 2.0]);
+        let m: Motor = Motor::from([-1.0, 0.0, 0.0, 1.0, -2.0, 0.0, 0.0, 2.0]);
         let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
         let expected = Versor::Odd(OddVersor::from([
             -1.0, 1.0, -5.0, -6.0, 1.0, -6.0, -10.0, -2.0,
@@ -422,7 +615,7 @@ mod test {
 
     #[test]
     fn motor_normalize() {
-        let m = Motor::from([-1.0, 0.0, 0.0, 1.0, -2.0, 0.0, 0.0, 2.0]).normalize();
+        let m: Motor = Motor::from([-1.0, 0.0, 0.0, 1.0, -2.0, 0.0, 0.0, 2.0]).normalize();
         assert!(float_eq(dbg!(m.magnitude()), 1.0));
         assert_eq!(m.inverse().unwrap(), m.reverse());
     }
@@ -431,30 +624,47 @@ mod test {
     fn rotate_plane() {
         use std::f32::consts::PI;
         let p = Vector::from([1.0, 0.0, 0.0, 0.0]);
-        let r = Transformation::rotation(Bivector::from([0.0, 1.0, 0.0, 0.0, 0.0, 0.0]), PI / 4.0);
+        let r = Transformation::rotation(Blade::e31, PI / 4.0);
         let expected = Vector::from([1.0, 0.0, 1.0, 0.0]).normalize();
-        let m = Motor::from(r);
+        let m = Motor::try_from(r).unwrap();
         assert_eq!(m.reverse() * p * m, Versor::from(expected));
-        assert_eq!(m.sandwich(p), expected);
+        assert_eq!(m.sandwich(p), Ok(expected));
         assert_eq!(m >> p, expected);
     }
 
+    #[test]
+    fn sandwich_large_motor() {
+        // The other grades of the products cancel to well above the epsilon in absolute terms
+        let m: Motor = Motor::from([312.5, -820.25, 97.0, 401.75, -655.5, 230.0, -48.25, 777.0]);
+        let p = Trivector::point(12.5, -3.25, 40.0);
+        let l = Bivector::from([0.5, -1.5, 2.0, 30.0, -12.0, 7.5]);
+        assert_eq!(m.sandwich(p), Ok(m >> p));
+        assert_eq!(m.sandwich(l), Ok(m >> l));
+        assert_eq!(m.reverse_sandwich(p), Ok(m << p));
+        assert_eq!(
+            Motor::from([f32::NAN; 8]).sandwich(p),
+            Err(PgaError::GradeMismatch {
+                expected: Some(3),
+                found: None
+            })
+        );
+    }
+
     #[test]
     fn translate_plane() {
-        let p = Vector::from([1.0, 0.0, 0.0, 0.0]);
+        let p: Vector = Vector::from([1.0, 0.0, 0.0, 0.0]);
         let dir = Trivector::direction(5.0, 0.0, 0.0);
         let t = Transformation::translation(dir);
         let expected = Vector::from([1.0, 0.0, 0.0, 5.0]).normalize();
-        assert_eq!(Motor::from(t) >> p, expected);
+        assert_eq!(Motor::try_from(t).unwrap() >> p, expected);
     }
 
     #[test]
     fn screw_line() {
         use std::f32::consts::PI;
         let x_axis = Bivector::from([0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
-        let z_axis = Bivector::from([1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
-        let s = Transformation::screw(z_axis, PI / 4.0, 5.0);
-        let result = (Motor::from(s) >> x_axis).normalize();
+        let s = Transformation::screw(Blade::e12, PI / 4.0, 5.0);
+        let result = (Motor::try_from(s).unwrap() >> x_axis).normalize();
         let Versor::KVec(AnyKVector::Three(p1)) = dbg!(
             (Trivector::from([1.0, 0.0, 0.0, 0.0]) | result)
                 .geo(result)
@@ -482,4 +692,233 @@ mod test {
             ])
         );
     }
+
+    #[test]
+    fn log_rotation() {
+        use std::f32::consts::PI;
+        let m = Motor::rotation(Blade::e31, PI / 3.0);
+        assert_eq!(m.log(), e31 * (-PI / 6.0));
+        assert_eq!(m.log().exp(), m);
+    }
+
+    #[test]
+    fn log_translation() {
+        let m: Motor = Motor::trans_coords(1.0, -2.0, 3.0);
+        assert_eq!(m.log(), Bivector::from([0.0, 0.0, 0.0, -0.5, 1.0, -1.5]));
+        assert_eq!(m.log().exp(), m);
+    }
+
+    #[test]
+    fn log_screw() {
+        use std::f32::consts::PI;
+        let axis = Bivector::from((
+            Trivector::point(1.0, 0.0, 0.0),
+            Trivector::direction(0.0, 0.0, 1.0),
+        ))
+        .normalize();
+        let m = Motor::screw(Blade::new(axis).unwrap(), PI / 3.0, 2.0);
+        let expected = axis * (-PI / 6.0) + (axis * e0123).assert::<Bivector>();
+        assert_eq!(m.log(), expected);
+    }
+
+    #[test]
+    fn log_of_negated_motor() {
+        use std::f32::consts::PI;
+        let m = Motor::rotation(Blade::e12, PI / 2.0);
+        assert_eq!((-m).log(), m.log());
+    }
+
+    #[test]
+    fn log_half_turn() {
+        use std::f32::consts::PI;
+        // The scalar part of a half turn can round to either side of zero, and shouldn't flip
+        // the axis when it does
+        let m = Motor::rotation(Blade::e12, PI);
+        assert_eq!(m.log(), e12 * (-PI / 2.0));
+        let rounded = Motor::from((-crate::util::EPSILON / 10.0, -e12, Pseudoscalar(0.0)));
+        assert_eq!(rounded.log(), e12 * (-PI / 2.0));
+    }
+
+    #[test]
+    fn interpolate_endpoints() {
+        use std::f32::consts::PI;
+        let a = Motor::rotation(Blade::e12, PI / 5.0);
+        let b = Motor::rotation(Blade::e23, PI / 3.0);
+        assert_eq!(Motor::interpolate(a, b, 0.0), a);
+        assert_eq!(Motor::interpolate(a, b, 1.0), b);
+    }
+
+    #[test]
+    fn log_exp_round_trip_screw() {
+        let m: Motor = Bivector::from([0.1, 0.2, 0.3, 0.4, 0.5, 0.6]).exp();
+        assert_eq!(m.log().exp(), m);
+        assert_eq!(m.log(), Bivector::from([0.1, 0.2, 0.3, 0.4, 0.5, 0.6]));
+    }
+
+    #[test]
+    fn composition_drift_in_f64() {
+        let step = Motor::<f64>::rotation(Blade::e12, std::f64::consts::PI / 500.0);
+        let full_turn = (0..1000).fold(Motor::from(1.0), |acc, _| acc * step);
+        assert_eq!(full_turn, Motor::from(-1.0));
+        let m = Bivector::<f64>::from([0.1, 0.2, 0.3, 0.4, 0.5, 0.6]).exp();
+        assert_eq!(m.log(), Bivector::from([0.1, 0.2, 0.3, 0.4, 0.5, 0.6]));
+    }
+
+    #[test]
+    fn interpolate_screw_endpoints() {
+        use std::f32::consts::PI;
+        let a = Motor::trans_coords(1.0, 2.0, 3.0);
+        let b = Motor::rotation(Blade::e23, PI / 3.0);
+        assert_eq!(Motor::interpolate(a, b, 0.0), a);
+        assert_eq!(Motor::interpolate(a, b, 1.0), b);
+    }
+
+    #[test]
+    fn interpolate_screw_halfway() {
+        use std::f32::consts::PI;
+        let axis = Bivector::from((
+            Trivector::point(1.0, 0.0, 0.0),
+            Trivector::direction(0.0, 0.0, 1.0),
+        ));
+        let a = Motor::from(1.0);
+        let b = Motor::screw(Blade::new(axis).unwrap(), PI / 2.0, 4.0);
+        assert_eq!(
+            Motor::interpolate(a, b, 0.5),
+            Motor::screw(Blade::new(axis).unwrap(), PI / 4.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn interpolate_rotation() {
+        use std::f32::consts::PI;
+        let a = Motor::from(1.0);
+        let b = Motor::rotation(Blade::e31, PI / 2.0);
+        assert_eq!(
+            Motor::interpolate(a, b, 0.5),
+            Motor::rotation(Blade::e31, PI / 4.0)
+        );
+    }
+
+    #[test]
+    fn interpolate_translation() {
+        let a: Motor = Motor::trans_coords(1.0, 0.0, 0.0);
+        let b = Motor::trans_coords(3.0, 4.0, 0.0);
+        assert_eq!(
+            Motor::interpolate(a, b, 0.5),
+            Motor::trans_coords(2.0, 2.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn slerp_point_along_arc() {
+        use std::f32::consts::PI;
+        let a = Motor::from(1.0);
+        let b = Motor::rotation(Blade::e12, PI);
+        let p = Trivector::point(1.0, 0.0, 0.0);
+        let quarter = a.slerp(b, 0.5) >> p;
+        assert_eq!(
+            quarter.normalize(),
+            (Motor::rotation(Blade::e12, PI / 2.0) >> p).normalize()
+        );
+        assert!(float_eq(quarter.normalize().x(), 0.0));
+    }
+
+    #[test]
+    fn sqrt_squares_to_motor() {
+        use std::f32::consts::PI;
+        let m: Motor = Bivector::from([0.1, 0.2, 0.3, 0.4, 0.5, 0.6]).exp();
+        let root = m.sqrt();
+        assert_eq!(m, root * root);
+        let turn = Motor::rotation(Blade::e12, PI / 2.0);
+        assert_eq!(turn.sqrt(), Motor::rotation(Blade::e12, PI / 4.0));
+        assert_eq!(
+            <Motor>::trans_coords(2.0, 0.0, 4.0).sqrt(),
+            Motor::trans_coords(1.0, 0.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn between_points() {
+        let a: Trivector = Trivector::point(1.0, 2.0, 3.0);
+        let b = Trivector::point(-1.0, 0.0, 5.0);
+        let m = Motor::between(a, b * 2.0).unwrap();
+        assert_eq!(m, Motor::trans_coords(-2.0, -2.0, 2.0));
+        assert_eq!((m >> a).normalize(), b);
+    }
+
+    #[test]
+    fn between_planes() {
+        let a: Vector = Vector::from([1.0, 0.0, 0.0, 2.0]);
+        let b = Vector::from([-1.0, 1.0, 0.0, 0.0]).normalize();
+        assert_eq!((Motor::between(a, b).unwrap() >> a).normalize(), b);
+        let c = Vector::from([0.0, 0.0, 3.0, 3.0]);
+        assert_eq!(
+            (Motor::between(a, c).unwrap() >> a).normalize(),
+            c.normalize()
+        );
+    }
+
+    #[test]
+    fn between_lines() {
+        let a = Bivector::from((e123, Trivector::direction(1.0, 0.0, 0.0)));
+        let b = Bivector::from((
+            Trivector::point(0.0, 3.0, 1.0),
+            Trivector::direction(0.0, -1.0, 1.0),
+        ))
+        .normalize();
+        let m = Motor::between(a, b).unwrap();
+        assert_eq!((m >> a).normalize(), b);
+        // The common normal of the lines is the y = z diagonal through the origin
+        assert_eq!((m >> e123).normalize(), Trivector::point(0.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn between_opposite_orientations() {
+        let plane: Vector = Vector::from([0.0, 1.0, 1.0, 4.0]).normalize();
+        let shifted = -Vector::from([0.0, 1.0, 1.0, 2.0]).normalize();
+        assert_eq!((Motor::between(plane, -plane).unwrap() >> plane), -plane);
+        assert_eq!((Motor::between(plane, shifted).unwrap() >> plane), shifted);
+        let line: Bivector = Bivector::from((
+            Trivector::point(1.0, 0.0, 0.0),
+            Trivector::direction(0.0, 0.0, 1.0),
+        ))
+        .normalize();
+        assert_eq!((Motor::between(line, -line).unwrap() >> line), -line);
+    }
+
+    #[test]
+    fn between_ideal_elements() {
+        let p: Trivector = Trivector::point(1.0, 2.0, 3.0);
+        let d = Trivector::direction(1.0, 0.0, 0.0);
+        assert_eq!(Motor::between(d, p), Err(PgaError::NonInvertible));
+        assert_eq!(Motor::between(p, d), Err(PgaError::NonInvertible));
+        assert_eq!(Motor::between(e0, e1), Err(PgaError::NonInvertible));
+        let line = Bivector::from((p, Trivector::direction(0.0, 1.0, 0.0)));
+        assert_eq!(Motor::between(line, e01), Err(PgaError::NonInvertible));
+        assert_eq!(Motor::between(e01, line), Err(PgaError::NonInvertible));
+    }
+
+    #[test]
+    fn between_negated_points() {
+        // A point and its negation are the same point, and there's no orientation to turn over
+        let p: Trivector = Trivector::point(1.0, 2.0, 3.0);
+        assert_eq!(Motor::between(p, -p), Ok(Motor::from(1.0)));
+        let q = Trivector::point(-1.0, 0.0, 5.0);
+        let m = Motor::between(p, -q).unwrap();
+        assert_eq!((m >> p).normalize(), q);
+    }
+
+    #[test]
+    fn between_mixed_grades() {
+        let plane: AnyKVector = e1.into();
+        let point = Trivector::point(1.0, 2.0, 3.0).into();
+        assert_eq!(
+            Motor::between(plane, point),
+            Err(PgaError::GradeMismatch {
+                expected: Some(1),
+                found: Some(3)
+            })
+        );
+        assert_eq!(Motor::between(plane, -plane).unwrap() >> plane, -plane);
+    }
 }
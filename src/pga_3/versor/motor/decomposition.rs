@@ -0,0 +1,232 @@
+use crate::pga_3::*;
+use crate::util::float_eq;
+
+/// A motor described as a screw motion: turning by `angle` about `axis` while sliding `distance`
+/// along it. `pitch` is the distance per radian, infinite for a pure translation.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MotorDecomposition<F: Float = f32> {
+    pub axis: Bivector<F>,
+    pub angle: F,
+    pub distance: F,
+    pub pitch: F,
+    pub transformation: Transformation<F>,
+}
+
+impl<F: Float> Motor<F> {
+    /// Finds the screw motion of the motor, with `angle` in `[0, PI]` and the axis oriented to
+    /// match, so that `Motor::try_from(d.transformation).unwrap()` is the normalized motor up to sign. A pure
+    /// translation has its axis through the origin along the direction of motion, and the
+    /// identity has a zero axis.
+    pub fn decompose(self) -> MotorDecomposition<F> {
+        let log = self.log();
+        let (line, _) = log.split();
+        let half_angle = line.eucl_norm();
+        if float_eq(half_angle, F::ZERO) {
+            // log = -(x * e01 + y * e02 + z * e03) / 2
+            let direction = Trivector::direction(
+                -F::from(2.0) * log[3],
+                -F::from(2.0) * log[4],
+                -F::from(2.0) * log[5],
+            );
+            let distance = direction.ideal_norm();
+            let (axis, pitch) = if float_eq(distance, F::ZERO) {
+                (Bivector::from([F::ZERO; 6]), F::ZERO)
+            } else {
+                let axis = Bivector::from((*Blade::<Trivector<F>>::e123, direction)).normalize();
+                (axis, F::INFINITY)
+            };
+            return MotorDecomposition {
+                axis,
+                angle: F::ZERO,
+                distance,
+                pitch,
+                transformation: Transformation::translation(direction),
+            };
+        }
+        // log = u * L + v * L * I for a normalized line L, and a screw about `axis` is
+        // exp(-angle / 2 * axis + distance / 2 * axis * I), so the axis is -L
+        let axis = -line / half_angle;
+        let angle = F::from(2.0) * half_angle;
+        let distance = -F::from(2.0) * log.pitch_product() / half_angle;
+        let blade = Blade::unchecked(axis);
+        MotorDecomposition {
+            axis,
+            angle,
+            distance,
+            pitch: distance / angle,
+            transformation: if float_eq(distance, F::ZERO) {
+                Transformation::rotation(blade, angle)
+            } else {
+                Transformation::screw(blade, angle, distance)
+            },
+        }
+    }
+
+    /// The rotation about the origin that the motor applies before its translation, so that
+    /// `m` is `m.rotation_factor() * m.translation_factor()`
+    pub fn rotation_factor(self) -> Motor<F> {
+        let m = self.normalize();
+        Motor::from([m[0], m[1], m[2], m[3], F::ZERO, F::ZERO, F::ZERO, F::ZERO])
+    }
+
+    /// The translation the motor applies after its rotation about the origin
+    pub fn translation_factor(self) -> Motor<F> {
+        self.rotation_factor().reverse() * self.normalize()
+    }
+
+    /// Rolls by `roll` about the z axis, then pitches by `pitch` about the x axis, then yaws by
+    /// `yaw` about the y axis, each as a `Transformation::rotation` about those axes
+    pub fn from_yaw_pitch_roll(yaw: F, pitch: F, roll: F) -> Motor<F> {
+        let roll = Motor::rotation(Blade::e12, roll);
+        let pitch = Motor::rotation(Blade::e23, pitch);
+        let yaw = Motor::rotation(Blade::e31, yaw);
+        roll * pitch * yaw
+    }
+
+    /// The `(yaw, pitch, roll)` angles of the motor's rotation, in the convention of
+    /// `Motor::from_yaw_pitch_roll`. Pitch is in `[-PI / 2, PI / 2]`, and when it's at either end
+    /// yaw and roll turn about the same axis, so all of the turn is put into yaw.
+    pub fn yaw_pitch_roll(self) -> (F, F, F) {
+        // The right-handed angles of R = Ry(a) * Rx(b) * Rz(c), which are the negated angles
+        // passed to `Transformation::rotation`
+        let m = self.rotation_factor().to_matrix();
+        let (r00, r10, r20) = (m[0], m[1], m[2]);
+        let (r11, r02, r12, r22) = (m[5], m[8], m[9], m[10]);
+        // atan2 rather than asin, which loses precision near gimbal lock
+        let b = (-r12).atan2((r02 * r02 + r22 * r22).sqrt());
+        let (a, c) = if float_eq(r12.abs(), F::ONE) {
+            ((-r20).atan2(r00), F::ZERO)
+        } else {
+            (r02.atan2(r22), r10.atan2(r11))
+        };
+        (-a, -b, -c)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4};
+
+    fn same_pose(a: Motor, b: Motor) -> bool {
+        a == b || a == -b
+    }
+
+    #[test]
+    fn decompose_rotation() {
+        let axis = Bivector::from((
+            Trivector::point(1.0, 2.0, 0.0),
+            Trivector::direction(0.0, 0.0, 1.0),
+        ));
+        let m = Motor::rotation(Blade::new(axis).unwrap(), 2.0);
+        let d = m.decompose();
+        assert_eq!(d.axis, axis.normalize());
+        assert!(float_eq(d.angle, 2.0));
+        assert!(float_eq(d.distance, 0.0));
+        assert!(matches!(d.transformation, Transformation::Rotation { .. }));
+        assert!(same_pose(Motor::try_from(d.transformation).unwrap(), m));
+    }
+
+    #[test]
+    fn decompose_translation() {
+        let m = Motor::trans_coords(0.0, 3.0, 4.0);
+        let d = m.decompose();
+        assert!(float_eq(d.angle, 0.0));
+        assert!(float_eq(d.distance, 5.0));
+        assert!(d.pitch.is_infinite());
+        assert_eq!(
+            d.transformation,
+            Transformation::trans_coords(0.0, 3.0, 4.0)
+        );
+        let along_axis = Transformation::screw(Blade::new(d.axis).unwrap(), 0.0, d.distance);
+        assert!(same_pose(Motor::try_from(along_axis).unwrap(), m));
+    }
+
+    #[test]
+    fn decompose_identity() {
+        let d = <Motor>::from(1.0).decompose();
+        assert!(d.axis.zero());
+        assert_eq!((d.angle, d.distance, d.pitch), (0.0, 0.0, 0.0));
+        // Translations too small to have an axis are treated as the identity throughout
+        let tiny = crate::util::EPSILON / 10.0;
+        let d = Motor::trans_coords(tiny, 0.0, 0.0).decompose();
+        assert!(d.axis.zero());
+        assert_eq!(d.pitch, 0.0);
+    }
+
+    #[test]
+    fn decompose_screw() {
+        let axis = Bivector::from((
+            Trivector::point(-1.0, 0.5, 2.0),
+            Trivector::direction(1.0, -2.0, 2.0),
+        ))
+        .normalize();
+        let m = Motor::screw(Blade::new(axis).unwrap(), 1.5, -3.0);
+        let d = m.decompose();
+        assert_eq!(d.axis, axis);
+        assert!(float_eq(d.angle, 1.5));
+        assert!(float_eq(d.distance, -3.0));
+        assert!(float_eq(d.pitch, -2.0));
+        assert!(same_pose(Motor::try_from(d.transformation).unwrap(), m));
+    }
+
+    #[test]
+    fn decompose_reverses_axis_for_negative_angle() {
+        let m = Motor::screw(Blade::e23, -FRAC_PI_2, 1.0);
+        let d = m.decompose();
+        assert_eq!(d.axis, -e23);
+        assert!(float_eq(d.angle, FRAC_PI_2));
+        assert!(float_eq(d.distance, -1.0));
+    }
+
+    #[test]
+    fn factors() {
+        let m = Bivector::from([0.1, 0.2, 0.3, 0.4, 0.5, 0.6]).exp();
+        let (r, t) = (m.rotation_factor(), m.translation_factor());
+        assert_eq!(r * t, m);
+        assert!(float_eq(r.decompose().distance, 0.0));
+        assert_eq!(
+            (r >> e123).normalize(),
+            e123,
+            "Rotation factor should fix the origin"
+        );
+        assert!(float_eq(t.decompose().angle, 0.0));
+        assert_eq!((t >> e123).normalize(), (m >> e123).normalize());
+    }
+
+    #[test]
+    fn yaw_pitch_roll_round_trip() {
+        let (yaw, pitch, roll) = (0.3, -0.7, 1.2);
+        let m: Motor = Motor::from_yaw_pitch_roll(yaw, pitch, roll);
+        let (y, p, r) = m.yaw_pitch_roll();
+        assert!(float_eq(y, yaw) && float_eq(p, pitch) && float_eq(r, roll));
+        let translated = m * Motor::trans_coords(1.0, 2.0, 3.0);
+        let (y, p, r) = translated.yaw_pitch_roll();
+        assert!(float_eq(y, yaw) && float_eq(p, pitch) && float_eq(r, roll));
+    }
+
+    #[test]
+    fn yaw_pitch_roll_single_axes() {
+        let (y, p, r) = Motor::rotation(Blade::e31, FRAC_PI_3).yaw_pitch_roll();
+        assert!(float_eq(y, FRAC_PI_3) && float_eq(p, 0.0) && float_eq(r, 0.0));
+        let (y, p, r) = Motor::rotation(Blade::e23, FRAC_PI_4).yaw_pitch_roll();
+        assert!(float_eq(y, 0.0) && float_eq(p, FRAC_PI_4) && float_eq(r, 0.0));
+        let (y, p, r) = Motor::rotation(Blade::e12, -FRAC_PI_4).yaw_pitch_roll();
+        assert!(float_eq(y, 0.0) && float_eq(p, 0.0) && float_eq(r, -FRAC_PI_4));
+    }
+
+    #[test]
+    fn yaw_pitch_roll_gimbal_lock() {
+        let m = Motor::from_yaw_pitch_roll(0.4, FRAC_PI_2, 0.5);
+        let (y, p, r) = m.yaw_pitch_roll();
+        assert!(float_eq(p, FRAC_PI_2) && float_eq(r, 0.0));
+        assert!(same_pose(Motor::from_yaw_pitch_roll(y, p, r), m));
+    }
+
+    #[test]
+    fn yaw_pitch_roll_near_gimbal_lock() {
+        let pitch = FRAC_PI_2 - 0.002;
+        let (y, p, r) = Motor::from_yaw_pitch_roll(0.4, pitch, 0.0).yaw_pitch_roll();
+        assert!(float_eq(y, 0.4) && float_eq(p, pitch) && float_eq(r, 0.0));
+    }
+}
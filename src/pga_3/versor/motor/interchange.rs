@@ -0,0 +1,242 @@
+use crate::pga_3::*;
+
+// Conversions to the pose formats other tools use. Quaternions are Hamilton quaternions stored
+// as `[x, y, z, w]` and matrices are column-major `[F; 16]` acting on column vectors, both as
+// in glTF. Every pose is a rotation followed by a translation, so the matrix is `[R | t]` and the
+// motor is the rotation motor times the translation motor.
+//
+// A motor's rotational part `s + a * e12 + b * e31 + c * e23` is the quaternion `[c, b, a, s]`.
+// The line `e12` points along +z (as do `e31` along +y and `e23` along +x), and
+// `Transformation::rotation(axis, angle)` turns by `-angle` about the direction of `axis` in the
+// right-handed sense, so a quaternion turning by `angle` about a unit direction `u` is
+// `Transformation::rotation` about the line through the origin along `u` by `-angle`.
+
+impl<F: Float> Motor<F> {
+    /// The motor of a rigid transformation matrix. Only the rotation and translation are read,
+    /// so the upper 3x3 block should be orthonormal with determinant 1.
+    pub fn from_matrix(m: [F; 16]) -> Motor<F> {
+        let (r00, r10, r20) = (m[0], m[1], m[2]);
+        let (r01, r11, r21) = (m[4], m[5], m[6]);
+        let (r02, r12, r22) = (m[8], m[9], m[10]);
+        // Shepperd's method, dividing by whichever component is largest for stability
+        let trace = r00 + r11 + r22;
+        let q = if trace > F::ZERO {
+            let s = F::from(2.0) * (trace + F::ONE).sqrt();
+            [
+                (r21 - r12) / s,
+                (r02 - r20) / s,
+                (r10 - r01) / s,
+                s / F::from(4.0),
+            ]
+        } else if r00 > r11 && r00 > r22 {
+            let s = F::from(2.0) * (F::ONE + r00 - r11 - r22).sqrt();
+            [
+                s / F::from(4.0),
+                (r01 + r10) / s,
+                (r02 + r20) / s,
+                (r21 - r12) / s,
+            ]
+        } else if r11 > r22 {
+            let s = F::from(2.0) * (F::ONE + r11 - r00 - r22).sqrt();
+            [
+                (r01 + r10) / s,
+                s / F::from(4.0),
+                (r12 + r21) / s,
+                (r02 - r20) / s,
+            ]
+        } else {
+            let s = F::from(2.0) * (F::ONE + r22 - r00 - r11).sqrt();
+            [
+                (r02 + r20) / s,
+                (r12 + r21) / s,
+                s / F::from(4.0),
+                (r10 - r01) / s,
+            ]
+        };
+        Motor::from_quaternion(q, [m[12], m[13], m[14]])
+    }
+
+    /// Column-major matrix taking `[x, y, z, 1]` to the coordinates of `self >> point(x, y, z)`
+    pub fn to_matrix(self) -> [F; 16] {
+        let ([x, y, z, w], t) = self.to_quaternion();
+        [
+            F::ONE - F::from(2.0) * (y * y + z * z),
+            F::from(2.0) * (x * y + z * w),
+            F::from(2.0) * (x * z - y * w),
+            F::ZERO,
+            F::from(2.0) * (x * y - z * w),
+            F::ONE - F::from(2.0) * (x * x + z * z),
+            F::from(2.0) * (y * z + x * w),
+            F::ZERO,
+            F::from(2.0) * (x * z + y * w),
+            F::from(2.0) * (y * z - x * w),
+            F::ONE - F::from(2.0) * (x * x + y * y),
+            F::ZERO,
+            t[0],
+            t[1],
+            t[2],
+            F::ONE,
+        ]
+    }
+
+    /// The motor rotating by the unit quaternion `rotation` and then moving by `translation`
+    pub fn from_quaternion(rotation: [F; 4], translation: [F; 3]) -> Motor<F> {
+        let [x, y, z, w] = rotation;
+        let r = Motor::from([w, z, y, x, F::ZERO, F::ZERO, F::ZERO, F::ZERO]);
+        let t = Motor::trans_coords(translation[0], translation[1], translation[2]);
+        r * t
+    }
+
+    /// Splits the normalized motor into a unit quaternion and the translation applied after it
+    pub fn to_quaternion(self) -> ([F; 4], [F; 3]) {
+        let r = self.rotation_factor();
+        // t = 1 - (x * e01 + y * e02 + z * e03) / 2
+        let t = self.translation_factor();
+        (
+            [r[3], r[2], r[1], r[0]],
+            [
+                -F::from(2.0) * t[4],
+                -F::from(2.0) * t[5],
+                -F::from(2.0) * t[6],
+            ],
+        )
+    }
+
+    /// The motor of a unit dual quaternion `real + dual * ε`, where `dual = t * real / 2` for the
+    /// pure quaternion `t` of the translation applied after the rotation
+    pub fn from_dual_quaternion(real: [F; 4], dual: [F; 4]) -> Motor<F> {
+        let [x, y, z, w] = real;
+        let [tx, ty, tz, _] = quaternion_product(dual, [-x, -y, -z, w]);
+        Motor::from_quaternion(
+            real,
+            [F::from(2.0) * tx, F::from(2.0) * ty, F::from(2.0) * tz],
+        )
+    }
+
+    /// The unit dual quaternion `(real, dual)` of the motor, in the convention of
+    /// `Motor::from_dual_quaternion`
+    pub fn to_dual_quaternion(self) -> ([F; 4], [F; 4]) {
+        let (real, [tx, ty, tz]) = self.to_quaternion();
+        let dual = quaternion_product(
+            [
+                tx / F::from(2.0),
+                ty / F::from(2.0),
+                tz / F::from(2.0),
+                F::ZERO,
+            ],
+            real,
+        );
+        (real, dual)
+    }
+}
+
+/// Hamilton product of two `[x, y, z, w]` quaternions
+fn quaternion_product<F: Float>(a: [F; 4], b: [F; 4]) -> [F; 4] {
+    let [ax, ay, az, aw] = a;
+    let [bx, by, bz, bw] = b;
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::float_eq;
+    use std::f32::consts::{FRAC_1_SQRT_2, FRAC_PI_2, PI};
+
+    fn sample() -> Motor {
+        let axis = Bivector::from((
+            Trivector::point(1.0, -2.0, 0.5),
+            Trivector::direction(1.0, 2.0, 2.0),
+        ));
+        Motor::screw(Blade::new(axis).unwrap(), 2.0, 3.0)
+    }
+
+    fn transform_point(m: [f32; 16], p: [f32; 3]) -> Trivector {
+        let coord =
+            |row: usize| m[row] * p[0] + m[4 + row] * p[1] + m[8 + row] * p[2] + m[12 + row];
+        Trivector::point(coord(0), coord(1), coord(2))
+    }
+
+    fn same(a: &[f32], b: &[f32]) -> bool {
+        a.iter().zip(b).all(|(x, y)| float_eq(*x, *y))
+    }
+
+    fn same_pose(a: Motor, b: Motor) -> bool {
+        // m and -m are the same transformation
+        a == b || a == -b
+    }
+
+    #[test]
+    fn rotation_convention() {
+        let m = Motor::rotation(Blade::e12, FRAC_PI_2);
+        let (q, t) = m.to_quaternion();
+        assert!(float_eq(q[2], -FRAC_1_SQRT_2) && float_eq(q[3], FRAC_1_SQRT_2));
+        assert!(same(&t, &[0.0; 3]));
+        assert_eq!(
+            transform_point(m.to_matrix(), [1.0, 0.0, 0.0]),
+            Trivector::point(0.0, -1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn matrix_applies_motor() {
+        let m = sample();
+        let matrix = m.to_matrix();
+        for p in [[0.0, 0.0, 0.0], [1.0, 2.0, 3.0], [-4.0, 0.5, 2.0]] {
+            assert_eq!(
+                transform_point(matrix, p),
+                (m >> Trivector::point(p[0], p[1], p[2])).normalize()
+            );
+        }
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let m = sample();
+        assert!(same_pose(Motor::from_matrix(m.to_matrix()), m.normalize()));
+        for angle in [0.0, FRAC_PI_2, PI] {
+            for axis in [Blade::e12, Blade::e31, Blade::e23] {
+                let r = Motor::rotation(axis, angle);
+                assert!(same_pose(Motor::from_matrix(r.to_matrix()), r));
+            }
+        }
+    }
+
+    #[test]
+    fn quaternion_round_trip() {
+        let m = sample();
+        let (q, t) = m.to_quaternion();
+        assert!(same_pose(Motor::from_quaternion(q, t), m.normalize()));
+        let q = [0.5, -0.5, 0.5, 0.5];
+        let (q2, t2) = Motor::from_quaternion(q, [1.0, 2.0, 3.0]).to_quaternion();
+        assert!(same(&q2, &q));
+        assert!(same(&t2, &[1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn translation_is_applied_after_rotation() {
+        let m = Motor::from_quaternion([0.0, 0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2], [5.0, 0.0, 0.0]);
+        assert_eq!(
+            (m >> Trivector::point(1.0, 0.0, 0.0)).normalize(),
+            Trivector::point(5.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn dual_quaternion_round_trip() {
+        let m = sample();
+        let (real, dual) = m.to_dual_quaternion();
+        assert!(same_pose(
+            Motor::from_dual_quaternion(real, dual),
+            m.normalize()
+        ));
+        let (real, dual) = Motor::trans_coords(2.0, 4.0, 6.0).to_dual_quaternion();
+        assert!(same(&real, &[0.0, 0.0, 0.0, 1.0]));
+        assert!(same(&dual, &[1.0, 2.0, 3.0, 0.0]));
+    }
+}
@@ -1,68 +1,98 @@
+use super::products;
 use crate::pga_3::*;
 use crate::util::float_eq;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, Div, Mul, Neg, Shl, Shr, Sub};
 use std::ops::{Index, IndexMut};
-use std::simd::{Simd, simd_swizzle};
 use std::slice::SliceIndex;
 
 #[derive(Clone, Copy, Debug)]
-pub struct OddVersor {
-    components: Simd<f32, 8>,
+pub struct OddVersor<F: Float = f32> {
+    pub(super) components: Lanes<F, 8>,
 }
-impl From<[f32; 8]> for OddVersor {
+impl<F: Float> From<[F; 8]> for OddVersor<F> {
     #[inline]
-    fn from(cs: [f32; 8]) -> Self {
+    fn from(cs: [F; 8]) -> Self {
         OddVersor {
-            components: Simd::from(cs),
+            components: Lanes::from(cs),
         }
     }
 }
 
-impl From<Simd<f32, 8>> for OddVersor {
+impl<F: Float> From<Lanes<F, 8>> for OddVersor<F> {
     #[inline]
-    fn from(cs: Simd<f32, 8>) -> Self {
+    fn from(cs: Lanes<F, 8>) -> Self {
         OddVersor { components: cs }
     }
 }
 
-impl From<Vector> for OddVersor {
+impl<F: Float> From<Vector<F>> for OddVersor<F> {
     #[inline]
-    fn from(v: Vector) -> Self {
-        Self::from([v[0], v[1], v[2], v[3], 0.0, 0.0, 0.0, 0.0])
+    fn from(v: Vector<F>) -> Self {
+        Self::from([v[0], v[1], v[2], v[3], F::ZERO, F::ZERO, F::ZERO, F::ZERO])
     }
 }
 
-impl From<Trivector> for OddVersor {
-    fn from(tv: Trivector) -> Self {
-        Self::from([0.0, 0.0, 0.0, 0.0, tv[0], tv[1], tv[2], tv[3]])
+impl<F: Float> From<Trivector<F>> for OddVersor<F> {
+    fn from(tv: Trivector<F>) -> Self {
+        Self::from([
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            F::ZERO,
+            tv[0],
+            tv[1],
+            tv[2],
+            tv[3],
+        ])
     }
 }
 
-impl From<(Vector, Trivector)> for OddVersor {
+impl<F: Float> From<(Vector<F>, Trivector<F>)> for OddVersor<F> {
     #[inline]
-    fn from(cs: (Vector, Trivector)) -> Self {
+    fn from(cs: (Vector<F>, Trivector<F>)) -> Self {
         OddVersor {
-            components: Simd::from([
+            components: Lanes::from([
                 cs.0[0], cs.0[1], cs.0[2], cs.0[3], cs.1[0], cs.1[1], cs.1[2], cs.1[3],
             ]),
         }
     }
 }
 
-impl<Idx: SliceIndex<[f32]>> Index<Idx> for OddVersor {
+impl<F: Float> TryFrom<Transformation<F>> for OddVersor<F> {
+    type Error = PgaError;
+
+    /// The odd versor of a reflection. Rigid motions are motors, and fail with
+    /// `PgaError::ParityMismatch`.
+    #[inline]
+    fn try_from(t: Transformation<F>) -> Result<Self, Self::Error> {
+        use Transformation::*;
+        match t {
+            Reflection { plane } => Ok(OddVersor::reflection(plane)),
+            RotoReflection { axis, angle, plane } => {
+                Ok(OddVersor::roto_reflection(axis, angle, plane))
+            }
+            Glide { plane, direction } => Ok(OddVersor::glide(plane, direction)),
+            Rotation { .. } | Translation { .. } | Screw { .. } => {
+                Err(PgaError::ParityMismatch { expected_odd: true })
+            }
+        }
+    }
+}
+
+impl<F: Float, Idx: SliceIndex<[F]>> Index<Idx> for OddVersor<F> {
     type Output = Idx::Output;
     fn index(&self, index: Idx) -> &Self::Output {
         &self.components[index]
     }
 }
 
-impl<Idx: SliceIndex<[f32]>> IndexMut<Idx> for OddVersor {
+impl<F: Float, Idx: SliceIndex<[F]>> IndexMut<Idx> for OddVersor<F> {
     fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
         &mut self.components[index]
     }
 }
 
-impl PartialEq for OddVersor {
+impl<F: Float> PartialEq for OddVersor<F> {
     fn eq(&self, other: &Self) -> bool {
         self.components
             .as_array()
@@ -72,7 +102,7 @@ impl PartialEq for OddVersor {
     }
 }
 
-impl Neg for OddVersor {
+impl<F: Float> Neg for OddVersor<F> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -82,7 +112,7 @@ impl Neg for OddVersor {
     }
 }
 
-impl Add for OddVersor {
+impl<F: Float> Add for OddVersor<F> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
         OddVersor {
@@ -91,7 +121,7 @@ impl Add for OddVersor {
     }
 }
 
-impl Sub for OddVersor {
+impl<F: Float> Sub for OddVersor<F> {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
         OddVersor {
@@ -100,34 +130,65 @@ impl Sub for OddVersor {
     }
 }
 
-impl Mul<Scalar> for OddVersor {
+impl<F: Float> Mul<F> for OddVersor<F> {
     type Output = Self;
-    fn mul(self, rhs: Scalar) -> Self::Output {
+    fn mul(self, rhs: F) -> Self::Output {
         OddVersor {
-            components: self.components * Simd::splat(rhs),
+            components: self.components * Lanes::splat(rhs),
         }
     }
 }
 
-impl<T: Multivector + NonScalar> Mul<T> for OddVersor {
-    type Output = Versor;
-    fn mul(self, rhs: T) -> Self::Output {
-        self.geo(rhs)
+impl<F: Float> Mul<Motor<F>> for OddVersor<F> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Motor<F>) -> Self::Output {
+        OddVersor::from(products::odd_even(self.components, rhs.components))
+    }
+}
+
+impl<F: Float> Mul for OddVersor<F> {
+    type Output = Motor<F>;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Motor::from(products::odd_odd(self.components, rhs.components))
     }
 }
 
-impl Div<Scalar> for OddVersor {
+impl<F: Float, T> Shr<T> for OddVersor<F>
+where
+    T: SingleGrade<Pga3<F>> + NonScalar + TryFrom<Multivector16<F>> + 'static,
+{
+    type Output = T;
+    fn shr(self, rhs: T) -> Self::Output {
+        let res: T = super::projected(self.reverse().geo(rhs).geo(self), rhs.highest_grade());
+        mirrored(res, rhs.highest_grade())
+    }
+}
+
+impl<F: Float, T> Shl<T> for OddVersor<F>
+where
+    T: SingleGrade<Pga3<F>> + NonScalar + TryFrom<Multivector16<F>> + 'static,
+{
+    type Output = T;
+    fn shl(self, rhs: T) -> Self::Output {
+        let res: T = super::projected(self.geo(rhs).geo(self.reverse()), rhs.highest_grade());
+        mirrored(res, rhs.highest_grade())
+    }
+}
+
+impl<F: Float> Div<F> for OddVersor<F> {
     type Output = Self;
-    fn div(self, rhs: Scalar) -> Self::Output {
+    fn div(self, rhs: F) -> Self::Output {
         OddVersor {
-            components: self.components / Simd::splat(rhs),
+            components: self.components / Lanes::splat(rhs),
         }
     }
 }
 
-impl Multivector for OddVersor {
+impl<F: Float> Multivector<Pga3<F>> for OddVersor<F> {
     #[inline]
-    fn e(&self, basis: u8) -> f32 {
+    fn e(&self, basis: u8) -> F {
         match basis {
             0b0100 => self[0],
             0b0010 => self[1],
@@ -137,25 +198,25 @@ impl Multivector for OddVersor {
             0b1011 => self[5],
             0b1101 => self[6],
             0b1110 => self[7],
-            _ => 0.0,
+            _ => F::ZERO,
         }
     }
 
     #[inline]
-    fn grade(&self, g: u8) -> AnyKVector {
+    fn grade(&self, g: u8) -> AnyKVector<F> {
         match g {
-            1 => Vector::from(simd_swizzle!(self.components, [0, 1, 2, 3])).into(),
-            3 => Trivector::from(simd_swizzle!(self.components, [4, 5, 6, 7])).into(),
-            _ => 0.0.into(),
+            1 => Vector::from(swizzle!(self.components, [0, 1, 2, 3])).into(),
+            3 => Trivector::from(swizzle!(self.components, [4, 5, 6, 7])).into(),
+            _ => F::ZERO.into(),
         }
     }
 
     #[inline]
     fn highest_grade(&self) -> u8 {
-        if self[4..8] != [0.0; 4] {
+        if self[4..8] != [F::ZERO; 4] {
             return 3;
         }
-        if self[0..4] != [0.0; 4] {
+        if self[0..4] != [F::ZERO; 4] {
             return 1;
         }
         0
@@ -175,83 +236,97 @@ impl Multivector for OddVersor {
 
     #[inline]
     fn zero(&self) -> bool {
-        self.components == Simd::splat(0.0)
+        self.components == Lanes::splat(F::ZERO)
     }
 
     #[inline]
     fn is_ideal(&self) -> bool {
-        self[3] == 0.0 && self[5..8] == [0.0; 3]
+        self[3] == F::ZERO && self[5..8] == [F::ZERO; 3]
     }
 
     #[inline]
-    fn dual(self) -> Versor {
+    fn dual(self) -> Versor<F> {
         Versor::from(OddVersor::from([
             -self[5], -self[6], -self[7], -self[4], self[3], self[0], self[1], self[2],
         ]))
     }
 
     #[inline]
-    fn undual(self) -> Versor {
+    fn undual(self) -> Versor<F> {
         -self.dual()
     }
 
     #[inline]
-    fn geo<T: Multivector>(self, rhs: T) -> Versor {
-        use AnyKVector::*;
+    fn geo<T: Multivector<Pga3<F>>>(self, rhs: T) -> Versor<F> {
         use Versor::*;
-        let One(self_g1) = self.grade(1) else {
-            panic!("Grade 1 part of odd versor should be vector");
-        };
-        let Three(self_g3) = self.grade(3) else {
-            panic!("Grade 3 part of odd versor should be trivector");
-        };
         match rhs.into() {
             KVec(kv) => kv.reverse().geo(self.reverse()).reverse(),
-            Even(m) => {
-                let t1 = match self_g1.geo(m) {
-                    Odd(ov) => ov,
-                    KVec(Zero(0.0)) => OddVersor::from([0.0; 8]),
-                    KVec(One(v)) => OddVersor::from(v),
-                    KVec(Three(tv)) => OddVersor::from(tv),
-                    _ => panic!("Vector * Motor should be odd versor"),
-                };
-                let t2 = match self_g3.geo(m) {
-                    Odd(ov) => ov,
-                    KVec(Zero(0.0)) => OddVersor::from([0.0; 8]),
-                    KVec(One(v)) => OddVersor::from(v),
-                    KVec(Three(tv)) => OddVersor::from(tv),
-                    _ => panic!("Triector * Motor should be odd versor"),
-                };
-                Versor::from(t1 + t2)
-            }
-            Odd(ov) => {
-                let t1 = match self_g1.geo(ov) {
-                    Even(m) => m,
-                    KVec(Zero(0.0)) => Motor::from([0.0; 8]),
-                    KVec(Two(bv)) => Motor::from(bv),
-                    KVec(Four(ps)) => Motor::from(ps),
-                    _ => panic!("Vector * odd versor should be motor"),
-                };
-                let t2 = match self_g3.geo(ov) {
-                    Even(m) => m,
-                    KVec(Zero(0.0)) => Motor::from([0.0; 8]),
-                    KVec(Two(bv)) => Motor::from(bv),
-                    KVec(Four(ps)) => Motor::from(ps),
-                    _ => panic!("Trivector * odd versor should be motor"),
-                };
-                Versor::from(t1 + t2)
-            }
+            Even(m) => Versor::from(self * m),
+            Odd(ov) => Versor::from(self * ov),
+            Mixed(mv) => Multivector16::from(self).geo(mv),
         }
     }
 }
 
+impl<F: Float> OddVersor<F> {
+    /// The odd versor of `Transformation::reflection`
+    #[inline]
+    pub fn reflection(plane: Vector<F>) -> Self {
+        OddVersor::from(plane.normalize())
+    }
+
+    /// The odd versor of `Transformation::roto_reflection`
+    #[inline]
+    pub fn roto_reflection(axis: Blade<Bivector<F>>, angle: F, plane: Vector<F>) -> Self {
+        Motor::rotation(axis, angle) * OddVersor::reflection(plane)
+    }
+
+    /// The odd versor of `Transformation::glide`
+    #[inline]
+    pub fn glide(plane: Vector<F>, direction: Trivector<F>) -> Self {
+        OddVersor::reflection(plane) * Motor::translation(direction)
+    }
+
+    /// Applies the reflection, giving the mirror image of `rhs`: planes and lines have their
+    /// normals and directions mirrored, and points keep their weight. The bare sandwich gets the
+    /// orientation of planes and lines backwards, so those grades are negated.
+    /// Fails with `PgaError::GradeMismatch` if the result has parts of other grades, as for
+    /// `Motor::sandwich`, and `ov >> rhs` keeps only the part of the grade of `rhs` instead.
+    pub fn sandwich<T>(self, rhs: T) -> Result<T, PgaError>
+    where
+        T: SingleGrade<Pga3<F>> + NonScalar + TryFrom<Multivector16<F>> + 'static,
+    {
+        super::sandwiched(self.reverse().geo(rhs).geo(self))
+            .map(|res| mirrored(res, rhs.highest_grade()))
+    }
+
+    /// Applies the inverse of the reflection, with the same sign correction as `sandwich`
+    pub fn reverse_sandwich<T>(self, rhs: T) -> Result<T, PgaError>
+    where
+        T: SingleGrade<Pga3<F>> + NonScalar + TryFrom<Multivector16<F>> + 'static,
+    {
+        super::sandwiched(self.geo(rhs).geo(self.reverse()))
+            .map(|res| mirrored(res, rhs.highest_grade()))
+    }
+}
+
+// Negates the grades whose orientation the bare sandwich gets backwards
+#[inline]
+fn mirrored<F: Float, T: Mul<F, Output = T>>(res: T, grade: u8) -> T {
+    if grade == 1 || grade == 2 {
+        res * -F::ONE
+    } else {
+        res
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
     #[test]
     fn geo_oddversor_vector() {
-        let ov = OddVersor::from([-1.0, 1.0, -5.0, -6.0, 1.0, -6.0, -10.0, -2.0]);
+        let ov: OddVersor = OddVersor::from([-1.0, 1.0, -5.0, -6.0, 1.0, -6.0, -10.0, -2.0]);
         let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
         let expected = Versor::Even(Motor::from([-14.0, 0.0, 0.0, 14.0, -28.0, 0.0, 0.0, 28.0]));
         let expected_reverse = Versor::Even(Motor::from([
@@ -263,7 +338,7 @@ mod test {
 
     #[test]
     fn geo_oddversor_bivector() {
-        let ov = OddVersor::from([-1.0, 1.0, -5.0, -6.0, 1.0, -6.0, -10.0, -2.0]);
+        let ov: OddVersor = OddVersor::from([-1.0, 1.0, -5.0, -6.0, 1.0, -6.0, -10.0, -2.0]);
         let bv = Bivector::from([-5.0, 10.0, -5.0, 15.0, 10.0, 5.0]);
         let expected = Versor::Odd(OddVersor::from([
             -40.0, -30.0, 10.0, -30.0, 40.0, -30.0, 20.0, 60.0,
@@ -275,4 +350,113 @@ mod test {
         assert_eq!(ov * bv, expected);
         assert_eq!(bv * ov, expected_reverse);
     }
+
+    #[test]
+    fn reflect_elements() {
+        let mirror = OddVersor::reflection(e1 * 2.0);
+        assert_eq!(
+            mirror >> Trivector::point(1.0, 2.0, 3.0),
+            Trivector::point(-1.0, 2.0, 3.0)
+        );
+        assert_eq!(
+            mirror >> Vector::from([1.0, 0.0, 0.0, 2.0]),
+            Vector::from([-1.0, 0.0, 0.0, 2.0])
+        );
+        let line = Bivector::from((
+            Trivector::point(1.0, 0.0, 0.0),
+            Trivector::direction(1.0, 1.0, 0.0),
+        ));
+        assert_eq!(
+            mirror >> line,
+            Bivector::from((
+                Trivector::point(-1.0, 0.0, 0.0),
+                Trivector::direction(-1.0, 1.0, 0.0),
+            ))
+        );
+        assert_eq!(mirror >> e0123, -e0123);
+    }
+
+    #[test]
+    fn reflection_is_involution() {
+        let mirror: OddVersor = OddVersor::reflection(Vector::from([1.0, 2.0, -2.0, 3.0]));
+        let p = Trivector::point(4.0, -1.0, 0.5);
+        let line = Bivector::from((p, Trivector::direction(0.0, 1.0, 2.0)));
+        assert_eq!((mirror >> (mirror >> p)), p);
+        assert_eq!((mirror >> (mirror >> line)), line);
+        assert_eq!(mirror << p, mirror >> p);
+    }
+
+    #[test]
+    fn sandwich_large_odd_versor() {
+        let ov: OddVersor =
+            OddVersor::from([312.5, -820.25, 97.0, 401.75, -655.5, 230.0, -48.25, 777.0]);
+        let p = Trivector::point(12.5, -3.25, 40.0);
+        let plane = Vector::from([0.5, -1.5, 2.0, 30.0]);
+        assert_eq!(ov.sandwich(p), Ok(ov >> p));
+        assert_eq!(ov.sandwich(plane), Ok(ov >> plane));
+        assert_eq!(ov.reverse_sandwich(plane), Ok(ov << plane));
+        assert!(OddVersor::from([f32::NAN; 8]).sandwich(p).is_err());
+    }
+
+    #[test]
+    fn reflection_preserves_incidence() {
+        let mirror = OddVersor::reflection(Vector::from([0.0, 1.0, 1.0, 1.0]));
+        let (p, q) = (
+            Trivector::point(1.0, 2.0, 3.0),
+            Trivector::point(-2.0, 0.0, 1.0),
+        );
+        let line = (p & q).assert::<Bivector>();
+        let image = mirror >> line;
+        assert!(float_eq((mirror >> p).distance_to_line(image), 0.0));
+        assert!(float_eq((mirror >> q).distance_to_line(image), 0.0));
+    }
+
+    #[test]
+    fn roto_reflection() {
+        use std::f32::consts::FRAC_PI_2;
+        let t = Transformation::roto_reflection(Blade::e12, FRAC_PI_2, e3);
+        let v = OddVersor::try_from(t).unwrap();
+        let rotated = Motor::rotation(Blade::e12, FRAC_PI_2) >> Trivector::point(1.0, 0.0, 2.0);
+        assert_eq!(
+            v >> Trivector::point(1.0, 0.0, 2.0),
+            Trivector::point(rotated.x(), rotated.y(), -2.0)
+        );
+        assert_eq!(v << (v >> e31), e31);
+    }
+
+    #[test]
+    fn glide() {
+        let t = Transformation::glide(e2, Trivector::direction(3.0, 0.0, 0.0));
+        let v = OddVersor::try_from(t).unwrap();
+        assert_eq!(
+            v >> Trivector::point(1.0, 2.0, 3.0),
+            Trivector::point(4.0, -2.0, 3.0)
+        );
+        assert_eq!(
+            v << Trivector::point(4.0, -2.0, 3.0),
+            Trivector::point(1.0, 2.0, 3.0)
+        );
+        assert!(matches!(Versor::from(t), Versor::Odd(_)));
+        assert!(t.is_reflection());
+    }
+
+    #[test]
+    fn parity_mismatch() {
+        let reflection = Transformation::reflection(e1);
+        let rotation: Transformation = Transformation::rotation(Blade::e12, 1.0);
+        assert_eq!(
+            Motor::try_from(reflection),
+            Err(PgaError::ParityMismatch {
+                expected_odd: false
+            })
+        );
+        assert_eq!(
+            OddVersor::try_from(rotation),
+            Err(PgaError::ParityMismatch { expected_odd: true })
+        );
+        assert_eq!(
+            Versor::from(rotation),
+            Versor::from(Motor::rotation(Blade::e12, 1.0))
+        );
+    }
 }
@@ -11,6 +11,9 @@ pub mod materials;
 
 pub type Ray = Bivector;
 
+/// A packet of eight rays, traced together through `Obj::hit_packet`
+pub type RayX8 = BivectorX8;
+
 impl Trivector {
     #[inline]
     pub fn reflect(self, surface: Vector) -> Trivector {
@@ -45,6 +48,13 @@ impl Ray {
     }
 }
 
+impl RayX8 {
+    #[inline]
+    pub fn forwards(&self) -> TrivectorX8 {
+        TrivectorX8::from([FloatX8::splat(0.0), -self[2], -self[1], -self[0]])
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -90,7 +100,7 @@ mod test {
         let p = Trivector::point(1.0, 2.0, 3.0);
         let d = Trivector::direction(0.0, 1.0, 0.0);
         let r = Ray::from((p, d));
-        let m = Motor::from(Transformation::trans_coords(3.0, 4.0, 5.0));
+        let m = Motor::trans_coords(3.0, 4.0, 5.0);
 
         let r2 = m >> r;
 
@@ -105,7 +115,7 @@ mod test {
         let d = Trivector::direction(0.0, 1.0, 0.0);
         let r = Ray::from((p, d));
 
-        let rotation = Motor::from(Transformation::rotation(e31, PI / 4.0));
+        let rotation = Motor::rotation(Blade::e31, PI / 4.0);
         let r2 = rotation >> r;
 
         assert_eq!(
@@ -119,11 +129,11 @@ mod test {
         let p = Trivector::point(1.0, 2.0, 3.0);
         let d = Trivector::direction(0.0, 1.0, 0.0);
         let r = Ray::from((p, d));
-        let mut c = Camera::new(p, e013, -e021, 500, 500, 0.0);
+        let mut c = Camera::new(p, e013, -e021, 500, 500, 0.0).unwrap();
 
-        let scale = Trivector::scale(2.0, 3.0, 4.0);
-        let r2 = r.scale(scale);
-        c.scale(scale);
+        let scale = Transform::scale(2.0, 3.0, 4.0).unwrap();
+        let r2 = scale >> r;
+        c.transform(scale);
 
         assert_eq!(
             c.location.normalize(),
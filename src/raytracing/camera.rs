@@ -72,7 +72,7 @@ impl Camera {
         hsize: usize,
         vsize: usize,
         fov: f32,
-    ) -> Self {
+    ) -> Result<Self, PgaError> {
         let mut res = Self {
             location,
             forward,
@@ -84,10 +84,10 @@ impl Camera {
             half_width: 0.0,
             half_height: 0.0,
             left: Trivector::direction(0.0, 0.0, 0.0),
-            forward_m: Motor::from(Transformation::translation(forward)),
+            forward_m: Motor::translation(forward),
         };
-        res.update_calculations();
-        res
+        res.update_calculations()?;
+        Ok(res)
     }
 
     #[inline]
@@ -103,7 +103,7 @@ impl Camera {
             half_width: 1.0,
             half_height: 1.0,
             left: Trivector::direction(-1.0, 0.0, 0.0),
-            forward_m: Motor::from(Transformation::translation(-e021)),
+            forward_m: Motor::translation(-e021),
         }
     }
 
@@ -113,7 +113,7 @@ impl Camera {
     }
 
     #[inline]
-    pub fn update_calculations(&mut self) {
+    pub fn update_calculations(&mut self) -> Result<(), PgaError> {
         let half_view = f32::tan(self.fov / 2.0);
         let aspect = (self.hsize as f32) / (self.vsize as f32);
         let (half_height, half_width) = if aspect >= 1.0 {
@@ -124,33 +124,27 @@ impl Camera {
         self.pixel_size = (half_width * 2.0) / (self.hsize as f32);
         self.half_height = half_height;
         self.half_width = half_width;
-        self.left =
-            ((self.up & self.forward).dual().assert::<Bivector>() ^ e0).assert::<Trivector>();
-        self.up =
-            ((self.forward & self.left).dual().assert::<Bivector>() ^ e0).assert::<Trivector>();
-        self.forward_m = Motor::from(Transformation::translation(self.forward));
+        self.left = ((self.up & self.forward).dual().try_assert::<Bivector>()? ^ e0)
+            .try_assert::<Trivector>()?;
+        self.up = ((self.forward & self.left).dual().try_assert::<Bivector>()? ^ e0)
+            .try_assert::<Trivector>()?;
+        self.forward_m = Motor::translation(self.forward);
+        Ok(())
     }
 
+    /// Moves the camera by `t` in world coordinates. `update_calculations` should be called
+    /// afterwards, which also makes `up` perpendicular to `forward` again after a shear.
     #[inline]
-    pub fn transform(&mut self, m: Motor) {
-        self.location = m >> self.location;
-        self.forward = m >> self.forward;
-        self.up = m >> self.up;
+    pub fn transform(&mut self, t: Transform) {
+        self.location = t >> self.location;
+        self.forward = t >> self.forward;
+        self.up = t >> self.up;
         self.up[0] = 0.0;
     }
 
     #[inline]
     pub fn transform_t(&mut self, t: Transformation) {
-        let m = Motor::from(t);
-        self.location = m >> self.location;
-        self.forward = m >> self.forward;
-        self.up = m >> self.up;
-        self.up[0] = 0.0;
-    }
-
-    #[inline]
-    pub fn scale(&mut self, s: Trivector) {
-        self.location = self.location.scale(s);
+        self.transform(t.into());
     }
 
     #[inline]
@@ -159,8 +153,8 @@ impl Camera {
         let y = y as f32;
         let x_offset = (x + 0.5) * self.pixel_size - self.half_width;
         let y_offset = self.half_height - (y + 0.5) * self.pixel_size;
-        let x_translation = Motor::from(Transformation::translation(x_offset * self.left));
-        let y_translation = Motor::from(Transformation::translation(y_offset * self.up));
+        let x_translation = Motor::translation(x_offset * self.left);
+        let y_translation = Motor::translation(y_offset * self.up);
         let p = x_translation >> (y_translation >> (self.forward_m >> self.location));
         Ray::from((p, p - self.location))
     }
@@ -180,10 +174,8 @@ impl Camera {
         let x_half_pixel = half_pixel_offset * self.left;
         let y_half_pixel = half_pixel_offset * self.up;
 
-        let center_x_translation =
-            Motor::from(Transformation::translation(center_x_offset * self.left));
-        let center_y_translation =
-            Motor::from(Transformation::translation(center_y_offset * self.up));
+        let center_x_translation = Motor::translation(center_x_offset * self.left);
+        let center_y_translation = Motor::translation(center_y_offset * self.up);
 
         let mut rays: Vec<Ray> = vec![];
 
@@ -229,19 +221,25 @@ mod test {
 
     #[test]
     fn pixel_size_horizontal() {
-        let camera = Camera::new(e123, e021, -e013, 200, 125, PI / 2.0);
+        let camera = Camera::new(e123, e021, -e013, 200, 125, PI / 2.0).unwrap();
         assert!(float_eq(camera.pixel_size(), 0.01));
     }
 
     #[test]
     fn pixel_size_vertical() {
-        let camera = Camera::new(e123, e021, -e013, 125, 200, PI / 2.0);
+        let camera = Camera::new(e123, e021, -e013, 125, 200, PI / 2.0).unwrap();
         assert!(float_eq(camera.pixel_size(), 0.01));
     }
 
+    #[test]
+    fn up_parallel_to_forward() {
+        let c = Camera::new(e123, e021, -e021, 200, 125, PI / 2.0);
+        assert!(c.is_err());
+    }
+
     #[test]
     fn ray_through_center_canvas() {
-        let c = Camera::new(e123, e021, -e013, 201, 101, PI / 2.0);
+        let c = Camera::new(e123, e021, -e013, 201, 101, PI / 2.0).unwrap();
         let r = c.ray_for_pixel(100, 50);
         assert_eq!(c.location, e123);
         assert_eq!(r.forwards(), Trivector::direction(0.0, 0.0, -1.0));
@@ -249,7 +247,7 @@ mod test {
 
     #[test]
     fn ray_through_corner_canvas() {
-        let c = Camera::new(e123, e021, -e013, 201, 101, PI / 2.0);
+        let c = Camera::new(e123, e021, -e013, 201, 101, PI / 2.0).unwrap();
         let r = c.ray_for_pixel(0, 0);
         assert_eq!(c.location, e123);
         assert_eq!(
@@ -261,12 +259,12 @@ mod test {
     #[test]
     fn ray_through_canvas_when_cam_transformed() {
         use std::f32::consts::SQRT_2;
-        let mut c = Camera::new(e123, e021, -e013, 201, 101, PI / 2.0);
-        let m1 = Motor::from(Transformation::rotation(e31, PI / 4.0));
-        let m2 = Motor::from(Transformation::trans_coords(0.0, -2.0, 5.0));
-        c.transform(m1);
-        c.transform(m2);
-        c.update_calculations();
+        let mut c = Camera::new(e123, e021, -e013, 201, 101, PI / 2.0).unwrap();
+        let m1 = Motor::rotation(Blade::e31, PI / 4.0);
+        let m2 = Motor::trans_coords(0.0, -2.0, 5.0);
+        c.transform(m1.into());
+        c.transform(m2.into());
+        c.update_calculations().unwrap();
         let r = c.ray_for_pixel(100, 50);
         assert_eq!(c.location, Trivector::point(0.0, -2.0, 5.0));
         assert_eq!(
@@ -274,4 +272,18 @@ mod test {
             Trivector::direction(SQRT_2 / 2.0, 0.0, -SQRT_2 / 2.0)
         );
     }
+
+    #[test]
+    fn mirrored_camera() {
+        let mut c = Camera::new(e123, e021, -e013, 201, 101, PI / 2.0).unwrap();
+        c.transform_t(Transformation::reflection(Vector::from([
+            0.0, 0.0, 1.0, 2.0,
+        ])));
+        c.update_calculations().unwrap();
+        assert_eq!(c.location, Trivector::point(0.0, 0.0, 4.0));
+        assert_eq!(
+            c.ray_for_pixel(100, 50).forwards(),
+            Trivector::direction(0.0, 0.0, 1.0)
+        );
+    }
 }
@@ -3,9 +3,11 @@ use crate::raytracing::lighting::*;
 use crate::raytracing::materials::*;
 use crate::raytracing::*;
 pub mod plane;
+pub mod polytope;
 pub mod sphere;
 pub mod world;
 pub use plane::*;
+pub use polytope::*;
 pub use sphere::*;
 pub use world::*;
 
@@ -20,39 +22,43 @@ pub trait Obj: Sealed {
         self.intersect_from_origin(r, c.location)
     }
     fn intersect_from_origin(&self, r: Ray, p: Trivector) -> Vec<Intersection<'_>> {
-        let origin = (self.get_transform() << p).scale(self.get_scale().reciprocal());
-        let r_t = ((origin
-            + (self.get_transform() << r.forwards()).scale(self.get_scale().reciprocal()))
-            & origin)
-            .assert::<Bivector>();
+        let inverse = self.inverse_transform();
+        let origin = inverse >> p;
+        let r_t = ((origin + (inverse >> r.forwards())) & origin).assert::<Bivector>();
         self.local_intersect_from_origin(r_t, origin)
     }
+    fn local_hit_packet(&self, r: RayX8, p: TrivectorX8) -> FloatX8;
+    /// The nearest positive `t` of each ray in the packet, or infinity where it misses, as the
+    /// packet form of `intersect_from_origin(r, p).hit()`
+    fn hit_packet(&self, r: RayX8, p: TrivectorX8) -> FloatX8 {
+        let inverse = self.inverse_transform();
+        let origin = inverse >> p;
+        let r_t = (origin + (inverse >> r.forwards())) & origin;
+        self.local_hit_packet(r_t, origin)
+    }
     fn local_surface_at(&self, p: Trivector) -> Vector;
     fn surface_at(&self, p: Trivector) -> Vector {
-        let p = (self.get_transform() << p).scale(self.get_scale().reciprocal()) - e123;
-        let mut n = self.get_transform()
-            >> self
-                .local_surface_at(p)
-                .scale(self.get_scale())
-                .scale_slope(self.get_scale().reciprocal());
-        n[3] = 0.0;
-        n.normalize()
+        let p = (self.inverse_transform() >> p) - e123;
+        self.get_transform().normal(self.local_surface_at(p))
     }
     fn material(&self) -> &Material;
     fn material_mut(&mut self) -> &mut Material;
     fn set_material(&mut self, m: Material);
     fn transform_t(&mut self, t: Transformation);
-    fn transform(&mut self, m: Motor);
-    fn get_transform(&self) -> Motor;
-    fn get_scale(&self) -> Trivector;
-    fn set_scale(&mut self, new_scale: Trivector);
-    fn scale(&mut self, scale: Trivector);
+    /// Applies `t` after the object's current transform, ie in world coordinates
+    fn transform(&mut self, t: Transform);
+    /// The transform taking the object's local coordinates to world coordinates
+    fn get_transform(&self) -> Transform;
+    fn inverse_transform(&self) -> Transform {
+        self.get_transform().inverse()
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Object {
     Sphere(Sphere),
     Plane(Plane),
+    Polytope(Polytope),
 }
 
 impl From<Sphere> for Object {
@@ -67,6 +73,12 @@ impl From<Plane> for Object {
     }
 }
 
+impl From<Polytope> for Object {
+    fn from(p: Polytope) -> Self {
+        Object::Polytope(p)
+    }
+}
+
 impl Sealed for Object {}
 impl Obj for Object {
     #[inline]
@@ -75,6 +87,7 @@ impl Obj for Object {
         match self {
             Sphere(s) => s.local_intersect_from_origin(r, p),
             Plane(pl) => pl.local_intersect_from_origin(r, p),
+            Polytope(po) => po.local_intersect_from_origin(r, p),
         }
     }
 
@@ -84,6 +97,27 @@ impl Obj for Object {
         match self {
             Sphere(s) => s.intersect_from_origin(r, p),
             Plane(pl) => pl.intersect_from_origin(r, p),
+            Polytope(po) => po.intersect_from_origin(r, p),
+        }
+    }
+
+    #[inline]
+    fn local_hit_packet(&self, r: RayX8, p: TrivectorX8) -> FloatX8 {
+        use Object::*;
+        match self {
+            Sphere(s) => s.local_hit_packet(r, p),
+            Plane(pl) => pl.local_hit_packet(r, p),
+            Polytope(po) => po.local_hit_packet(r, p),
+        }
+    }
+
+    #[inline]
+    fn hit_packet(&self, r: RayX8, p: TrivectorX8) -> FloatX8 {
+        use Object::*;
+        match self {
+            Sphere(s) => s.hit_packet(r, p),
+            Plane(pl) => pl.hit_packet(r, p),
+            Polytope(po) => po.hit_packet(r, p),
         }
     }
 
@@ -93,6 +127,7 @@ impl Obj for Object {
         match self {
             Sphere(s) => s.local_surface_at(p),
             Plane(pl) => pl.local_surface_at(p),
+            Polytope(po) => po.local_surface_at(p),
         }
     }
 
@@ -102,6 +137,7 @@ impl Obj for Object {
         match self {
             Sphere(s) => s.surface_at(p),
             Plane(pl) => pl.surface_at(p),
+            Polytope(po) => po.surface_at(p),
         }
     }
 
@@ -111,6 +147,7 @@ impl Obj for Object {
         match self {
             Sphere(s) => &s.material,
             Plane(pl) => &pl.material,
+            Polytope(po) => &po.material,
         }
     }
 
@@ -120,6 +157,7 @@ impl Obj for Object {
         match self {
             Sphere(s) => &mut s.material,
             Plane(pl) => &mut pl.material,
+            Polytope(po) => &mut po.material,
         }
     }
 
@@ -129,6 +167,7 @@ impl Obj for Object {
         match self {
             Sphere(s) => s.material = m,
             Plane(pl) => pl.material = m,
+            Polytope(po) => po.material = m,
         };
     }
 
@@ -138,51 +177,27 @@ impl Obj for Object {
         match self {
             Sphere(s) => s.transform_t(t),
             Plane(pl) => pl.transform_t(t),
+            Polytope(po) => po.transform_t(t),
         };
     }
 
     #[inline]
-    fn transform(&mut self, m: Motor) {
+    fn transform(&mut self, t: Transform) {
         use Object::*;
         match self {
-            Sphere(s) => s.transform(m),
-            Plane(pl) => pl.transform(m),
+            Sphere(s) => s.transform(t),
+            Plane(pl) => pl.transform(t),
+            Polytope(po) => po.transform(t),
         }
     }
 
     #[inline]
-    fn get_transform(&self) -> Motor {
+    fn get_transform(&self) -> Transform {
         use Object::*;
         match self {
             Sphere(s) => s.transform,
             Plane(p) => p.transform,
-        }
-    }
-
-    #[inline]
-    fn get_scale(&self) -> Trivector {
-        use Object::*;
-        match self {
-            Sphere(s) => s.scale,
-            Plane(pl) => pl.scale,
-        }
-    }
-
-    #[inline]
-    fn set_scale(&mut self, scale: Trivector) {
-        use Object::*;
-        match self {
-            Sphere(s) => s.set_scale(scale),
-            Plane(pl) => pl.set_scale(scale),
-        }
-    }
-
-    #[inline]
-    fn scale(&mut self, scale: Trivector) {
-        use Object::*;
-        match self {
-            Sphere(s) => s.scale(scale),
-            Plane(pl) => pl.scale(scale),
+            Polytope(po) => po.transform,
         }
     }
 }
@@ -191,6 +206,7 @@ impl Obj for Object {
 pub enum ObjectRef<'a> {
     Sphere(&'a Sphere),
     Plane(&'a Plane),
+    Polytope(&'a Polytope),
 }
 
 impl<'a> From<&'a Object> for ObjectRef<'a> {
@@ -198,6 +214,7 @@ impl<'a> From<&'a Object> for ObjectRef<'a> {
         match o {
             Object::Sphere(s) => ObjectRef::Sphere(&s),
             Object::Plane(pl) => ObjectRef::Plane(&pl),
+            Object::Polytope(po) => ObjectRef::Polytope(po),
         }
     }
 }
@@ -208,6 +225,7 @@ impl ObjectRef<'_> {
         match self {
             Sphere(s) => s.intersect(r, c),
             Plane(pl) => pl.intersect(r, c),
+            Polytope(po) => po.intersect(r, c),
         }
     }
 
@@ -216,6 +234,7 @@ impl ObjectRef<'_> {
         match self {
             Sphere(s) => s.surface_at(p),
             Plane(pl) => pl.surface_at(p),
+            Polytope(po) => po.surface_at(p),
         }
     }
 
@@ -224,24 +243,17 @@ impl ObjectRef<'_> {
         match self {
             Sphere(s) => &s.material,
             Plane(pl) => &pl.material,
+            Polytope(po) => &po.material,
         }
     }
 
     #[inline]
-    pub fn get_transform(&self) -> &Motor {
+    pub fn get_transform(&self) -> &Transform {
         use ObjectRef::*;
         match self {
             Sphere(s) => &s.transform,
             Plane(p) => &p.transform,
-        }
-    }
-
-    #[inline]
-    pub fn get_scale(&self) -> &Trivector {
-        use ObjectRef::*;
-        match self {
-            Sphere(s) => &s.scale,
-            Plane(p) => &p.scale,
+            Polytope(po) => &po.transform,
         }
     }
 }
@@ -253,6 +265,7 @@ impl<'a> PartialEq for ObjectRef<'a> {
         match (self, other) {
             (Sphere(s1), Sphere(s2)) => s1 == s2,
             (Plane(pl1), Plane(pl2)) => pl1 == pl2,
+            (Polytope(po1), Polytope(po2)) => po1 == po2,
             _ => false,
         }
     }
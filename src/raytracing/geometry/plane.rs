@@ -4,12 +4,14 @@ use crate::pga_3::*;
 use crate::raytracing::intersections::*;
 use crate::raytracing::materials::*;
 use crate::raytracing::*;
+use crate::util::EPSILON;
+use std::simd::cmp::SimdPartialOrd;
+use std::simd::num::SimdFloat;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Plane {
     pub vector: Vector,
-    pub transform: Motor,
-    pub scale: Trivector,
+    pub transform: Transform,
     pub material: Material,
 }
 
@@ -17,7 +19,8 @@ impl Sealed for Plane {}
 impl Obj for Plane {
     #[inline]
     fn local_intersect_from_origin(&self, r: Ray, origin: Trivector) -> Vec<Intersection<'_>> {
-        let AnyKVector::Three(intersection) = (r ^ self.vector).normalize() else {
+        // A ray lying in the plane meets it in zero, which can't be normalized
+        let Ok(AnyKVector::Three(intersection)) = (r ^ self.vector).try_normalize() else {
             return vec![];
         };
 
@@ -44,6 +47,23 @@ impl Obj for Plane {
         self.local_intersect_from_origin(r, origin)
     }
 
+    fn local_hit_packet(&self, r: RayX8, origin: TrivectorX8) -> FloatX8 {
+        let intersection = r ^ VectorX8::splat(self.vector);
+        let d = r.forwards();
+        let t = (intersection.normalize() - origin.normalize()).dot(d) / d.dot(d);
+        // Rays parallel to the plane meet it at an ideal point, and rays lying in it at zero
+        let finite = intersection[0].abs().simd_ge(FloatX8::splat(EPSILON));
+        packet_hit(
+            finite.select(t, FloatX8::splat(f32::NAN)),
+            FloatX8::splat(f32::NAN),
+        )
+    }
+
+    #[inline]
+    fn hit_packet(&self, r: RayX8, origin: TrivectorX8) -> FloatX8 {
+        self.local_hit_packet(r, origin)
+    }
+
     #[inline]
     fn local_surface_at(&self, _: Trivector) -> Vector {
         self.vector
@@ -70,50 +90,27 @@ impl Obj for Plane {
     }
 
     #[inline]
-    fn transform(&mut self, m: Motor) {
-        self.vector = m >> self.vector;
-        self.transform = match self.transform * m {
-            Versor::KVec(AnyKVector::Zero(f)) => Motor::from(f),
-            Versor::KVec(AnyKVector::Two(bv)) => Motor::from(bv),
-            Versor::KVec(AnyKVector::Four(ps)) => Motor::from(ps),
-            Versor::Even(m) => m,
-            _ => panic!("Motor * motor should be motor"),
-        }
+    fn transform(&mut self, t: Transform) {
+        self.vector = (t >> self.vector).normalize();
+        self.transform = self.transform * t;
     }
 
     #[inline]
     fn transform_t(&mut self, t: Transformation) {
-        let m = Motor::from(t);
-        self.transform(m);
+        self.transform(t.into());
     }
 
     #[inline]
-    fn get_transform(&self) -> Motor {
+    fn get_transform(&self) -> Transform {
         self.transform
     }
-
-    #[inline]
-    fn get_scale(&self) -> Trivector {
-        self.scale
-    }
-
-    #[inline]
-    fn set_scale(&mut self, new_scale: Trivector) {
-        self.scale = new_scale;
-    }
-
-    #[inline]
-    fn scale(&mut self, scale: Trivector) {
-        self.scale = self.scale.scale(scale);
-    }
 }
 
 impl From<Vector> for Plane {
     fn from(v: Vector) -> Self {
         Self {
             vector: v,
-            transform: Motor::from(1.0),
-            scale: Trivector::scale(1.0, 1.0, 1.0),
+            transform: Transform::identity(),
             material: Material::new(),
         }
     }
@@ -124,8 +121,7 @@ impl Plane {
     pub fn new() -> Self {
         Self {
             vector: e2,
-            transform: Motor::from(1.0),
-            scale: Trivector::scale(1.0, 1.0, 1.0),
+            transform: Transform::identity(),
             material: Material::new(),
         }
     }
@@ -154,6 +150,14 @@ mod test {
         assert_eq!(p.intersect_from_origin(r, ray_origin), vec![]);
     }
 
+    #[test]
+    fn intersect_ray_coplanar() {
+        let p = Plane::new();
+        let ray_origin = Trivector::point(0.0, 0.0, 0.0);
+        let r = Ray::from((ray_origin, Trivector::direction(0.0, 0.0, 1.0)));
+        assert_eq!(p.intersect_from_origin(r, ray_origin), vec![]);
+    }
+
     #[test]
     fn intersect_ray_above() {
         let p = Plane::new();
@@ -208,4 +212,33 @@ mod test {
         assert_eq!(xs[0].t(), -1.0);
         assert_eq!(xs[0].obj(), ObjectRef::Plane(&p));
     }
+
+    #[test]
+    fn hit_packet() {
+        let p = Plane::new();
+        let origins = [
+            Trivector::point(0.0, 10.0, 0.0),
+            Trivector::point(0.0, 0.0, 0.0),
+            Trivector::point(0.0, 1.0, 0.0),
+            Trivector::point(-1.0, 1.0, -1.0),
+            Trivector::point(0.0, 1.0, 0.0),
+            Trivector::point(0.0, -1.0, 0.0),
+            Trivector::point(0.0, -1.0, 0.0),
+            Trivector::point(0.0, 2.0, 0.0),
+        ];
+        let directions = [
+            Trivector::direction(0.0, 0.0, 1.0),
+            Trivector::direction(0.0, 0.0, 1.0),
+            Trivector::direction(0.0, -1.0, 0.0),
+            Trivector::direction(1.0, -1.0, 1.0),
+            Trivector::direction(0.0, 1.0, 0.0),
+            Trivector::direction(0.0, 1.0, 0.0),
+            Trivector::direction(0.0, -1.0, 0.0),
+            Trivector::direction(0.0, -4.0, 1.0),
+        ];
+        let rays = std::array::from_fn(|i| Ray::from((origins[i], directions[i])));
+        let ts = p.hit_packet(RayX8::from(rays), TrivectorX8::from(origins));
+        let inf = f32::INFINITY;
+        assert_eq!(ts.to_array(), [inf, inf, 1.0, 1.0, inf, 1.0, inf, 0.5]);
+    }
 }
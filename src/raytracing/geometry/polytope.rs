@@ -0,0 +1,211 @@
+use super::Sealed;
+use crate::intersections;
+use crate::pga_3::*;
+use crate::raytracing::intersections::*;
+use crate::raytracing::materials::*;
+use crate::raytracing::*;
+use std::simd::cmp::{SimdPartialEq, SimdPartialOrd};
+use std::simd::num::SimdFloat;
+
+/// A convex solid bounded by the planes of a `ConvexPolytope`
+#[derive(Debug, PartialEq, Clone)]
+pub struct Polytope {
+    pub polytope: ConvexPolytope,
+    pub transform: Transform,
+    pub material: Material,
+}
+
+impl Sealed for Polytope {}
+impl Obj for Polytope {
+    // A ray is inside every half-space between the last plane it enters and the first one it
+    // leaves, and the signed distance to each plane changes linearly along it
+    fn local_intersect_from_origin(&self, r: Ray, origin: Trivector) -> Vec<Intersection<'_>> {
+        let origin = origin.normalize();
+        let f = r.forwards();
+        let (mut enter, mut exit) = (f32::NEG_INFINITY, f32::INFINITY);
+        for plane in self.polytope.planes() {
+            let distance = origin.distance_to_plane(*plane);
+            let speed = -(plane[0] * f[1] + plane[1] * f[2] + plane[2] * f[3]);
+            if speed == 0.0 {
+                if distance > 0.0 {
+                    return vec![];
+                }
+                continue;
+            }
+            let t = -distance / speed;
+            if speed < 0.0 {
+                enter = enter.max(t);
+            } else {
+                exit = exit.min(t);
+            }
+        }
+        if enter > exit {
+            return vec![];
+        }
+        intersections![
+            new(enter, ObjectRef::Polytope(self)),
+            new(exit, ObjectRef::Polytope(self))
+        ]
+    }
+
+    fn local_hit_packet(&self, r: RayX8, origin: TrivectorX8) -> FloatX8 {
+        let o = origin.normalize();
+        let f = r.forwards();
+        let zero = FloatX8::splat(0.0);
+        let mut enter = FloatX8::splat(f32::NEG_INFINITY);
+        let mut exit = FloatX8::splat(f32::INFINITY);
+        let mut outside = zero.simd_ne(zero);
+        for plane in self.polytope.planes() {
+            let n = VectorX8::splat(*plane);
+            let distance = -(n[0] * o[1] + n[1] * o[2] + n[2] * o[3]) - n[3];
+            let speed = -(n[0] * f[1] + n[1] * f[2] + n[2] * f[3]);
+            let t = -distance / speed;
+            enter = speed.simd_lt(zero).select(enter.simd_max(t), enter);
+            exit = speed.simd_gt(zero).select(exit.simd_min(t), exit);
+            outside |= speed.simd_eq(zero) & distance.simd_gt(zero);
+        }
+        let miss = outside | enter.simd_gt(exit);
+        let nan = FloatX8::splat(f32::NAN);
+        packet_hit(miss.select(nan, enter), miss.select(nan, exit))
+    }
+
+    /// The face the point is closest to being on
+    #[inline]
+    fn local_surface_at(&self, p: Trivector) -> Vector {
+        let point = p + e123;
+        let face = self
+            .polytope
+            .planes()
+            .iter()
+            .max_by(|a, b| {
+                point
+                    .distance_to_plane(**a)
+                    .total_cmp(&point.distance_to_plane(**b))
+            })
+            .expect("Polytopes have at least four faces");
+        Vector::from([face[0], face[1], face[2], 0.0])
+    }
+
+    #[inline]
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    #[inline]
+    fn material_mut(&mut self) -> &mut Material {
+        &mut self.material
+    }
+
+    #[inline]
+    fn set_material(&mut self, m: Material) {
+        self.material = m;
+    }
+
+    #[inline]
+    fn transform(&mut self, t: Transform) {
+        self.transform = self.transform * t;
+    }
+
+    #[inline]
+    fn transform_t(&mut self, t: Transformation) {
+        self.transform(t.into());
+    }
+
+    #[inline]
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+}
+
+impl From<ConvexPolytope> for Polytope {
+    fn from(polytope: ConvexPolytope) -> Self {
+        Self {
+            polytope,
+            transform: Transform::identity(),
+            material: Material::new(),
+        }
+    }
+}
+
+impl Polytope {
+    /// The cube from -1 to 1 on every axis, matching the default sphere
+    #[inline]
+    pub fn cube() -> Self {
+        let planes = [e1, -e1, e2, -e2, e3, -e3].map(|n| n + e0);
+        Self::from(ConvexPolytope::from_planes(&planes).expect("A cube is a polytope"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::float_eq;
+
+    #[test]
+    fn intersect_cube() {
+        let cube = Polytope::cube();
+        let origin = Trivector::point(0.5, 0.0, -5.0);
+        let r = Ray::from((origin, Trivector::direction(0.0, 0.0, 1.0)));
+        let xs = cube.intersect_from_origin(r, origin);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t(), 4.0);
+        assert_eq!(xs[1].t(), 6.0);
+        assert_eq!(xs[0].obj(), ObjectRef::Polytope(&cube));
+
+        // From inside, and missing past an edge
+        let inside = Trivector::point(0.0, 0.0, 0.0);
+        let r = Ray::from((inside, Trivector::direction(1.0, 1.0, 0.0)));
+        let xs = cube.intersect_from_origin(r, inside);
+        assert_eq!(xs[0].t(), -1.0);
+        assert_eq!(xs[1].t(), 1.0);
+        let r = Ray::from((origin, Trivector::direction(1.0, 0.0, 1.0)));
+        assert_eq!(cube.intersect_from_origin(r, origin), vec![]);
+    }
+
+    #[test]
+    fn transformed_tetrahedron() {
+        let tetrahedron = ConvexPolytope::from_points(&[
+            Trivector::point(0.0, 0.0, 0.0),
+            Trivector::point(1.0, 0.0, 0.0),
+            Trivector::point(0.0, 1.0, 0.0),
+            Trivector::point(0.0, 0.0, 1.0),
+        ])
+        .unwrap();
+        let mut solid = Polytope::from(tetrahedron);
+        solid.transform(Transform::scale(2.0, 2.0, 2.0).unwrap());
+        solid.transform_t(Transformation::trans_coords(0.0, 0.0, 1.0));
+        let origin = Trivector::point(0.5, 0.5, 10.0);
+        let r = Ray::from((origin, Trivector::direction(0.0, 0.0, -1.0)));
+        let xs = solid.intersect_from_origin(r, origin);
+        // Entering through the slanted face x + y + z = 2 and leaving through z = 0
+        assert!(float_eq(xs[0].t(), 8.0));
+        assert!(float_eq(xs[1].t(), 9.0));
+        let n = solid.surface_at(r.position(xs[0].t(), origin));
+        assert_eq!(n, Vector::from([1.0, 1.0, 1.0, 0.0]).normalize());
+        assert_eq!(
+            solid.surface_at(r.position(xs[1].t(), origin)),
+            Vector::from([0.0, 0.0, -1.0, 0.0])
+        );
+    }
+
+    #[test]
+    fn hit_packet_matches_single_rays() {
+        let mut cube = Polytope::cube();
+        cube.transform(Transform::shear(0.5, 0.0, 0.0, 0.0, 0.0, 0.3).unwrap());
+        cube.transform_t(Transformation::rotation(Blade::e12, 0.4));
+        let origin = Trivector::point(0.2, -0.3, -6.0);
+        let rays: [Ray; LANES] = std::array::from_fn(|i| {
+            let i = i as f32;
+            Ray::from((origin, Trivector::direction(0.08 * i - 0.3, 0.05 * i, 1.0)))
+        });
+        let ts = cube.hit_packet(RayX8::from(rays), TrivectorX8::splat(origin));
+        for (i, r) in rays.iter().enumerate() {
+            match cube.intersect_from_origin(*r, origin).hit() {
+                Some(h) => assert!(float_eq(h.t(), ts[i])),
+                None => assert_eq!(ts[i], f32::INFINITY),
+            }
+        }
+        assert!(ts.to_array().iter().any(|t| t.is_finite()));
+        assert!(ts.to_array().iter().any(|t| t.is_infinite()));
+    }
+}
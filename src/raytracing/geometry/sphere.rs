@@ -6,11 +6,11 @@ use crate::raytracing::intersections::*;
 use crate::raytracing::materials::patterns::Pattern;
 use crate::raytracing::materials::*;
 use crate::raytracing::*;
+use std::simd::StdFloat;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Sphere {
-    pub transform: Motor,
-    pub scale: Trivector,
+    pub transform: Transform,
     pub material: Material,
 }
 
@@ -40,6 +40,19 @@ impl Obj for Sphere {
         ]
     }
 
+    // The same intersection in coordinates, solving |o + t * d|^2 = 1 for each ray, as packets
+    // don't have the k-vector products the single-ray version uses
+    fn local_hit_packet(&self, r: RayX8, origin: TrivectorX8) -> FloatX8 {
+        let o = origin.normalize();
+        let d = r.forwards();
+        let a = d.dot(d);
+        let b = o.dot(d);
+        let c = o.dot(o) - FloatX8::splat(1.0);
+        // A negative discriminant gives NaNs, which count as misses
+        let root = (b * b - a * c).sqrt();
+        packet_hit((-b - root) / a, (-b + root) / a)
+    }
+
     #[inline]
     fn local_surface_at(&self, p: Trivector) -> Vector {
         Vector::from([-p[1], -p[2], -p[3], 0.0])
@@ -61,49 +74,26 @@ impl Obj for Sphere {
     }
 
     #[inline]
-    fn transform(&mut self, m: Motor) {
-        self.transform = match self.transform * m {
-            Versor::Even(m) => m,
-            Versor::KVec(AnyKVector::Zero(s)) => Motor::from(s),
-            Versor::KVec(AnyKVector::Two(bv)) => Motor::from(bv),
-            Versor::KVec(AnyKVector::Four(ps)) => Motor::from(ps),
-            _ => panic!("motor * motor should = motor"),
-        };
+    fn transform(&mut self, t: Transform) {
+        self.transform = self.transform * t;
     }
 
     #[inline]
     fn transform_t(&mut self, t: Transformation) {
-        let m = Motor::from(t);
-        self.transform(m);
+        self.transform(t.into());
     }
 
     #[inline]
-    fn get_transform(&self) -> Motor {
+    fn get_transform(&self) -> Transform {
         self.transform
     }
-
-    #[inline]
-    fn get_scale(&self) -> Trivector {
-        self.scale
-    }
-
-    #[inline]
-    fn set_scale(&mut self, new_scale: Trivector) {
-        self.scale = new_scale;
-    }
-
-    #[inline]
-    fn scale(&mut self, scale: Trivector) {
-        self.scale = self.scale.scale(scale);
-    }
 }
 
 impl Sphere {
     #[inline]
     pub fn new() -> Self {
         Sphere {
-            transform: Motor::from([1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
-            scale: Trivector::scale(1.0, 1.0, 1.0),
+            transform: Transform::identity(),
             material: Material::new(),
         }
     }
@@ -119,8 +109,8 @@ impl Sphere {
     #[inline]
     pub fn apply_gradient(&mut self, c1: Color, c2: Color) {
         let mut gradient = Pattern::gradient(c1, c2);
+        gradient.transform(Transform::scale(2.0, 1.0, 1.0).expect("A nonzero scale is invertible"));
         gradient.transform_t(Transformation::trans_coords(-1.0, 0.0, 0.0));
-        gradient.scale(Trivector::scale(2.0, 1.0, 1.0));
         self.material.pattern = Some(gradient);
     }
 }
@@ -134,14 +124,14 @@ mod test {
         let s = Sphere::new();
         assert_eq!(
             s.transform,
-            Motor::from(Transformation::trans_coords(0.0, 0.0, 0.0))
+            Transform::from(Transformation::trans_coords(0.0, 0.0, 0.0))
         );
     }
 
     #[test]
     fn sphere_change_transform() {
         let mut s = Sphere::new();
-        let m = Motor::from(Transformation::trans_coords(2.0, 3.0, 4.0));
+        let m = Transform::from(Transformation::trans_coords(2.0, 3.0, 4.0));
         s.transform = m;
         assert_eq!(s.transform, m);
     }
@@ -205,8 +195,8 @@ mod test {
     fn sphere_surface_transformed() {
         use std::f32::consts::PI;
         let mut s = Sphere::new();
-        s.transform(Transformation::rotation(e12, PI / 5.0).into());
-        s.scale = Trivector::scale(1.0, 0.5, 1.0);
+        s.transform = Transform::scale(1.0, 0.5, 1.0).unwrap();
+        s.transform(Transformation::rotation(Blade::e12, PI / 5.0).into());
         let p = Trivector::point(0.0, f32::sqrt(2.0) / 2.0, -f32::sqrt(2.0) / 2.0);
 
         assert_eq!(
@@ -235,7 +225,7 @@ mod test {
         let d = Trivector::direction(0.0, 0.0, 1.0);
         let r = Ray::from((p, d));
         let s = Sphere::new();
-        let c = Camera::new(p, -e013, -e021, 500, 500, 0.0);
+        let c = Camera::new(p, -e013, -e021, 500, 500, 0.0).unwrap();
 
         let xs = s.intersect(r, &c);
 
@@ -250,7 +240,7 @@ mod test {
         let d = Trivector::direction(0.0, 0.0, 1.0);
         let r = Ray::from((p, d));
         let s = Sphere::new();
-        let c = Camera::new(p, -e013, -e021, 500, 500, 0.0);
+        let c = Camera::new(p, -e013, -e021, 500, 500, 0.0).unwrap();
 
         let xs = s.intersect(r, &c);
 
@@ -265,8 +255,8 @@ mod test {
         let d = Trivector::direction(0.0, 0.0, 1.0);
         let r = Ray::from((p, d));
         let mut s = Sphere::new();
-        s.transform(Transformation::rotation(e23, 3.141592 / 4.0).into());
-        let c = Camera::new(p, -e013, -e021, 500, 500, 0.0);
+        s.transform(Transformation::rotation(Blade::e23, 3.141592 / 4.0).into());
+        let c = Camera::new(p, -e013, -e021, 500, 500, 0.0).unwrap();
 
         let xs = s.intersect(r, &c);
 
@@ -294,7 +284,7 @@ mod test {
         let d = Trivector::direction(0.0, 0.0, 1.0);
         let r = Ray::from((p, d));
         let s = Sphere::new();
-        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0);
+        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0).unwrap();
 
         let xs = s.intersect(r, &c);
 
@@ -323,10 +313,10 @@ mod test {
         let p = Trivector::point(0.0, 0.0, -5.0);
         let d = Trivector::direction(0.0, 0.0, 1.0);
         let r = Ray::from((p, d));
-        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0);
+        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0).unwrap();
 
         let mut s = Sphere::new();
-        s.scale = Trivector::scale(2.0, 2.0, 2.0);
+        s.transform = Transform::scale(2.0, 2.0, 2.0).unwrap();
 
         let xs = s.intersect(r, &c);
 
@@ -340,10 +330,10 @@ mod test {
         let p = Trivector::point(0.0, 0.0, -5.0);
         let d = Trivector::direction(0.0, 0.0, 1.0);
         let r = Ray::from((p, d));
-        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0);
+        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0).unwrap();
 
         let mut s = Sphere::new();
-        s.transform = Motor::from(Transformation::trans_coords(5.0, 0.0, 0.0));
+        s.transform = Transform::from(Transformation::trans_coords(5.0, 0.0, 0.0));
 
         let xs = s.intersect(r, &c);
 
@@ -355,10 +345,10 @@ mod test {
         let p = Trivector::point(0.0, 0.0, -5.0);
         let d = Trivector::direction(0.0, 0.0, 1.0);
         let r = Ray::from((p, d));
-        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0);
+        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0).unwrap();
 
         let mut s = Sphere::new();
-        s.transform_t(Transformation::rotation(e23, 3.141592 / 4.0));
+        s.transform_t(Transformation::rotation(Blade::e23, 3.141592 / 4.0));
 
         let xs = s.intersect(r, &c);
 
@@ -367,15 +357,35 @@ mod test {
         assert!(f32::abs(xs[1].t() - 6.0) < 0.01);
     }
 
+    #[test]
+    fn mirrored_sphere() {
+        let p = Trivector::point(4.0, 0.0, -5.0);
+        let r = Ray::from((p, Trivector::direction(0.0, 0.0, 1.0)));
+        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0).unwrap();
+
+        // Mirrored in the plane x = 2, so centred on (4, 0, 0)
+        let mut s = Sphere::new();
+        s.transform_t(Transformation::reflection(Vector::from([
+            1.0, 0.0, 0.0, 2.0,
+        ])));
+
+        let xs = s.intersect(r, &c);
+        assert_eq!(xs.len(), 2);
+        assert!(f32::abs(xs[0].t() - 4.0) < 0.01);
+        // Normals still point outwards
+        let n = s.surface_at(Trivector::point(5.0, 0.0, 0.0));
+        assert_eq!(n, Vector::from([1.0, 0.0, 0.0, 0.0]));
+    }
+
     #[test]
     fn intersect_flattened_sphere() {
         let p = Trivector::point(0.0, 0.0, -5.0);
         let d = Trivector::direction(0.0, 0.0, 1.0);
         let r = Ray::from((p, d));
-        let c = Camera::new(p, -e021, -e013, 100, 100, std::f32::consts::PI / 2.0);
+        let c = Camera::new(p, -e021, -e013, 100, 100, std::f32::consts::PI / 2.0).unwrap();
 
         let mut s = Sphere::new();
-        s.scale = Trivector::scale(10.0, 10.0, 0.1);
+        s.transform = Transform::scale(10.0, 10.0, 0.1).unwrap();
 
         let xs = s.intersect(r, &c);
 
@@ -389,10 +399,10 @@ mod test {
         let p = Trivector::point(0.0, 0.0, -5.0);
         let d = Trivector::direction(0.0, 0.0, 1.0);
         let r = Ray::from((p, d));
-        let c = Camera::new(p, -e021, -e013, 100, 100, std::f32::consts::PI / 2.0);
+        let c = Camera::new(p, -e021, -e013, 100, 100, std::f32::consts::PI / 2.0).unwrap();
 
         let mut s = Sphere::new();
-        s.scale = Trivector::scale(10.0, 10.0, 0.1);
+        s.transform = Transform::scale(10.0, 10.0, 0.1).unwrap();
         s.transform_t(Transformation::trans_coords(3.0, 3.0, 0.0));
 
         let xs = s.intersect(r, &c);
@@ -402,6 +412,29 @@ mod test {
         assert!(f32::abs(xs[1].t() - 5.1) < 0.01);
     }
 
+    #[test]
+    fn intersect_sheared_sphere() {
+        use crate::util::float_eq;
+        let p = Trivector::point(1.0, 0.5, -5.0);
+        let d = Trivector::direction(0.0, 0.0, 1.0);
+        let r = Ray::from((p, d));
+        let c = Camera::new(p, -e021, -e013, 100, 100, std::f32::consts::PI / 2.0).unwrap();
+
+        // Shearing x by y moves the point (0.5, 0.5) of the unit circle to (1.0, 0.5)
+        let mut s = Sphere::new();
+        s.transform = Transform::shear(1.0, 0.0, 0.0, 0.0, 0.0, 0.0).unwrap();
+
+        let xs = s.intersect(r, &c);
+        let half = f32::sqrt(0.5);
+
+        assert_eq!(xs.len(), 2);
+        assert!(float_eq(xs[0].t(), 5.0 - half));
+        assert!(float_eq(xs[1].t(), 5.0 + half));
+        // The normal is perpendicular to the sheared surface, tilting against the shear
+        let n = s.surface_at(Trivector::point(1.0, 0.5, -half));
+        assert_eq!(n, Vector::from([0.5, 0.0, -half, 0.0]).normalize());
+    }
+
     #[test]
     fn intersect_scaled_sphere_edge_case_1() {
         let r = Ray::from([
@@ -424,11 +457,12 @@ mod test {
             500,
             500,
             PI / 3.0,
-        );
+        )
+        .unwrap();
 
         let mut right = Sphere::new();
+        right.transform = Transform::scale(0.5, 0.5, 0.5).unwrap();
         right.transform_t(Transformation::trans_coords(1.5, 0.5, -0.5));
-        right.scale = Trivector::scale(0.5, 0.5, 0.5);
 
         let xs = right.intersect(r, &c);
 
@@ -457,13 +491,54 @@ mod test {
             500,
             500,
             PI / 3.0,
-        );
+        )
+        .unwrap();
 
         let mut floor = Sphere::new();
-        floor.scale = Trivector::scale(10.0, 0.01, 1.0);
+        floor.transform = Transform::scale(10.0, 0.01, 1.0).unwrap();
 
         let xs = floor.intersect(r, &c);
 
         assert_eq!(xs.len(), 2);
     }
+
+    #[test]
+    fn hit_packet_transformed_sphere() {
+        use crate::util::float_eq;
+        let mut s = Sphere::new();
+        s.transform = Transform::scale(2.0, 0.5, 1.0).unwrap();
+        s.transform_t(Transformation::trans_coords(1.0, 0.0, 2.0));
+        let origin = Trivector::point(0.0, 0.5, -5.0);
+        let rays: [Ray; LANES] = std::array::from_fn(|i| {
+            let i = i as f32;
+            Ray::from((origin, Trivector::direction(0.1 * i, -0.02 * i, 1.0)))
+        });
+        let ts = s.hit_packet(RayX8::from(rays), TrivectorX8::splat(origin));
+        for (i, r) in rays.iter().enumerate() {
+            match s.intersect_from_origin(*r, origin).hit() {
+                Some(h) => assert!(float_eq(h.t(), ts[i])),
+                None => assert_eq!(ts[i], f32::INFINITY),
+            }
+        }
+    }
+
+    #[test]
+    fn sphere_follows_rigid_body() {
+        use crate::pga_3::dynamics::*;
+        let mut s = Sphere::new();
+        s.transform_t(Transformation::trans_coords(0.0, 3.0, 0.0));
+        let mut body = RigidBody::new(s.transform.motor(), Inertia::sphere(1.0, 1.0));
+        body.gravity = Trivector::direction(0.0, -9.8, 0.0);
+        for _ in 0..16 {
+            s.transform(body.step(0.05).into());
+        }
+        assert_eq!(s.transform.motor().normalize(), body.pose);
+        let r = Ray::from((
+            Trivector::point(0.0, 0.0, -5.0),
+            Trivector::direction(0.0, 0.0, 1.0),
+        ));
+        // Having fallen about 3.1, the sphere is now in the ray's path
+        let xs = s.intersect_from_origin(r, Trivector::point(0.0, 0.0, -5.0));
+        assert_eq!(xs.len(), 2);
+    }
 }
@@ -2,6 +2,8 @@ use crate::canvas::*;
 use crate::pga_3::*;
 use crate::raytracing::geometry::*;
 use std::any::Any;
+use std::array;
+use std::simd::cmp::SimdPartialOrd;
 
 use sealed::Sealed;
 mod sealed {
@@ -39,18 +41,20 @@ impl World {
         }
     }
 
-    pub fn default() -> Self {
+    /// The world of two concentric spheres and a light that the tests render. Fails like
+    /// `Camera::new` and `Transform::scale`, which it builds on.
+    pub fn default() -> Result<Self, PgaError> {
         let mut s1 = Sphere::new();
         s1.material.color = Color::new(0.8, 1.0, 0.6);
         s1.material.diffuse = 0.7;
         s1.material.specular = 0.2;
 
         let mut s2 = Sphere::new();
-        s2.scale = Trivector::scale(0.5, 0.5, 0.5);
+        s2.transform = Transform::scale(0.5, 0.5, 0.5)?;
 
         let light = PointLight::new(Trivector::point(-10.0, 10.0, -10.0), WHITE);
 
-        World {
+        Ok(World {
             objects: vec![Object::Sphere(s1), Object::Sphere(s2)],
             lights: vec![Light::Point(light)],
             camera: Camera::new(
@@ -60,8 +64,8 @@ impl World {
                 500,
                 500,
                 std::f32::consts::PI / 2.0,
-            ),
-        }
+            )?,
+        })
     }
 
     #[allow(irrefutable_let_patterns)]
@@ -147,13 +151,47 @@ impl World {
         self.shade_hit(&i)
     }
 
+    /// The packet form of `intersect_from_origin(r, origin).hit()`, giving the hit of each ray
+    #[inline]
+    pub fn hit_packet(&self, r: RayX8, origin: TrivectorX8) -> [Option<Intersection<'_>>; LANES] {
+        let mut nearest = FloatX8::splat(f32::INFINITY);
+        let mut hits = [None; LANES];
+        for o in &self.objects {
+            let t = o.hit_packet(r, origin);
+            let closer = t.simd_lt(nearest);
+            nearest = closer.select(t, nearest);
+            for (i, hit) in hits.iter_mut().enumerate() {
+                if closer.test(i) {
+                    *hit = Some(Intersection::new(t[i], o.into()));
+                }
+            }
+        }
+        hits
+    }
+
+    /// Traces the camera's rays in packets along each row of pixels, shading every hit on its own
     #[inline]
     pub fn render(&self) -> Canvas {
-        let mut img = Canvas::new(self.camera.hsize, self.camera.vsize);
-        for (x, y, c) in img.enumerate_mut() {
-            let r = self.camera.ray_for_pixel(x, y);
-            *c = self.color_at(r);
+        let (hsize, vsize) = (self.camera.hsize, self.camera.vsize);
+        let mut img = Canvas::new(hsize, vsize);
+        // Pixels come in rows, in the order the packets trace them
+        let mut pixels = img.iter_mut();
+        let origin = TrivectorX8::splat(self.camera.location);
+        for y in 0..vsize {
+            for x in (0..hsize).step_by(LANES) {
+                // The last packet of a row repeats its final pixel to fill the lanes
+                let rays: [Ray; LANES] =
+                    array::from_fn(|i| self.camera.ray_for_pixel((x + i).min(hsize - 1), y));
+                let hits = self.hit_packet(RayX8::from(rays), origin);
+                for (i, pixel) in pixels.by_ref().take(LANES.min(hsize - x)).enumerate() {
+                    *pixel = match hits[i] {
+                        Some(h) => self.shade_hit(&h.precompute(&rays[i], &self.camera)),
+                        None => BLACK,
+                    };
+                }
+            }
         }
+        drop(pixels);
         img
     }
 
@@ -235,6 +273,7 @@ impl World {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::util::float_eq;
 
     #[test]
     fn world_new_empty() {
@@ -251,11 +290,11 @@ mod test {
         s1.material.specular = 0.2;
 
         let mut s2 = Sphere::new();
-        s2.scale = Trivector::scale(0.5, 0.5, 0.5);
+        s2.transform = Transform::scale(0.5, 0.5, 0.5).unwrap();
 
         let light = PointLight::new(Trivector::point(-10.0, 10.0, -10.0), WHITE);
 
-        let w = World::default();
+        let w = World::default().unwrap();
         assert_eq!(w.objects.len(), 2);
         assert_eq!(w.lights.len(), 1);
         assert!(w.contains(&Object::Sphere(s1)));
@@ -265,7 +304,7 @@ mod test {
 
     #[test]
     fn intersect_world() {
-        let w = World::default();
+        let w = World::default().unwrap();
         let r = Ray::from((
             Trivector::point(0.0, 0.0, -5.0),
             Trivector::direction(0.0, 0.0, 1.0),
@@ -282,7 +321,7 @@ mod test {
 
     #[test]
     fn shade_intersection() {
-        let w = World::default();
+        let w = World::default().unwrap();
         let r = Ray::from((
             Trivector::point(0.0, 0.0, -5.0),
             Trivector::direction(0.0, 0.0, 1.0),
@@ -295,7 +334,7 @@ mod test {
 
     #[test]
     fn shade_intersection_in_shadow() {
-        let mut w = World::default();
+        let mut w = World::default().unwrap();
         w.lights[0] = Light::Point(PointLight::new(Trivector::point(0.0, 0.0, -10.0), WHITE));
         w.objects.push(Object::Sphere(Sphere::new()));
         w.objects.push(Object::Sphere(Sphere::new()));
@@ -314,7 +353,7 @@ mod test {
 
     #[test]
     fn color_ray_miss() {
-        let w = World::default();
+        let w = World::default().unwrap();
         let r = Ray::from((
             Trivector::point(0.0, 0.0, -5.0),
             Trivector::direction(0.0, 1.0, 0.0),
@@ -325,7 +364,7 @@ mod test {
 
     #[test]
     fn color_ray_hit() {
-        let w = World::default();
+        let w = World::default().unwrap();
         let r = Ray::from((
             Trivector::point(0.0, 0.0, -5.0),
             Trivector::direction(0.0, 0.0, 1.0),
@@ -336,12 +375,12 @@ mod test {
 
     #[test]
     fn color_ray_with_intersection_behind_ray() {
-        let mut w = World::default();
+        let mut w = World::default().unwrap();
         w.objects[0].material_mut().ambient = 1.0;
         w.objects[1].material_mut().ambient = 1.0;
         let p = Trivector::point(0.0, 0.0, 0.75);
         let r = Ray::from((p, Trivector::direction(0.0, 0.0, -1.0)));
-        w.camera = Camera::new(p, -e021, -e013, 500, 500, 0.0);
+        w.camera = Camera::new(p, -e021, -e013, 500, 500, 0.0).unwrap();
         let col = w.color_at(r);
         assert_eq!(col, w.objects[1].material().color);
     }
@@ -349,8 +388,8 @@ mod test {
     #[test]
     fn render_default_world() {
         use std::f32::consts::PI;
-        let mut w = World::default();
-        let c = Camera::new(e123 + 5.0 * e021, -e021, -e013, 11, 11, PI / 2.0);
+        let mut w = World::default().unwrap();
+        let c = Camera::new(e123 + 5.0 * e021, -e021, -e013, 11, 11, PI / 2.0).unwrap();
         w.camera = c;
         let image = w.render();
         assert_eq!(
@@ -359,30 +398,75 @@ mod test {
         );
     }
 
+    #[test]
+    fn render_matches_single_rays() {
+        use std::f32::consts::PI;
+        let mut w = World::default().unwrap();
+        let mut floor = Plane::new();
+        floor.transform_t(Transformation::trans_coords(0.0, -1.0, 0.0));
+        w.objects.push(Object::Plane(floor));
+        w.objects[1].transform_t(Transformation::trans_coords(0.5, 0.5, -1.0));
+        w.camera = Camera::new(
+            Trivector::point(1.0, 1.5, 5.0),
+            -e021,
+            -e013,
+            21,
+            13,
+            PI / 2.0,
+        )
+        .unwrap();
+        let image = w.render();
+        for (x, y, c) in image.enumerate() {
+            assert_eq!(*c, w.color_at(w.camera.ray_for_pixel(x, y)));
+        }
+    }
+
+    #[test]
+    fn hit_packet_matches_hit() {
+        let w = World::default().unwrap();
+        let origin = Trivector::point(0.0, 0.0, -5.0);
+        let rays: [Ray; LANES] = array::from_fn(|i| {
+            let i = i as f32;
+            Ray::from((origin, Trivector::direction(0.2 * i - 0.7, 0.1 * i, 1.0)))
+        });
+        let hits = w.hit_packet(RayX8::from(rays), TrivectorX8::splat(origin));
+        for (r, packet_hit) in rays.iter().zip(hits) {
+            let xs = w.intersect_from_origin(*r, origin);
+            match (xs.hit(), packet_hit) {
+                (Some(h), Some(ph)) => {
+                    assert!(float_eq(h.t(), ph.t()));
+                    assert_eq!(h.obj(), ph.obj());
+                }
+                (None, None) => {}
+                _ => panic!("Packet and single ray hits differ"),
+            }
+        }
+    }
+
     #[test]
     fn shadow_no_object_colinear() {
-        let world = World::default();
+        let world = World::default().unwrap();
         let point = Trivector::point(0.0, 10.0, 0.0);
         assert_eq!(world.is_shadowed(point, &world.lights[0]), false);
     }
 
     #[test]
     fn shadow_object_occlude_light() {
-        let world = World::default();
+        let world = World::default().unwrap();
         let point = Trivector::point(10.0, -10.0, 10.0);
         assert_eq!(world.is_shadowed(point, &world.lights[0]), true);
     }
 
     #[test]
     fn shadow_object_behind_light() {
-        let world = World::default();
+        let world = World::default().unwrap();
         let point = Trivector::point(-20.0, 20.0, -20.0);
         assert_eq!(world.is_shadowed(point, &world.lights[0]), false);
     }
 
     #[test]
     fn shadow_object_behind_point() {
-        let world = World::default();
+        let world = World::default().unwrap();
         let point = Trivector::point(-2.0, 2.0, -2.0);
         assert_eq!(world.is_shadowed(point, &world.lights[0]), false);
     }
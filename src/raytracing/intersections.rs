@@ -1,4 +1,5 @@
 use crate::raytracing::*;
+use std::simd::cmp::SimdPartialOrd;
 
 pub trait Hit {
     fn hit(&self) -> Option<&Intersection<'_>>;
@@ -22,6 +23,15 @@ impl Hit for Vec<Intersection<'_>> {
     }
 }
 
+/// The packet form of `Hit::hit` for rays meeting an object at most twice, at `t0 <= t1`: the
+/// first positive `t` of each ray, or infinity where there's none. A NaN `t` is a miss.
+#[inline]
+pub fn packet_hit(t0: FloatX8, t1: FloatX8) -> FloatX8 {
+    let zero = FloatX8::splat(0.0);
+    let t1 = t1.simd_gt(zero).select(t1, FloatX8::splat(f32::INFINITY));
+    t0.simd_gt(zero).select(t0, t1)
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct Intersection<'a> {
     t: f32,
@@ -187,7 +197,7 @@ mod test {
             Trivector::direction(0.0, 0.0, 1.0),
         ));
         let s = Sphere::new();
-        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0);
+        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0).unwrap();
         let i = s.intersect(r, &c)[0];
         let comps = i.precompute(&r, &c);
 
@@ -203,7 +213,7 @@ mod test {
     fn precompute_inside() {
         let r = Ray::from((e123, Trivector::direction(0.0, 0.0, 1.0)));
         let s = Sphere::new();
-        let c = Camera::new(e123, -e021, -e013, 500, 500, 0.0);
+        let c = Camera::new(e123, -e021, -e013, 500, 500, 0.0).unwrap();
         let i = s.intersect(r, &c)[1];
         let comps = i.precompute(&r, &c);
 
@@ -219,7 +229,7 @@ mod test {
 
         let p = Trivector::point(0.0, 0.0, -5.0);
         let r = Ray::from((p, Trivector::direction(0.0, 0.0, 1.0)));
-        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0);
+        let c = Camera::new(p, -e021, -e013, 500, 500, 0.0).unwrap();
         let mut shape = Sphere::new();
         shape.transform_t(Transformation::trans_coords(0.0, 0.0, 1.0));
         let shape = Object::Sphere(shape);
@@ -7,8 +7,7 @@ use std::rc::Rc;
 #[derive(Clone)]
 pub struct Pattern {
     func: Rc<dyn Fn(Trivector) -> Color>,
-    transform: Motor,
-    scale: Trivector,
+    transform: Transform,
 }
 
 impl Debug for Pattern {
@@ -16,7 +15,6 @@ impl Debug for Pattern {
         f.debug_struct("Pattern")
             .field("func", &"Fn(&Object, Trivector) -> Color")
             .field("transform", &self.transform)
-            .field("scale", &self.scale)
             .finish()
     }
 }
@@ -26,8 +24,7 @@ impl Pattern {
     pub fn new<F: Fn(Trivector) -> Color + 'static>(func: F) -> Self {
         Self {
             func: Rc::new(func),
-            transform: Motor::from(1.0),
-            scale: Trivector::scale(1.0, 1.0, 1.0),
+            transform: Transform::identity(),
         }
     }
 
@@ -137,37 +134,25 @@ impl Pattern {
     /// recursive patterns' closures.
     #[inline]
     pub fn apply_with_transform(&self, point: Trivector) -> Color {
-        let point = (self.transform << point).scale(self.scale.reciprocal());
-        self.apply_at(point)
+        self.apply_at(self.transform << point)
     }
 
     #[inline]
     pub fn apply_at_shape(&self, shape: ObjectRef, point: Trivector) -> Color {
-        let point = (*shape.get_transform() << point).scale(shape.get_scale().reciprocal());
-        let point = (self.transform << point).scale(self.scale.reciprocal());
-        self.apply_at(point)
+        let point = *shape.get_transform() << point;
+        self.apply_at(self.transform << point)
     }
 
+    /// Applies `t` after the pattern's current transform, ie in the coordinates of the shape
     #[inline]
-    pub fn transform(&mut self, m: Motor) {
-        self.transform = match m * self.transform {
-            Versor::Even(m) => m,
-            Versor::KVec(AnyKVector::Zero(f)) => Motor::from(f),
-            Versor::KVec(AnyKVector::Two(bv)) => Motor::from(bv),
-            Versor::KVec(AnyKVector::Four(ps)) => Motor::from(ps),
-            _ => panic!("Motor * motor should be motor"),
-        };
+    pub fn transform(&mut self, t: Transform) {
+        self.transform = self.transform * t;
     }
 
     #[inline]
     pub fn transform_t(&mut self, t: Transformation) {
         self.transform(t.into());
     }
-
-    #[inline]
-    pub fn scale(&mut self, scale: Trivector) {
-        self.scale = self.scale.scale(scale);
-    }
 }
 
 #[cfg(test)]
@@ -205,7 +190,7 @@ mod test {
     #[test]
     fn stripe_with_pattern_transform() {
         let mut pat = Pattern::stripe(WHITE, BLACK);
-        pat.scale(Trivector::scale(2.0, 2.0, 2.0));
+        pat.transform(Transform::scale(2.0, 2.0, 2.0).unwrap());
         let sphere = Sphere::new();
         assert_eq!(
             pat.apply_at_shape(ObjectRef::Sphere(&sphere), Trivector::point(1.5, 0.0, 0.0)),
@@ -217,7 +202,7 @@ mod test {
     fn stripe_with_object() {
         let pat = Pattern::stripe(WHITE, BLACK);
         let mut sphere = Sphere::new();
-        sphere.scale(Trivector::scale(2.0, 2.0, 2.0));
+        sphere.transform(Transform::scale(2.0, 2.0, 2.0).unwrap());
         assert_eq!(
             pat.apply_at_shape(ObjectRef::Sphere(&sphere), Trivector::point(1.5, 0.0, 0.0)),
             WHITE
@@ -229,7 +214,7 @@ mod test {
         let mut pat = Pattern::stripe(WHITE, BLACK);
         pat.transform_t(Transformation::trans_coords(0.5, 0.0, 0.0));
         let mut sphere = Sphere::new();
-        sphere.scale(Trivector::scale(2.0, 2.0, 2.0));
+        sphere.transform(Transform::scale(2.0, 2.0, 2.0).unwrap());
         assert_eq!(
             pat.apply_at_shape(ObjectRef::Sphere(&sphere), Trivector::point(2.5, 0.0, 0.0)),
             WHITE
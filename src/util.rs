@@ -1,13 +1,271 @@
-pub const EPSILON: f32 = 0.00001;
+pub use lanes::*;
+use std::fmt::{Debug, Display};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount};
 
-pub const fn float_eq(f1: f32, f2: f32) -> bool {
-    return (f1 - f2).abs() < EPSILON;
+pub const EPSILON: f32 = <f32 as Float>::EPSILON;
+
+/// The float type of the PGA primitives, which are generic over it with f32 as the default, eg
+/// `Vector` is `Vector<f32>`. f32 packs twice as many lanes into each SIMD register; f64 is for
+/// large scenes and geometric queries where f32 drift becomes visible.
+///
+/// The default only applies where a type is written out: an unannotated `Trivector::point(1.0,
+/// 2.0, 3.0)` infers its float from the literals, which fall back to f64. Annotate the binding or
+/// write `<Trivector>::point(..)` to get the f32 default.
+///
+/// The `*_lanes` functions are the SIMD arithmetic of `Lanes`, which `std::simd` only provides for
+/// each concrete float type.
+pub trait Float:
+    SimdElement
+    + Debug
+    + Display
+    + Default
+    + PartialOrd
+    + Send
+    + Sync
+    + 'static
+    + From<f32>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+    + sealed::Sealed
+{
+    /// The tolerance of `float_eq`, and so of every comparison of PGA types
+    const EPSILON: Self;
+    const ZERO: Self;
+    const ONE: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+    const NAN: Self;
+    const MIN_POSITIVE: Self;
+    const PI: Self;
+    const FRAC_PI_2: Self;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn signum(self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn total_cmp(&self, other: &Self) -> std::cmp::Ordering;
+    fn is_finite(self) -> bool;
+    fn is_infinite(self) -> bool;
+    fn is_nan(self) -> bool;
+    fn is_sign_negative(self) -> bool;
+    /// Rounds to the nearest `Float`, for constants without an exact f32 value
+    fn from_f64(f: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    fn add_lanes<const N: usize>(a: Simd<Self, N>, b: Simd<Self, N>) -> Simd<Self, N>
+    where
+        LaneCount<N>: SupportedLaneCount;
+    fn sub_lanes<const N: usize>(a: Simd<Self, N>, b: Simd<Self, N>) -> Simd<Self, N>
+    where
+        LaneCount<N>: SupportedLaneCount;
+    fn mul_lanes<const N: usize>(a: Simd<Self, N>, b: Simd<Self, N>) -> Simd<Self, N>
+    where
+        LaneCount<N>: SupportedLaneCount;
+    fn div_lanes<const N: usize>(a: Simd<Self, N>, b: Simd<Self, N>) -> Simd<Self, N>
+    where
+        LaneCount<N>: SupportedLaneCount;
+    fn neg_lanes<const N: usize>(a: Simd<Self, N>) -> Simd<Self, N>
+    where
+        LaneCount<N>: SupportedLaneCount;
+    fn sum_lanes<const N: usize>(a: Simd<Self, N>) -> Self
+    where
+        LaneCount<N>: SupportedLaneCount;
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
 }
 
-pub fn sum_of_squares(floats: &[f32]) -> f32 {
+macro_rules! float {
+    ($f:ident, $epsilon:literal) => {
+        impl Float for $f {
+            const EPSILON: Self = $epsilon;
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const INFINITY: Self = $f::INFINITY;
+            const NEG_INFINITY: Self = $f::NEG_INFINITY;
+            const NAN: Self = $f::NAN;
+            const MIN_POSITIVE: Self = $f::MIN_POSITIVE;
+            const PI: Self = std::$f::consts::PI;
+            const FRAC_PI_2: Self = std::$f::consts::FRAC_PI_2;
+
+            float!($f; abs, sqrt, sin, cos, tan, asin, acos, atan, sinh, cosh, exp, ln, signum);
+            float!($f, other; atan2, copysign, max, min, hypot);
+
+            #[inline]
+            fn powi(self, n: i32) -> Self {
+                $f::powi(self, n)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                $f::sin_cos(self)
+            }
+
+            #[inline]
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $f::clamp(self, min, max)
+            }
+
+            #[inline]
+            fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+                $f::total_cmp(self, other)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $f::is_finite(self)
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                $f::is_infinite(self)
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                $f::is_nan(self)
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> bool {
+                $f::is_sign_negative(self)
+            }
+
+            #[inline]
+            fn from_f64(f: f64) -> Self {
+                f as $f
+            }
+
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            #[inline]
+            fn add_lanes<const N: usize>(a: Simd<Self, N>, b: Simd<Self, N>) -> Simd<Self, N>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                a + b
+            }
+
+            #[inline]
+            fn sub_lanes<const N: usize>(a: Simd<Self, N>, b: Simd<Self, N>) -> Simd<Self, N>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                a - b
+            }
+
+            #[inline]
+            fn mul_lanes<const N: usize>(a: Simd<Self, N>, b: Simd<Self, N>) -> Simd<Self, N>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                a * b
+            }
+
+            #[inline]
+            fn div_lanes<const N: usize>(a: Simd<Self, N>, b: Simd<Self, N>) -> Simd<Self, N>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                a / b
+            }
+
+            #[inline]
+            fn neg_lanes<const N: usize>(a: Simd<Self, N>) -> Simd<Self, N>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                -a
+            }
+
+            #[inline]
+            fn sum_lanes<const N: usize>(a: Simd<Self, N>) -> Self
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                std::simd::num::SimdFloat::reduce_sum(a)
+            }
+        }
+    };
+    ($f:ident; $($unary:ident),*) => {
+        $(
+            #[inline]
+            fn $unary(self) -> Self {
+                $f::$unary(self)
+            }
+        )*
+    };
+    ($f:ident, $other:ident; $($binary:ident),*) => {
+        $(
+            #[inline]
+            fn $binary(self, $other: Self) -> Self {
+                $f::$binary(self, $other)
+            }
+        )*
+    };
+}
+
+float!(f32, 0.00001);
+float!(f64, 0.000000001);
+
+/// Whether the floats are equal up to the `Float::EPSILON` of their precision
+#[inline]
+pub fn float_eq<F: Float>(f1: F, f2: F) -> bool {
+    (f1 - f2).abs() < F::EPSILON
+}
+
+pub fn sum_of_squares<F: Float>(floats: &[F]) -> F {
     floats
         .iter()
         .map(|f| f.powi(2))
-        .fold(0.0, |acc, f| acc + f)
+        .fold(F::ZERO, |acc, f| acc + f)
         .sqrt()
 }
+
+mod lanes;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn float_eq_uses_the_precision_epsilon() {
+        assert!(float_eq::<f32>(1.0, 1.000001));
+        assert!(!float_eq::<f64>(1.0, 1.000001));
+        assert!(float_eq::<f64>(1.0, 1.0 + 1e-10));
+        assert_eq!(sum_of_squares::<f64>(&[3.0, 4.0]), 5.0);
+    }
+}
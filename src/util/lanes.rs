@@ -0,0 +1,195 @@
+use crate::util::Float;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+use std::simd::{LaneCount, Simd, SupportedLaneCount};
+use std::slice::SliceIndex;
+
+/// The coefficients of a PGA type, as a `Simd` whose arithmetic works for any `Float`. It mirrors
+/// the parts of the `Simd` API the primitives use, and `swizzle!` stands in for `simd_swizzle!`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(transparent)]
+pub struct Lanes<F: Float, const N: usize>(pub Simd<F, N>)
+where
+    LaneCount<N>: SupportedLaneCount;
+
+impl<F: Float, const N: usize> Lanes<F, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    pub fn splat(f: F) -> Self {
+        Self(Simd::splat(f))
+    }
+
+    #[inline]
+    pub const fn from_array(array: [F; N]) -> Self {
+        Self(Simd::from_array(array))
+    }
+
+    #[inline]
+    pub const fn as_array(&self) -> &[F; N] {
+        self.0.as_array()
+    }
+
+    #[inline]
+    pub const fn to_array(self) -> [F; N] {
+        self.0.to_array()
+    }
+
+    /// Lanes `START..START + LEN`
+    #[inline]
+    pub fn extract<const START: usize, const LEN: usize>(self) -> Lanes<F, LEN>
+    where
+        LaneCount<LEN>: SupportedLaneCount,
+    {
+        Lanes(self.0.extract::<START, LEN>())
+    }
+
+    #[inline]
+    pub fn resize<const LEN: usize>(self, value: F) -> Lanes<F, LEN>
+    where
+        LaneCount<LEN>: SupportedLaneCount,
+    {
+        Lanes(self.0.resize::<LEN>(value))
+    }
+
+    #[inline]
+    pub fn rotate_elements_right<const OFFSET: usize>(self) -> Self {
+        Self(self.0.rotate_elements_right::<OFFSET>())
+    }
+
+    /// The lanes of `slice`, with those past its end taken from `or`
+    #[inline]
+    pub fn load_or(slice: &[F], or: Self) -> Self {
+        Self(Simd::load_or(slice, or.0))
+    }
+
+    #[inline]
+    pub fn reduce_sum(self) -> F {
+        F::sum_lanes(self.0)
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(Simd::from_array(self.0.to_array().map(F::abs)))
+    }
+}
+
+/// `simd_swizzle!` for `Lanes`
+macro_rules! swizzle {
+    // The length is spelled out, as otherwise it can be inferred from a `LaneCount` bound in scope
+    ($lanes:expr, $index:expr) => {
+        $crate::util::Lanes::<_, { $index.len() }>(std::simd::simd_swizzle!($lanes.0, $index))
+    };
+}
+pub(crate) use swizzle;
+
+impl<F: Float, const N: usize> From<[F; N]> for Lanes<F, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(array: [F; N]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+impl<F: Float, const N: usize> From<Simd<F, N>> for Lanes<F, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(simd: Simd<F, N>) -> Self {
+        Self(simd)
+    }
+}
+
+impl<F: Float, I: SliceIndex<[F]>, const N: usize> Index<I> for Lanes<F, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<F: Float, I: SliceIndex<[F]>, const N: usize> IndexMut<I> for Lanes<F, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+macro_rules! lanes_op {
+    ($op:ident, $method:ident, $assign:ident, $assign_method:ident, $lanes:ident) => {
+        impl<F: Float, const N: usize> $op for Lanes<F, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                Self(F::$lanes(self.0, rhs.0))
+            }
+        }
+
+        impl<F: Float, const N: usize> $assign for Lanes<F, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = $op::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+lanes_op!(Add, add, AddAssign, add_assign, add_lanes);
+lanes_op!(Sub, sub, SubAssign, sub_assign, sub_lanes);
+lanes_op!(Mul, mul, MulAssign, mul_assign, mul_lanes);
+lanes_op!(Div, div, DivAssign, div_assign, div_lanes);
+
+impl<F: Float, const N: usize> Neg for Lanes<F, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(F::neg_lanes(self.0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn arithmetic_matches_simd() {
+        let a = Simd::from_array([1.0, -2.0, 3.5, 0.25]);
+        let b = Simd::from_array([0.5, 4.0, -1.0, 2.0]);
+        let (la, lb) = (Lanes(a), Lanes(b));
+        assert_eq!((la + lb).0, a + b);
+        assert_eq!((la - lb).0, a - b);
+        assert_eq!((la * lb).0, a * b);
+        assert_eq!((la / lb).0, a / b);
+        assert_eq!((-la).0, -a);
+        assert_eq!(la.reduce_sum(), 2.75);
+        assert_eq!(swizzle!(la, [3, 0]), Lanes::from([0.25, 1.0]));
+        let wide = Lanes::<f64, 4>::from([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(
+            wide.extract::<1, 2>() * Lanes::splat(2.0),
+            Lanes::from([4.0, 6.0])
+        );
+    }
+}